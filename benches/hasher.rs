@@ -0,0 +1,79 @@
+// flexicon/benches/hasher.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Lookup and deserialization with the default SipHash `NamedMap` against
+//! the aHash-backed `FastNamedMap`.
+//!
+//! Run with `cargo bench --features ahash,serde_json`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use flexicon::adaptive::{FastNamedMap, FromName, NamedMap};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+struct Device {
+    version: String,
+    enabled: bool,
+}
+
+impl FromName for Device {
+    fn from_name(_name: &str) -> Self {
+        Device {
+            version: "latest".to_string(),
+            enabled: true,
+        }
+    }
+}
+
+const SIZES: [usize; 3] = [8, 64, 1024];
+
+fn names(size: usize) -> Vec<String> {
+    (0..size).map(|i| format!("device{}", i)).collect()
+}
+
+fn document(size: usize) -> String {
+    let entries: serde_json::Map<String, serde_json::Value> = names(size)
+        .into_iter()
+        .map(|name| (name, serde_json::json!({"version": "1", "enabled": false})))
+        .collect();
+    serde_json::Value::Object(entries).to_string()
+}
+
+fn lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lookup");
+    for size in SIZES {
+        let names = names(size);
+        let sip = NamedMap::<Device>::from(names.clone());
+        let fast = FastNamedMap::<Device>::from(names.clone());
+        group.bench_with_input(BenchmarkId::new("siphash", size), &names, |b, names| {
+            b.iter(|| names.iter().filter(|name| sip.contains_key(*name)).count())
+        });
+        group.bench_with_input(BenchmarkId::new("ahash", size), &names, |b, names| {
+            b.iter(|| names.iter().filter(|name| fast.contains_key(*name)).count())
+        });
+    }
+    group.finish();
+}
+
+fn deserialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deserialize");
+    for size in SIZES {
+        let json = document(size);
+        group.bench_with_input(BenchmarkId::new("siphash", size), &json, |b, json| {
+            b.iter(|| NamedMap::<Device>::from_json_str(black_box(json)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("ahash", size), &json, |b, json| {
+            b.iter(|| FastNamedMap::<Device>::from_json_str(black_box(json)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, lookup, deserialize);
+criterion_main!(benches);
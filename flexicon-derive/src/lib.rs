@@ -0,0 +1,145 @@
+// flexicon/flexicon-derive/src/lib.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Derive macros for [`flexicon`](https://docs.rs/flexicon).
+//!
+//! Use them through the `derive` feature of `flexicon` rather than
+//! depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, Lit, Result,
+};
+
+/// Derives `flexicon::adaptive::FromName` for a struct with named fields.
+///
+/// Field attributes:
+///
+/// - `#[from_name(name_field)]` — the field receives the name
+///   (converted with `Into`, so `String`, `Box<str>`, `Arc<str>` all work).
+/// - `#[from_name(default = <literal>)]` — the field is set to the literal.
+///   String literals are converted with `Into`, other literals are used as-is.
+///
+/// Unannotated fields fall back to `Default::default()`.
+///
+/// ```rust,ignore
+/// #[derive(Clone, FromName)]
+/// struct Interface {
+///     #[from_name(name_field)]
+///     name: String,
+///     #[from_name(default = "latest")]
+///     version: String,
+///     optional: bool,
+/// }
+/// ```
+#[proc_macro_derive(FromName, attributes(from_name))]
+pub fn derive_from_name(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// How a single field is initialized by the generated `from_name`.
+enum FieldInit {
+    Name,
+    Literal(Lit),
+    Default,
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "FromName can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "FromName can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let mut name_field_seen = false;
+    let mut inits = Vec::with_capacity(fields.len());
+    for field in fields {
+        let init = field_init(field)?;
+        if let FieldInit::Name = init {
+            if name_field_seen {
+                return Err(Error::new(
+                    field.span(),
+                    "only one field can be marked with #[from_name(name_field)]",
+                ));
+            }
+            name_field_seen = true;
+        }
+
+        let ident = field.ident.as_ref().expect("named field");
+        let span = field.ty.span();
+        inits.push(match init {
+            FieldInit::Name => quote_spanned! {span=>
+                #ident: ::core::convert::Into::into(name)
+            },
+            FieldInit::Literal(Lit::Str(lit)) => quote_spanned! {span=>
+                #ident: ::core::convert::Into::into(#lit)
+            },
+            FieldInit::Literal(lit) => quote_spanned! {span=> #ident: #lit },
+            FieldInit::Default => quote_spanned! {span=>
+                #ident: ::core::default::Default::default()
+            },
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let name_arg = if name_field_seen {
+        quote!(name)
+    } else {
+        quote!(_name)
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::flexicon::adaptive::FromName for #ident #ty_generics #where_clause {
+            fn from_name(#name_arg: &str) -> Self {
+                Self {
+                    #(#inits,)*
+                }
+            }
+        }
+    })
+}
+
+fn field_init(field: &syn::Field) -> Result<FieldInit> {
+    let mut init = FieldInit::Default;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("from_name")) {
+        attr.parse_nested_meta(|meta| {
+            if !matches!(init, FieldInit::Default) {
+                return Err(meta.error("conflicting #[from_name] attributes on one field"));
+            }
+            if meta.path.is_ident("name_field") {
+                init = FieldInit::Name;
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                init = FieldInit::Literal(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `name_field` or `default = <literal>`"))
+            }
+        })?;
+    }
+    Ok(init)
+}
@@ -0,0 +1,76 @@
+// flexicon/flexicon-derive/tests/derive.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use flexicon::adaptive::{FromName, NamedMap};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, FromName)]
+struct Interface {
+    #[from_name(name_field)]
+    name: String,
+    #[from_name(default = "latest")]
+    version: String,
+    #[from_name(default = 3)]
+    retries: u32,
+    optional: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, FromName)]
+struct NoName {
+    #[from_name(default = true)]
+    enabled: bool,
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_from_name_fields() {
+    let iface = Interface::from_name("wasi:cli/stdio");
+    assert_eq!(
+        iface,
+        Interface {
+            name: "wasi:cli/stdio".to_string(),
+            version: "latest".to_string(),
+            retries: 3,
+            optional: false,
+        }
+    );
+}
+
+#[test]
+fn test_without_name_field() {
+    let value = NoName::from_name("ignored");
+    assert!(value.enabled);
+    assert!(value.tags.is_empty());
+}
+
+#[test]
+fn test_named_map_roundtrip() {
+    #[derive(Deserialize)]
+    struct Manifest {
+        interfaces: NamedMap<Interface>,
+    }
+
+    let manifest: Manifest = toml::from_str(
+        r#"
+        interfaces = [
+            "logger",
+            { http = { name = "http", version = "0.2", retries = 0, optional = true } },
+        ]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(manifest.interfaces["logger"], Interface::from_name("logger"));
+    assert_eq!(manifest.interfaces["http"].version, "0.2");
+}
+
+#[test]
+fn test_compile_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
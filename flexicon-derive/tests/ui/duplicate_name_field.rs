@@ -0,0 +1,11 @@
+use flexicon::adaptive::FromName;
+
+#[derive(Clone, FromName)]
+struct Interface {
+    #[from_name(name_field)]
+    name: String,
+    #[from_name(name_field)]
+    alias: String,
+}
+
+fn main() {}
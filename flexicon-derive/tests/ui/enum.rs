@@ -0,0 +1,9 @@
+use flexicon::adaptive::FromName;
+
+#[derive(Clone, FromName)]
+enum Mode {
+    Fast,
+    Slow,
+}
+
+fn main() {}
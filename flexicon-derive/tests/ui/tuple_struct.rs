@@ -0,0 +1,6 @@
+use flexicon::adaptive::FromName;
+
+#[derive(Clone, FromName)]
+struct Wrapper(String);
+
+fn main() {}
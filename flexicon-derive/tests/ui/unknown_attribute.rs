@@ -0,0 +1,9 @@
+use flexicon::adaptive::FromName;
+
+#[derive(Clone, FromName)]
+struct Interface {
+    #[from_name(rename = "id")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,161 @@
+// flexicon/src/adaptive/arbitrary.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `arbitrary::Arbitrary` impls for fuzzing (the `arbitrary` feature).
+//!
+//! Generated maps hold at most 64 entries; `arbitrary_take_rest` reads
+//! entries until the input runs out or that budget is spent. Names are
+//! valid UTF-8 by construction; empty and whitespace-only names are
+//! skipped, as deserialization would reject them.
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{CaseInsensitiveNamedMap, KeyValidator, NamedMap, SortedNamedMap, ValidatedNamedMap};
+
+#[cfg(feature = "unicode")]
+use super::NormalizedNamedMap;
+
+/// The most entries a generated map holds, so that large fuzz inputs
+/// exercise many small maps rather than one huge one.
+const MAX_ENTRIES: usize = 64;
+
+/// Reads one entry, or `None` if its name is unusable.
+fn entry<'a, T: Arbitrary<'a>>(u: &mut Unstructured<'a>) -> Result<Option<(String, T)>> {
+    let name = String::arbitrary(u)?;
+    let value = T::arbitrary(u)?;
+    Ok((!name.trim().is_empty()).then_some((name, value)))
+}
+
+/// Reads a length-prefixed run of entries.
+fn entries<'a, T: Arbitrary<'a>>(u: &mut Unstructured<'a>) -> Result<Vec<(String, T)>> {
+    let len = u.arbitrary_len::<(String, T)>()?.min(MAX_ENTRIES);
+    let mut entries = Vec::with_capacity(len);
+    for _ in 0..len {
+        entries.extend(entry(u)?);
+    }
+    Ok(entries)
+}
+
+/// Reads entries until the input or the entry budget runs out.
+fn entries_take_rest<'a, T: Arbitrary<'a>>(mut u: Unstructured<'a>) -> Result<Vec<(String, T)>> {
+    let mut entries = Vec::new();
+    let mut attempts = 0;
+    while !u.is_empty() && attempts < MAX_ENTRIES {
+        entries.extend(entry(&mut u)?);
+        attempts += 1;
+    }
+    Ok(entries)
+}
+
+macro_rules! arbitrary_from_entries {
+    ($($map:ident),*) => {
+        $(
+            impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for $map<T> {
+                fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                    Ok(entries(u)?.into_iter().collect())
+                }
+
+                fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+                    Ok(entries_take_rest(u)?.into_iter().collect())
+                }
+
+                fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                    (0, None)
+                }
+            }
+        )*
+    };
+}
+
+arbitrary_from_entries!(NamedMap, SortedNamedMap, CaseInsensitiveNamedMap);
+
+#[cfg(feature = "unicode")]
+arbitrary_from_entries!(NormalizedNamedMap);
+
+impl<'a, T: Arbitrary<'a>, V: KeyValidator> Arbitrary<'a> for ValidatedNamedMap<T, V> {
+    /// Generates entries as for [`NamedMap`], dropping names `V` rejects.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(validated(entries(u)?))
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        Ok(validated(entries_take_rest(u)?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+fn validated<T, V: KeyValidator>(entries: Vec<(String, T)>) -> ValidatedNamedMap<T, V> {
+    let mut map = ValidatedNamedMap::new();
+    for (name, value) in entries {
+        let _ = map.insert(name, value);
+    }
+    map
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adaptive::IdentifierKeys;
+
+    /// Deterministic pseudo-random bytes, so the tests need no RNG.
+    fn bytes(len: usize, seed: u32) -> Vec<u8> {
+        let mut state = seed.wrapping_mul(2_654_435_761).wrapping_add(1);
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_any_input_length() {
+        for len in [0, 1, 2, 7, 64, 1024, 65_536] {
+            for seed in 0..8 {
+                let data = bytes(len, seed);
+
+                let map = NamedMap::<u32>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+                assert!(map.len() <= MAX_ENTRIES);
+                assert!(map.keys().all(|name| !name.trim().is_empty()));
+
+                let rest =
+                    NamedMap::<Vec<u8>>::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+                assert!(rest.len() <= MAX_ENTRIES);
+
+                SortedNamedMap::<bool>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+                CaseInsensitiveNamedMap::<String>::arbitrary_take_rest(Unstructured::new(&data))
+                    .unwrap();
+                let validated = ValidatedNamedMap::<u8, IdentifierKeys>::arbitrary(
+                    &mut Unstructured::new(&data),
+                )
+                .unwrap();
+                assert!(validated
+                    .keys()
+                    .all(|name| IdentifierKeys::validate(name).is_ok()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_take_rest_uses_the_input() {
+        let data = bytes(4096, 42);
+        let map = NamedMap::<u8>::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+        assert!(!map.is_empty());
+        assert!(NamedMap::<u8>::arbitrary_take_rest(Unstructured::new(&[]))
+            .unwrap()
+            .is_empty());
+    }
+}
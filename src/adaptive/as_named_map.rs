@@ -0,0 +1,154 @@
+// flexicon/src/adaptive/as_named_map.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+use super::{FromName, NamedMap};
+
+/// A `serde_with` adapter giving plain map fields [`NamedMap`]'s
+/// dual-format parsing.
+///
+/// Works with `HashMap<String, T>` and `BTreeMap<String, T>`; serialization
+/// emits the detailed (object) form.
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use std::collections::BTreeMap;
+/// # #[derive(Clone, Serialize, Deserialize)]
+/// # struct Interface { version: String }
+/// # impl flexicon::adaptive::FromName for Interface {
+/// #     fn from_name(_name: &str) -> Self { Self { version: "latest".into() } }
+/// # }
+/// use flexicon::adaptive::AsNamedMap;
+/// use serde_with::serde_as;
+///
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Manifest {
+///     #[serde_as(as = "AsNamedMap")]
+///     interfaces: BTreeMap<String, Interface>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsNamedMap;
+
+impl<'de, T, S> DeserializeAs<'de, HashMap<String, T, S>> for AsNamedMap
+where
+    T: DeserializeOwned + FromName,
+    S: BuildHasher + Default,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<HashMap<String, T, S>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        NamedMap::<T, S>::deserialize(deserializer).map(NamedMap::into_inner)
+    }
+}
+
+impl<'de, T> DeserializeAs<'de, BTreeMap<String, T>> for AsNamedMap
+where
+    T: DeserializeOwned + FromName,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<BTreeMap<String, T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        NamedMap::<T>::deserialize(deserializer).map(|map| map.into_iter().collect())
+    }
+}
+
+impl<T, S> SerializeAs<HashMap<String, T, S>> for AsNamedMap
+where
+    T: Serialize,
+{
+    fn serialize_as<Ser>(
+        source: &HashMap<String, T, S>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        serializer.collect_map(source)
+    }
+}
+
+impl<T> SerializeAs<BTreeMap<String, T>> for AsNamedMap
+where
+    T: Serialize,
+{
+    fn serialize_as<Ser>(
+        source: &BTreeMap<String, T>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        serializer.collect_map(source)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_with::serde_as;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Interface {
+        version: String,
+    }
+
+    impl FromName for Interface {
+        fn from_name(_name: &str) -> Self {
+            Self {
+                version: "latest".to_string(),
+            }
+        }
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Manifest {
+        #[serde_as(as = "AsNamedMap")]
+        hashed: HashMap<String, Interface>,
+        #[serde_as(as = "AsNamedMap")]
+        sorted: BTreeMap<String, Interface>,
+    }
+
+    #[test]
+    fn test_array_shorthand_for_both_targets() {
+        let manifest: Manifest = toml::from_str(
+            r#"
+            hashed = ["logger", { http = { version = "0.2" } }]
+            sorted = ["b", "a"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(manifest.hashed["logger"], Interface::from_name("logger"));
+        assert_eq!(manifest.hashed["http"].version, "0.2");
+        assert_eq!(manifest.sorted.keys().collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_object_form_roundtrip() {
+        let manifest: Manifest = toml::from_str(
+            r#"
+            hashed = ["logger"]
+            sorted = { a = { version = "1.0" } }
+            "#,
+        )
+        .unwrap();
+        let restored: Manifest = toml::from_str(&toml::to_string(&manifest).unwrap()).unwrap();
+        assert_eq!(manifest, restored);
+    }
+}
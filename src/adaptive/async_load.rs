@@ -0,0 +1,161 @@
+// flexicon/src/adaptive/async_load.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Async (tokio) counterparts of the file helpers in `load.rs`.
+//!
+//! These read or write the whole file asynchronously and parse in memory;
+//! parsing itself is not incremental.
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::Path;
+
+use super::load::{detect, io_error, parse, render};
+use super::{Format, FromName, LoadError, NamedMap};
+
+impl<T> NamedMap<T>
+where
+    T: DeserializeOwned + FromName + Clone,
+{
+    /// Async version of [`from_path`](Self::from_path).
+    pub async fn from_path_async(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        Self::from_path_with_format_async(path, detect(path)?).await
+    }
+
+    /// Async version of [`from_path_with_format`](Self::from_path_with_format).
+    pub async fn from_path_with_format_async(
+        path: impl AsRef<Path>,
+        format: Format,
+    ) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+        let text = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|err| io_error(path, err))?;
+        let result = parse(&text, format, path);
+        #[cfg(feature = "tracing")]
+        super::load::trace_load(path, format, started, result.is_ok());
+        result
+    }
+
+    /// Async version of [`from_json_reader`](Self::from_json_reader): reads
+    /// the stream to the end, then parses it.
+    #[cfg(feature = "serde_json")]
+    pub async fn from_json_reader_async<R>(mut reader: R) -> serde_json::Result<Self>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        use tokio::io::AsyncReadExt;
+
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(serde_json::Error::io)?;
+        serde_json::from_slice(&bytes)
+    }
+}
+
+impl<T> NamedMap<T>
+where
+    T: Serialize,
+{
+    /// Async version of [`save_to_path`](Self::save_to_path).
+    pub async fn save_to_path_async(&self, path: impl AsRef<Path>) -> Result<(), LoadError> {
+        let path = path.as_ref();
+        self.save_to_path_with_format_async(path, detect(path)?)
+            .await
+    }
+
+    /// Async version of [`save_to_path_with_format`](Self::save_to_path_with_format).
+    pub async fn save_to_path_with_format_async(
+        &self,
+        path: impl AsRef<Path>,
+        format: Format,
+    ) -> Result<(), LoadError> {
+        let path = path.as_ref();
+        let text = render(self, format, path)?;
+        tokio::fs::write(path, text)
+            .await
+            .map_err(|err| io_error(path, err))
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::path::PathBuf;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Plugin {
+        enabled: bool,
+    }
+
+    impl FromName for Plugin {
+        fn from_name(_name: &str) -> Self {
+            Plugin { enabled: true }
+        }
+    }
+
+    fn temp_dir(test: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("flexicon-async-{}-{}", test, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_unknown_extension_and_missing_file() {
+        let dir = temp_dir("errors");
+        let err = NamedMap::<Plugin>::from_path_async(dir.join("plugins.ini"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LoadError::UnknownFormat { .. }), "{}", err);
+
+        let err = NamedMap::<Plugin>::from_path_async(dir.join("absent.toml"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LoadError::Io { .. }), "{}", err);
+    }
+
+    #[cfg(all(feature = "serde_json", feature = "toml"))]
+    #[tokio::test]
+    async fn test_roundtrip() {
+        let dir = temp_dir("roundtrip");
+        let mut map = NamedMap::from(vec!["logger".to_string()]);
+        map.insert("http".to_string(), Plugin { enabled: false });
+
+        for name in ["plugins.json", "plugins.toml"] {
+            let path = dir.join(name);
+            map.save_to_path_async(&path).await.unwrap();
+            assert_eq!(NamedMap::from_path_async(&path).await.unwrap(), map);
+            // The sync and async paths agree.
+            assert_eq!(NamedMap::from_path(&path).unwrap(), map);
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[tokio::test]
+    async fn test_from_json_reader_async() {
+        let map = NamedMap::<Plugin>::from_json_reader_async(&br#"["a", "b"]"#[..])
+            .await
+            .unwrap();
+        assert_eq!(map.len(), 2);
+
+        let err = NamedMap::<Plugin>::from_json_reader_async(&br#"{"a": 1}"#[..])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("error in entry \"a\""), "{}", err);
+    }
+}
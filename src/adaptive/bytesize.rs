@@ -0,0 +1,551 @@
+// flexicon/src/adaptive/bytesize.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Byte counts written as `"10MiB"`-style strings or as plain integers.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+
+/// Canonical suffixes used when writing sizes, largest first within each
+/// family.
+const UNITS: [(&str, u128); 12] = [
+    ("EiB", 1 << 60),
+    ("PiB", 1 << 50),
+    ("TiB", 1 << 40),
+    ("GiB", 1 << 30),
+    ("MiB", 1 << 20),
+    ("KiB", 1 << 10),
+    ("EB", 1_000_000_000_000_000_000),
+    ("PB", 1_000_000_000_000_000),
+    ("TB", 1_000_000_000_000),
+    ("GB", 1_000_000_000),
+    ("MB", 1_000_000),
+    ("kB", 1_000),
+];
+
+/// Longest fraction accepted, in digits after trailing zeros are dropped.
+const MAX_FRACTION_DIGITS: usize = 20;
+
+/// A string or number that is not a valid byte size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ByteSizeParseError {
+    /// The rejected input, as written.
+    pub input: String,
+    /// What is wrong with it.
+    pub reason: &'static str,
+}
+
+impl ByteSizeParseError {
+    fn new(input: impl ToString, reason: &'static str) -> Self {
+        ByteSizeParseError {
+            input: input.to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for ByteSizeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid byte size {:?}: {}", self.input, self.reason)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ByteSizeParseError {}
+
+/// A number of bytes that can be written as `1048576`, `"1MiB"`, `"10MB"`
+/// or `"512k"`.
+///
+/// Suffixes are case-insensitive. `k`, `m`, `g`, `t`, `p` and `e`, alone or
+/// followed by `b`, are SI units (powers of 1000); followed by `i` or `ib`
+/// they are IEC units (powers of 1024). `b` or no suffix means bytes.
+/// Fractions such as `"1.5GiB"` are accepted when they come out to whole
+/// bytes, and sizes beyond `u64::MAX` are rejected.
+///
+/// With the `serde` feature it deserializes from integers and strings and
+/// serializes as the shortest exact form: a string like `"1.5GiB"` or
+/// `"10MB"` when one is shorter, otherwise the plain integer. Formats that
+/// are not human-readable (bincode, postcard, ...) store the `u64`.
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// use flexicon::adaptive::ByteSize;
+///
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() {
+/// let buffer: ByteSize = serde_json::from_str(r#""512k""#).unwrap();
+/// assert_eq!(buffer.as_u64(), 512_000);
+///
+/// let quota: ByteSize = serde_json::from_str("1048576").unwrap();
+/// assert_eq!(serde_json::to_string(&quota).unwrap(), r#""1MiB""#);
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// A size of `bytes` bytes.
+    pub const fn new(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+
+    /// Returns the size in bytes.
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+}
+
+impl From<ByteSize> for u64 {
+    fn from(size: ByteSize) -> Self {
+        size.0
+    }
+}
+
+impl fmt::Display for ByteSize {
+    /// Writes the shortest string that parses back to the same size.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&shortest(self.0))
+    }
+}
+
+impl FromStr for ByteSize {
+    type Err = ByteSizeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+            .map(ByteSize)
+            .map_err(|reason| ByteSizeParseError::new(s, reason))
+    }
+}
+
+/// Length of one `suffix` in bytes, ignoring case.
+fn unit_bytes(suffix: &str) -> Option<u128> {
+    let suffix = suffix.to_ascii_lowercase();
+    let (prefix, rest) = match suffix.char_indices().nth(1) {
+        Some((split, _)) => suffix.split_at(split),
+        None => (suffix.as_str(), ""),
+    };
+    if prefix.is_empty() || prefix == "b" && rest.is_empty() {
+        return Some(1);
+    }
+    let power = ["k", "m", "g", "t", "p", "e"]
+        .iter()
+        .position(|p| *p == prefix)? as u32
+        + 1;
+    match rest {
+        "" | "b" => Some(1000u128.pow(power)),
+        "i" | "ib" => Some(1024u128.pow(power)),
+        _ => None,
+    }
+}
+
+/// Parses `"1.5GiB"`-style input, returning the reason on failure.
+fn parse(input: &str) -> Result<u64, &'static str> {
+    let text = input.trim();
+    if text.is_empty() {
+        return Err("empty string");
+    }
+    if text.starts_with('-') {
+        return Err("must not be negative");
+    }
+
+    let number_len = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let (number, suffix) = text.split_at(number_len);
+    let suffix = suffix.trim_start();
+    if number.is_empty() {
+        return Err("missing number");
+    }
+    let unit = unit_bytes(suffix).ok_or("unknown unit (B, kB, KiB, MB, MiB, ...)")?;
+
+    let (whole, fraction) = match number.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction.trim_end_matches('0')),
+        None => (number, ""),
+    };
+    if whole.is_empty() || number.ends_with('.') || fraction.contains('.') {
+        return Err("malformed number");
+    }
+    let whole: u128 = whole.parse().map_err(|_| "too large")?;
+    let mut bytes = whole.checked_mul(unit).ok_or("too large")?;
+    if !fraction.is_empty() {
+        if fraction.len() > MAX_FRACTION_DIGITS {
+            return Err("too many decimal places");
+        }
+        let scale = 10u128.pow(fraction.len() as u32);
+        let scaled = fraction.parse::<u128>().map_err(|_| "malformed number")? * unit;
+        if scaled % scale != 0 {
+            return Err("not a whole number of bytes");
+        }
+        bytes = bytes.checked_add(scaled / scale).ok_or("too large")?;
+    }
+    u64::try_from(bytes).map_err(|_| "too large")
+}
+
+/// The shortest unit form of `bytes`, if any is shorter than the plain
+/// number.
+fn with_unit(bytes: u64) -> Option<String> {
+    let bytes = u128::from(bytes);
+    let mut best: Option<String> = None;
+    for (suffix, unit) in UNITS {
+        if bytes < unit {
+            continue;
+        }
+        let whole = bytes / unit;
+        let remainder = bytes % unit;
+        let candidate = if remainder == 0 {
+            format!("{}{}", whole, suffix)
+        } else {
+            let Some(digits) =
+                (1..=MAX_FRACTION_DIGITS as u32).find(|&k| (remainder * 10u128.pow(k)) % unit == 0)
+            else {
+                continue;
+            };
+            let fraction = remainder * 10u128.pow(digits) / unit;
+            format!(
+                "{}.{:0width$}{}",
+                whole,
+                fraction,
+                suffix,
+                width = digits as usize
+            )
+        };
+        if best
+            .as_ref()
+            .map_or(true, |best| candidate.len() < best.len())
+        {
+            best = Some(candidate);
+        }
+    }
+    best.filter(|best| best.len() < bytes.to_string().len())
+}
+
+fn shortest(bytes: u64) -> String {
+    with_unit(bytes).unwrap_or_else(|| bytes.to_string())
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use serde::{
+        de::{self, Deserializer, Visitor},
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+
+    struct ByteSizeVisitor;
+
+    impl<'de> Visitor<'de> for ByteSizeVisitor {
+        type Value = ByteSize;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "a byte count or a size such as \"10MiB\" or \"512k\""
+            )
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(ByteSize(v))
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            u64::try_from(v)
+                .map(ByteSize)
+                .map_err(|_| E::custom(ByteSizeParseError::new(v, "must not be negative")))
+        }
+
+        fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            u64::try_from(v)
+                .map(ByteSize)
+                .map_err(|_| E::custom(ByteSizeParseError::new(v, "too large")))
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            // 2^64 is the first float past u64::MAX.
+            if v < 0.0 {
+                Err(E::custom(ByteSizeParseError::new(
+                    v,
+                    "must not be negative",
+                )))
+            } else if v.is_nan() || v >= 18_446_744_073_709_551_616.0 {
+                Err(E::custom(ByteSizeParseError::new(v, "too large")))
+            } else if v as u64 as f64 != v {
+                Err(E::custom(ByteSizeParseError::new(
+                    v,
+                    "not a whole number of bytes",
+                )))
+            } else {
+                Ok(ByteSize(v as u64))
+            }
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            v.parse().map_err(E::custom)
+        }
+    }
+
+    impl Serialize for ByteSize {
+        /// Serializes as the shortest exact form, or as the `u64` in formats
+        /// that are not human-readable.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match with_unit(self.0) {
+                Some(text) if serializer.is_human_readable() => serializer.serialize_str(&text),
+                _ => serializer.serialize_u64(self.0),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ByteSize {
+        /// Deserializes from an integer or a suffixed string.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(ByteSizeVisitor)
+            } else {
+                u64::deserialize(deserializer).map(ByteSize)
+            }
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_ok(s: &str) -> u64 {
+        s.parse::<ByteSize>()
+            .unwrap_or_else(|err| panic!("{}", err))
+            .as_u64()
+    }
+
+    #[test]
+    fn test_parse_every_unit() {
+        for (suffixes, bytes) in [
+            (&["", "b", "B"][..], 1u64),
+            (&["k", "kb", "kB", "K", "KB"][..], 1_000),
+            (&["ki", "kib", "KiB", "KIB"][..], 1 << 10),
+            (&["m", "mb", "MB"][..], 1_000_000),
+            (&["mi", "mib", "MiB"][..], 1 << 20),
+            (&["g", "gb", "GB"][..], 1_000_000_000),
+            (&["gi", "gib", "GiB"][..], 1 << 30),
+            (&["t", "tb", "TB"][..], 1_000_000_000_000),
+            (&["ti", "tib", "TiB"][..], 1 << 40),
+            (&["p", "pb", "PB"][..], 1_000_000_000_000_000),
+            (&["pi", "pib", "PiB"][..], 1 << 50),
+            (&["e", "eb", "EB"][..], 1_000_000_000_000_000_000),
+            (&["ei", "eib", "EiB"][..], 1 << 60),
+        ] {
+            for suffix in suffixes {
+                assert_eq!(parse_ok(&format!("1{}", suffix)), bytes, "1{}", suffix);
+                assert_eq!(parse_ok(&format!("3 {}", suffix)), 3 * bytes);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_fractions() {
+        assert_eq!(parse_ok("512k"), 512_000);
+        assert_eq!(parse_ok(" 1048576 "), 1_048_576);
+        assert_eq!(parse_ok("1.5GiB"), 3 << 29);
+        assert_eq!(parse_ok("0.5KiB"), 512);
+        assert_eq!(parse_ok("2.50MB"), 2_500_000);
+        assert_eq!(parse_ok("0.001kB"), 1);
+        assert_eq!(parse_ok("1.0B"), 1);
+        assert_eq!(parse_ok("0.0009765625MiB"), 1024);
+    }
+
+    #[test]
+    fn test_parse_errors_keep_input() {
+        for (input, reason) in [
+            ("", "empty string"),
+            ("-1MB", "must not be negative"),
+            ("MB", "missing number"),
+            ("10XB", "unknown unit"),
+            ("10 megabytes", "unknown unit"),
+            ("10MiBs", "unknown unit"),
+            ("1.5.5MB", "malformed number"),
+            (".5MB", "malformed number"),
+            ("0.5B", "not a whole number of bytes"),
+            ("1.0001KiB", "not a whole number of bytes"),
+            ("1.000000000000000000001EiB", "too many decimal places"),
+            ("16EiB", "too large"),
+            ("18446744073709551616", "too large"),
+            ("99999999999999999999999999999999999999999", "too large"),
+            ("1000000000000000000000000EB", "too large"),
+        ] {
+            let err = input.parse::<ByteSize>().unwrap_err();
+            assert_eq!(err.input, input);
+            assert!(err.reason.starts_with(reason), "{}: {}", input, err);
+            assert!(err.to_string().contains(&format!("{:?}", input)));
+        }
+        assert_eq!(
+            "MB".parse::<ByteSize>().unwrap_err().to_string(),
+            "invalid byte size \"MB\": missing number"
+        );
+        assert_eq!(parse_ok("18446744073709551615"), u64::MAX);
+        assert_eq!(parse_ok("15EiB"), 15 << 60);
+    }
+
+    #[test]
+    fn test_display_is_shortest() {
+        for (bytes, text) in [
+            (0, "0"),
+            (1, "1"),
+            (999, "999"),
+            (1000, "1kB"),
+            (1024, "1024"),
+            (1500, "1500"),
+            (1536, "1536"),
+            (1_048_576, "1MiB"),
+            (1_572_864, "1.5MiB"),
+            (10_000_000, "10MB"),
+            (3 << 29, "1.5GiB"),
+            (1_000_001, "1000001"),
+            (1023, "1023"),
+            (u64::MAX, "18446744073709551615"),
+        ] {
+            assert_eq!(ByteSize::new(bytes).to_string(), text);
+        }
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let mut bytes: u64 = 1;
+        for step in 0..500u64 {
+            bytes = bytes
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(step);
+            for value in [
+                bytes,
+                bytes >> 40,
+                (bytes >> 50) << 20,
+                (bytes >> 54) * 1000,
+            ] {
+                let text = ByteSize::from(value).to_string();
+                assert_eq!(parse_ok(&text), value, "{}", text);
+            }
+        }
+        for (_, unit) in UNITS {
+            let unit = unit as u64;
+            assert_eq!(parse_ok(&ByteSize::new(unit).to_string()), unit);
+            assert_eq!(parse_ok(&ByteSize::new(unit + 1).to_string()), unit + 1);
+        }
+        assert_eq!(u64::from(ByteSize::from(7)), 7);
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod serde_tests {
+        use super::*;
+        use crate::adaptive::{FromName, NamedMap};
+        use serde::{Deserialize, Serialize};
+        use serde_json::json;
+
+        #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+        struct Volume {
+            #[serde(default)]
+            quota: ByteSize,
+        }
+
+        impl FromName for Volume {
+            fn from_name(_name: &str) -> Self {
+                Volume::default()
+            }
+        }
+
+        #[test]
+        fn test_json_inputs() {
+            for (input, bytes) in [
+                (json!(1_048_576), 1_048_576),
+                (json!("1MiB"), 1 << 20),
+                (json!("10MB"), 10_000_000),
+                (json!("512k"), 512_000),
+                (json!(4096.0), 4096),
+            ] {
+                let size: ByteSize = serde_json::from_value(input).unwrap();
+                assert_eq!(size.as_u64(), bytes);
+            }
+
+            for (input, text) in [
+                ("-1", "\"-1\""),
+                ("1.5", "\"1.5\""),
+                ("1e30", "too large"),
+                (r#""16EiB""#, "\"16EiB\": too large"),
+                (r#""lots""#, "\"lots\""),
+            ] {
+                let err = serde_json::from_str::<ByteSize>(input).unwrap_err();
+                assert!(err.to_string().contains(text), "{}", err);
+            }
+            let err = serde_json::from_str::<ByteSize>("true").unwrap_err();
+            assert!(err.to_string().contains("a byte count"), "{}", err);
+        }
+
+        #[test]
+        fn test_round_trip_inside_named_map() {
+            let volumes: NamedMap<Volume> = toml::from_str(
+                "data = { quota = \"1.5GiB\" }\nlogs = { quota = 1000001 }\n\
+                 tmp = { quota = \"512k\" }\n",
+            )
+            .unwrap();
+
+            let json = serde_json::to_value(&volumes).unwrap();
+            assert_eq!(json["data"]["quota"], "1.5GiB");
+            assert_eq!(json["logs"]["quota"], 1_000_001);
+            assert_eq!(json["tmp"]["quota"], "512kB");
+            assert_eq!(
+                serde_json::from_value::<NamedMap<Volume>>(json).unwrap(),
+                volumes
+            );
+
+            let text = toml::to_string(&volumes).unwrap();
+            assert_eq!(toml::from_str::<NamedMap<Volume>>(&text).unwrap(), volumes);
+
+            let bytes = bincode::serialize(&volumes).unwrap();
+            assert_eq!(
+                bincode::deserialize::<NamedMap<Volume>>(&bytes).unwrap(),
+                volumes
+            );
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// flexicon/src/adaptive/canonical.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Deterministic JSON output for caching and change detection.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::NamedMap;
+
+/// Rebuilds every object in `value` with its keys in sorted order.
+///
+/// `serde_json::Map` is already sorted unless serde_json's `preserve_order`
+/// feature is enabled somewhere in the dependency graph; sorting explicitly
+/// keeps the output stable either way.
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(sort_keys).collect()),
+        other => other,
+    }
+}
+
+/// 64-bit FNV-1a: tiny, dependency-free and identical on every platform and
+/// run (unlike `std`'s randomly seeded `DefaultHasher`).
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+impl<T> NamedMap<T>
+where
+    T: Serialize,
+{
+    /// Serialize this map to compact JSON with every object's keys sorted,
+    /// at the top level and in nested values.
+    ///
+    /// Equal maps always produce identical bytes, regardless of insertion
+    /// order or `HashMap` iteration order.
+    pub fn to_canonical_json_string(&self) -> serde_json::Result<String> {
+        serde_json::to_string(&sort_keys(self.to_json_value()?))
+    }
+
+    /// A stable 64-bit digest of [`to_canonical_json_string`](Self::to_canonical_json_string),
+    /// suitable as a cache key.
+    ///
+    /// The value only depends on the map's contents, so it is the same across
+    /// runs, processes and platforms. It is not a cryptographic hash.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let a: NamedMap<u32> = [("x".to_string(), 1), ("y".to_string(), 2)].into_iter().collect();
+    /// let b: NamedMap<u32> = [("y".to_string(), 2), ("x".to_string(), 1)].into_iter().collect();
+    /// assert_eq!(a.content_hash().unwrap(), b.content_hash().unwrap());
+    /// ```
+    pub fn content_hash(&self) -> serde_json::Result<u64> {
+        self.to_canonical_json_string()
+            .map(|json| fnv1a_64(json.as_bytes()))
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn build(order: &[&str]) -> NamedMap<Value> {
+        order
+            .iter()
+            .map(|name| {
+                let value = json!({ "z": 1, "a": { "y": [ { "b": 2, "a": 1 } ], "x": name } });
+                (name.to_string(), value)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_canonical_json_is_sorted_recursively() {
+        let map = build(&["b", "a"]);
+        assert_eq!(
+            map.to_canonical_json_string().unwrap(),
+            r#"{"a":{"a":{"x":"a","y":[{"a":1,"b":2}]},"z":1},"b":{"a":{"x":"b","y":[{"a":1,"b":2}]},"z":1}}"#
+        );
+    }
+
+    #[test]
+    fn test_hash_independent_of_insertion_order() {
+        let names = ["logger", "http", "metrics", "cache", "auth"];
+        let expected = build(&names).content_hash().unwrap();
+        let mut reversed = names;
+        reversed.reverse();
+        assert_eq!(build(&reversed).content_hash().unwrap(), expected);
+        assert_eq!(
+            build(&["cache", "auth", "logger", "metrics", "http"])
+                .content_hash()
+                .unwrap(),
+            expected
+        );
+        assert_ne!(build(&names[..4]).content_hash().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_hash_is_stable_across_runs() {
+        assert_eq!(fnv1a_64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+        let map: NamedMap<u32> = [("x".to_string(), 1)].into_iter().collect();
+        assert_eq!(map.content_hash().unwrap(), fnv1a_64(br#"{"x":1}"#));
+    }
+}
@@ -0,0 +1,415 @@
+// flexicon/src/adaptive/caseinsensitivenamedmap.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use core::ops::Index;
+
+use super::map::{Entry, HashMap};
+use super::{FromName, NamedMap};
+
+/// The canonical form of a name: its Unicode lowercase.
+fn fold(name: &str) -> String {
+    name.to_lowercase()
+}
+
+/// A variant of [`NamedMap`] whose names are case-insensitive.
+///
+/// `Logger`, `logger` and `LOGGER` are the same entry: lookups accept any
+/// casing, while the spelling an entry was first given is kept for
+/// iteration and serialization, so hand-edited files round-trip unchanged.
+///
+/// Deserialization rejects names that differ only by case.
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # #[derive(Clone, Serialize, Deserialize)]
+/// # struct Plugin { enabled: bool }
+/// # impl flexicon::adaptive::FromName for Plugin {
+/// #     fn from_name(_name: &str) -> Self { Self { enabled: true } }
+/// # }
+/// use flexicon::adaptive::CaseInsensitiveNamedMap;
+///
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() -> Result<(), serde_json::Error> {
+/// let plugins: CaseInsensitiveNamedMap<Plugin> = serde_json::from_str(r#"["Logger"]"#)?;
+/// assert!(plugins.get("LOGGER").is_some());
+/// assert_eq!(serde_json::to_string(&plugins)?, r#"{"Logger":{"enabled":true}}"#);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaseInsensitiveNamedMap<T>(HashMap<String, (String, T)>);
+
+impl<T> CaseInsensitiveNamedMap<T> {
+    /// Creates an empty `CaseInsensitiveNamedMap`.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Inserts a key-value pair, returning the value it replaced, if any.
+    ///
+    /// Like `HashMap::insert`, replacing a value keeps the existing key:
+    /// the entry keeps the spelling it was first given.
+    pub fn insert(&mut self, key: String, value: T) -> Option<T> {
+        match self.0.entry(fold(&key)) {
+            Entry::Occupied(mut entry) => Some(core::mem::replace(&mut entry.get_mut().1, value)),
+            Entry::Vacant(entry) => {
+                entry.insert((key, value));
+                None
+            }
+        }
+    }
+
+    /// Returns the value of `name`, in any casing.
+    pub fn get(&self, name: &str) -> Option<&T> {
+        self.0.get(&fold(name)).map(|(_, value)| value)
+    }
+
+    /// Returns a mutable reference to the value of `name`, in any casing.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut T> {
+        self.0.get_mut(&fold(name)).map(|(_, value)| value)
+    }
+
+    /// Returns `true` if the map has an entry for `name`, in any casing.
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.0.contains_key(&fold(name))
+    }
+
+    /// Returns the spelling the entry for `name` was given.
+    pub fn original_name(&self, name: &str) -> Option<&str> {
+        self.0
+            .get(&fold(name))
+            .map(|(original, _)| original.as_str())
+    }
+
+    /// Removes the entry for `name`, in any casing, returning its original
+    /// spelling and value.
+    pub fn remove(&mut self, name: &str) -> Option<(String, T)> {
+        self.0.remove(&fold(name))
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the entries, with their original spelling, in
+    /// arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.0
+            .values()
+            .map(|(original, value)| (original.as_str(), value))
+    }
+
+    /// Iterates over the names, in their original spelling.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.iter().map(|(name, _)| name)
+    }
+
+    /// Iterates over the values.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.0.values().map(|(_, value)| value)
+    }
+}
+
+impl<T> Default for CaseInsensitiveNamedMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<&str> for CaseInsensitiveNamedMap<T> {
+    type Output = T;
+
+    /// Returns the value of `name`, in any casing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such entry.
+    fn index(&self, name: &str) -> &T {
+        self.get(name).expect("no entry found for name")
+    }
+}
+
+// Keys keep their original spelling.
+impl<T> From<CaseInsensitiveNamedMap<T>> for NamedMap<T> {
+    fn from(map: CaseInsensitiveNamedMap<T>) -> Self {
+        map.0.into_values().collect()
+    }
+}
+
+impl<T> FromIterator<(String, T)> for CaseInsensitiveNamedMap<T> {
+    /// Collects pairs; a later name differing only by case replaces the
+    /// value but keeps the first spelling.
+    fn from_iter<I: IntoIterator<Item = (String, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (name, value) in iter {
+            map.insert(name, value);
+        }
+        map
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::adaptive::seq::{
+        cautious_capacity, deserialize_adaptive, next_named_value, Key, KeySeed, SeqItem,
+    };
+    use alloc::string::ToString;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::{
+        de::{self, Deserializer, MapAccess, SeqAccess, Visitor},
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+
+    impl<T> CaseInsensitiveNamedMap<T> {
+        /// Inserts a new entry, rejecting a name already present in any
+        /// casing; both spellings are named in the error.
+        fn insert_new<E: de::Error>(&mut self, name: String, value: T) -> Result<(), E> {
+            match self.0.entry(fold(&name)) {
+                Entry::Occupied(entry) if entry.get().0 == name => {
+                    Err(E::custom(format_args!("duplicate name {:?}", name)))
+                }
+                Entry::Occupied(entry) => Err(E::custom(format_args!(
+                    "names {:?} and {:?} differ only by case",
+                    entry.get().0,
+                    name
+                ))),
+                Entry::Vacant(entry) => {
+                    entry.insert((name, value));
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    struct CaseInsensitiveVisitor<T> {
+        /// Whether the input can be driven with `deserialize_any`.
+        self_describing: bool,
+        _phantom: PhantomData<T>,
+    }
+
+    impl<'de, T> Visitor<'de> for CaseInsensitiveVisitor<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        type Value = CaseInsensitiveNamedMap<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a map (e.g., {{ \"a\": {{...}} }}), a sequence of names and single-key maps (e.g., [\"a\", {{ \"b\": {{...}} }}]), or a single name (e.g., \"a\")")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            self.visit_string(v.to_string())
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if v.is_empty() {
+                return Err(de::Error::invalid_value(
+                    de::Unexpected::Str(&v),
+                    &"a non-empty name",
+                ));
+            }
+            let value = T::from_name(&v);
+            let mut map = CaseInsensitiveNamedMap::new();
+            map.insert(v, value);
+            Ok(map)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut map =
+                CaseInsensitiveNamedMap(HashMap::with_capacity(cautious_capacity::<(String, T)>(
+                    seq.size_hint(),
+                )));
+            while let Some(item) = seq.next_element::<SeqItem<T>>()? {
+                let (name, value) = item.into_entry();
+                map.insert_new(name, value)?;
+            }
+            Ok(map)
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut map =
+                CaseInsensitiveNamedMap(HashMap::with_capacity(cautious_capacity::<(String, T)>(
+                    access.size_hint(),
+                )));
+            let seed = KeySeed {
+                self_describing: self.self_describing,
+            };
+            while let Some(Key(name)) = access.next_key_seed(seed)? {
+                let value = next_named_value(&mut access, &name)?;
+                map.insert_new(name.into_owned(), value)?;
+            }
+            Ok(map)
+        }
+    }
+
+    impl<T> Serialize for CaseInsensitiveNamedMap<T>
+    where
+        T: Serialize,
+    {
+        /// Serializes as an object keyed by the original spellings.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_map(self.iter())
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for CaseInsensitiveNamedMap<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        /// Deserializes from the same forms as [`NamedMap`]; names that
+        /// collide once case is ignored are rejected.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let self_describing = deserializer.is_human_readable();
+            deserialize_adaptive(
+                deserializer,
+                CaseInsensitiveVisitor {
+                    self_describing,
+                    _phantom: PhantomData,
+                },
+            )
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    struct Plugin {
+        level: u8,
+    }
+
+    impl FromName for Plugin {
+        fn from_name(_name: &str) -> Self {
+            Self { level: 1 }
+        }
+    }
+
+    #[test]
+    fn test_lookup_across_casings() {
+        let mut map = CaseInsensitiveNamedMap::new();
+        assert_eq!(map.insert("Logger".to_string(), Plugin { level: 2 }), None);
+        for name in ["Logger", "logger", "LOGGER", "lOgGeR"] {
+            assert_eq!(map.get(name), Some(&Plugin { level: 2 }), "{}", name);
+            assert!(map.contains_key(name));
+        }
+        assert_eq!(map["LOGGER"].level, 2);
+
+        // Replacing keeps the first spelling.
+        assert_eq!(
+            map.insert("LOGGER".to_string(), Plugin { level: 3 }),
+            Some(Plugin { level: 2 })
+        );
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.original_name("logger"), Some("Logger"));
+        map.get_mut("logger").unwrap().level = 4;
+
+        let named: NamedMap<Plugin> = map.clone().into();
+        assert_eq!(named["Logger"].level, 4);
+
+        assert_eq!(
+            map.remove("lOgGeR"),
+            Some(("Logger".to_string(), Plugin { level: 4 }))
+        );
+        assert!(map.is_empty());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_roundtrip_preserves_original_case() {
+        let map: CaseInsensitiveNamedMap<Plugin> =
+            serde_json::from_str(r#"["Logger", {"HTTP": {"level": 3}}]"#).unwrap();
+        assert_eq!(map["http"].level, 3);
+        let mut keys: Vec<&str> = map.keys().collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["HTTP", "Logger"]);
+
+        let json = serde_json::to_value(&map).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"Logger": {"level": 1}, "HTTP": {"level": 3}})
+        );
+        let restored: CaseInsensitiveNamedMap<Plugin> = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, map);
+
+        let bytes = bincode::serialize(&map).unwrap();
+        assert_eq!(
+            bincode::deserialize::<CaseInsensitiveNamedMap<Plugin>>(&bytes).unwrap(),
+            map
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_collision_names_both_spellings() {
+        let err = serde_json::from_str::<CaseInsensitiveNamedMap<Plugin>>(
+            r#"{"Logger": {"level": 1}, "LOGGER": {"level": 2}}"#,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("names \"Logger\" and \"LOGGER\" differ only by case"),
+            "{}",
+            err
+        );
+
+        let err = serde_json::from_str::<CaseInsensitiveNamedMap<Plugin>>(r#"["http", "Http"]"#)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("names \"http\" and \"Http\" differ only by case"),
+            "{}",
+            err
+        );
+
+        let err = serde_json::from_str::<CaseInsensitiveNamedMap<Plugin>>(r#"["http", "http"]"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("duplicate name \"http\""), "{}", err);
+    }
+}
@@ -0,0 +1,173 @@
+// flexicon/src/adaptive/cli.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `clap` value parser producing a `NamedMap` from a command-line argument.
+
+use clap::builder::{StyledStr, TypedValueParser};
+use clap::error::{ContextKind, ContextValue, ErrorKind};
+use clap::{Arg, Command, Error};
+use serde::de::DeserializeOwned;
+use std::ffi::OsStr;
+use std::marker::PhantomData;
+
+use super::{FromName, NamedMap};
+
+/// Parses an argument into a [`NamedMap`], accepting JSON or a list of
+/// names:
+///
+/// - `--interfaces '{"logger": {"version": "1"}}'` or `'["logger", "http"]'`
+///   (anything starting with `{` or `[` is parsed as JSON);
+/// - `--interfaces logger,http`, each name going through `FromName`.
+///
+/// ```rust,ignore
+/// let cmd = Command::new("app").arg(
+///     Arg::new("interfaces")
+///         .long("interfaces")
+///         .value_parser(NamedMapValueParser::<Interface>::new()),
+/// );
+/// ```
+pub struct NamedMapValueParser<T> {
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> NamedMapValueParser<T> {
+    /// Creates the parser.
+    pub fn new() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for NamedMapValueParser<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for NamedMapValueParser<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+/// Builds a `ValueValidation` error for `value`, with `reason` as a tip.
+fn invalid_value(cmd: &Command, arg: Option<&Arg>, value: &str, reason: String) -> Error {
+    let mut err = Error::new(ErrorKind::ValueValidation).with_cmd(cmd);
+    let arg = arg.map_or_else(|| "...".to_string(), ToString::to_string);
+    err.insert(ContextKind::InvalidArg, ContextValue::String(arg));
+    err.insert(
+        ContextKind::InvalidValue,
+        ContextValue::String(value.to_string()),
+    );
+    err.insert(
+        ContextKind::Suggested,
+        ContextValue::StyledStrs(vec![StyledStr::from(reason)]),
+    );
+    err
+}
+
+impl<T> TypedValueParser for NamedMapValueParser<T>
+where
+    T: DeserializeOwned + FromName + Clone + Send + Sync + 'static,
+{
+    type Value = NamedMap<T>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let Some(value) = value.to_str() else {
+            return Err(Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd));
+        };
+        if value.trim_start().starts_with(['{', '[']) {
+            serde_json::from_str(value)
+                .map_err(|err| invalid_value(cmd, arg, value, format!("invalid JSON: {}", err)))
+        } else {
+            NamedMap::from_comma_separated(value)
+                .map_err(|err| invalid_value(cmd, arg, value, err.to_string()))
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    struct Interface {
+        version: String,
+    }
+
+    impl FromName for Interface {
+        fn from_name(_name: &str) -> Self {
+            Interface {
+                version: "latest".to_string(),
+            }
+        }
+    }
+
+    fn command() -> Command {
+        Command::new("app").arg(
+            Arg::new("interfaces")
+                .long("interfaces")
+                .value_parser(NamedMapValueParser::<Interface>::new()),
+        )
+    }
+
+    fn parse(value: &str) -> Result<NamedMap<Interface>, Error> {
+        let matches = command().try_get_matches_from(["app", "--interfaces", value])?;
+        Ok(matches
+            .get_one::<NamedMap<Interface>>("interfaces")
+            .unwrap()
+            .clone())
+    }
+
+    #[test]
+    fn test_comma_separated() {
+        let map = parse("logger, http").unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["http"].version, "latest");
+    }
+
+    #[test]
+    fn test_json_forms() {
+        let map = parse(r#"{"logger": {"version": "1"}}"#).unwrap();
+        assert_eq!(map["logger"].version, "1");
+
+        let map = parse(r#"["logger", {"http": {"version": "2"}}]"#).unwrap();
+        assert_eq!(map["logger"].version, "latest");
+        assert_eq!(map["http"].version, "2");
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let err = parse(r#"{"logger": {"version": 1}"#).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        let rendered = err.to_string();
+        assert!(
+            rendered.contains(r#"invalid value '{"logger": {"version": 1}' for '--interfaces"#),
+            "{}",
+            rendered
+        );
+        assert!(rendered.contains("tip: invalid JSON"), "{}", rendered);
+
+        let err = parse("logger,logger").unwrap_err();
+        assert!(
+            err.to_string().contains("duplicate name \"logger\""),
+            "{}",
+            err
+        );
+    }
+}
@@ -0,0 +1,228 @@
+// flexicon/src/adaptive/comma.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::{String, ToString};
+use core::hash::BuildHasher;
+
+use super::map::HashMap;
+use super::{DuplicateNameError, DuplicatePolicy, FromName, NamedMap};
+
+/// Splits `"a, b,,c,"` into trimmed, non-empty names.
+fn split_names(s: &str) -> impl Iterator<Item = String> + '_ {
+    s.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+}
+
+impl<T: FromName, S: BuildHasher + Default> NamedMap<T, S> {
+    /// Builds a map from a comma-separated list of names, as found in
+    /// environment variables and CLI flags (`"logger, http,metrics"`).
+    ///
+    /// Segments are trimmed and empty ones skipped; each name goes through
+    /// `FromName`. Repeated names are rejected, like in the array form.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[derive(Clone)]
+    /// # struct Plugin;
+    /// # impl flexicon::adaptive::FromName for Plugin {
+    /// #     fn from_name(_name: &str) -> Self { Plugin }
+    /// # }
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let map = NamedMap::<Plugin>::from_comma_separated("logger, http,").unwrap();
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    pub fn from_comma_separated(s: &str) -> Result<Self, DuplicateNameError> {
+        Self::from_comma_separated_with_policy(s, DuplicatePolicy::Error)
+    }
+
+    /// Like [`from_comma_separated`](Self::from_comma_separated), handling
+    /// repeated names according to `policy`.
+    pub fn from_comma_separated_with_policy(
+        s: &str,
+        policy: DuplicatePolicy,
+    ) -> Result<Self, DuplicateNameError> {
+        let mut map = HashMap::default();
+        for (index, name) in split_names(s).enumerate() {
+            let value = T::from_name(&name);
+            policy.insert(&mut map, name, value, index)?;
+        }
+        Ok(NamedMap::from(map))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use serde::{
+        de::{
+            self,
+            value::{MapAccessDeserializer, SeqAccessDeserializer},
+            DeserializeOwned, Deserializer, MapAccess, SeqAccess, Visitor,
+        },
+        Deserialize,
+    };
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    /// Visitor that splits strings on commas and defers everything else
+    /// to the regular `NamedMap` impl.
+    struct CommaSeparatedVisitor<T, S> {
+        _phantom: PhantomData<(T, S)>,
+    }
+
+    impl<'de, T, S> Visitor<'de> for CommaSeparatedVisitor<T, S>
+    where
+        T: DeserializeOwned + FromName,
+        S: BuildHasher + Default,
+    {
+        type Value = NamedMap<T, S>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "a comma-separated list of names (e.g., \"a, b\"), a sequence of names, or a map"
+            )
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            NamedMap::from_comma_separated(v).map_err(E::custom)
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            NamedMap::deserialize(SeqAccessDeserializer::new(seq))
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            NamedMap::deserialize(MapAccessDeserializer::new(map))
+        }
+    }
+
+    impl<T, S> NamedMap<T, S>
+    where
+        T: DeserializeOwned + FromName,
+        S: BuildHasher + Default,
+    {
+        /// Deserializes like the `Deserialize` impl, but a string is read as
+        /// a comma-separated list of names (see [`NamedMap::from_comma_separated`]).
+        ///
+        /// Use as `#[serde(deserialize_with = "NamedMap::deserialize_comma_separated")]`.
+        /// Non-human-readable formats only accept the object form, like the
+        /// `Deserialize` impl.
+        pub fn deserialize_comma_separated<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if !deserializer.is_human_readable() {
+                return NamedMap::deserialize(deserializer);
+            }
+            deserializer.deserialize_any(CommaSeparatedVisitor {
+                _phantom: PhantomData,
+            })
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct Item(String);
+
+    impl FromName for Item {
+        fn from_name(name: &str) -> Self {
+            Item(name.to_string())
+        }
+    }
+
+    fn names(map: &NamedMap<Item>) -> Vec<&str> {
+        let mut names: Vec<&str> = map.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_trailing_comma_and_whitespace() {
+        let map = NamedMap::<Item>::from_comma_separated(" logger ,http,  metrics ,,").unwrap();
+        assert_eq!(names(&map), ["http", "logger", "metrics"]);
+        assert_eq!(map["logger"], Item::from_name("logger"));
+    }
+
+    #[test]
+    fn test_single_name() {
+        let map = NamedMap::<Item>::from_comma_separated("logger").unwrap();
+        assert_eq!(names(&map), ["logger"]);
+        assert!(NamedMap::<Item>::from_comma_separated(" , ")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_duplicates_follow_policy() {
+        let err = NamedMap::<Item>::from_comma_separated("a, b, a").unwrap_err();
+        assert_eq!(err.to_string(), "duplicate name \"a\" at index 2");
+
+        let map = NamedMap::<Item>::from_comma_separated_with_policy(
+            "a, b, a",
+            DuplicatePolicy::KeepFirst,
+        )
+        .unwrap();
+        assert_eq!(names(&map), ["a", "b"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_comma_separated() {
+        #[derive(serde::Deserialize)]
+        struct Config {
+            #[serde(deserialize_with = "NamedMap::deserialize_comma_separated")]
+            plugins: NamedMap<Item>,
+        }
+
+        let config: Config = toml::from_str(r#"plugins = "logger, http,""#).unwrap();
+        assert_eq!(names(&config.plugins), ["http", "logger"]);
+
+        let config: Config = toml::from_str(r#"plugins = ["logger"]"#).unwrap();
+        assert_eq!(names(&config.plugins), ["logger"]);
+
+        assert!(toml::from_str::<Config>(r#"plugins = "a,a""#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_bincode_roundtrip() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Config {
+            #[serde(deserialize_with = "NamedMap::deserialize_comma_separated")]
+            plugins: NamedMap<Item>,
+        }
+
+        let original = Config {
+            plugins: NamedMap::from_comma_separated("logger, http").unwrap(),
+        };
+        let bytes = bincode::serialize(&original).unwrap();
+        assert_eq!(bincode::deserialize::<Config>(&bytes).unwrap(), original);
+    }
+}
@@ -0,0 +1,261 @@
+// flexicon/src/adaptive/concurrentnamedmap.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A named map shared between threads, with atomic whole-map reloads.
+
+use parking_lot::RwLock;
+
+use super::NamedMap;
+
+/// A [`NamedMap`] behind a read-write lock, for many reader threads and an
+/// occasional writer such as a configuration reload.
+///
+/// Values are handed out as clones, so no lock is held once a call
+/// returns. [`replace_all`](Self::replace_all) swaps in a whole new map
+/// under a single write lock: every read sees either the old map or the new
+/// one, never a mix.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use flexicon::adaptive::{ConcurrentNamedMap, NamedMap};
+///
+/// let map = Arc::new(ConcurrentNamedMap::new());
+/// map.insert("eth0".to_string(), 1500);
+///
+/// let reader = Arc::clone(&map);
+/// std::thread::spawn(move || assert_eq!(reader.get_cloned("eth0"), Some(1500)))
+///     .join()
+///     .unwrap();
+///
+/// let mut reloaded = NamedMap::new();
+/// reloaded.insert("eth1".to_string(), 9000);
+/// let old = map.replace_all(reloaded);
+/// assert!(old.contains_key("eth0"));
+/// assert_eq!(map.get_cloned("eth0"), None);
+/// ```
+#[derive(Debug)]
+pub struct ConcurrentNamedMap<T> {
+    map: RwLock<NamedMap<T>>,
+}
+
+impl<T> ConcurrentNamedMap<T> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self {
+            map: RwLock::new(NamedMap::new()),
+        }
+    }
+
+    /// Inserts a key-value pair, returning the value it replaced, if any.
+    pub fn insert(&self, key: String, value: T) -> Option<T> {
+        self.map.write().insert(key, value)
+    }
+
+    /// Removes the entry for `name`, returning its value.
+    pub fn remove(&self, name: &str) -> Option<T> {
+        self.map.write().as_inner_mut().remove(name)
+    }
+
+    /// Replaces every entry with those of `map` in one step, returning the
+    /// previous map.
+    pub fn replace_all(&self, map: NamedMap<T>) -> NamedMap<T> {
+        core::mem::replace(&mut *self.map.write(), map)
+    }
+
+    /// Returns `true` if the map contains `name`.
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.map.read().contains_key(name)
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.map.read().len()
+    }
+
+    /// Returns `true` if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.read().is_empty()
+    }
+
+    /// Calls `f` with the current map under the read lock, for reads of
+    /// several entries that must come from the same version.
+    ///
+    /// Writers wait until `f` returns, so keep it short.
+    pub fn with_read<R>(&self, f: impl FnOnce(&NamedMap<T>) -> R) -> R {
+        f(&self.map.read())
+    }
+
+    /// Returns the map.
+    pub fn into_inner(self) -> NamedMap<T> {
+        self.map.into_inner()
+    }
+}
+
+impl<T: Clone> ConcurrentNamedMap<T> {
+    /// Returns a clone of the value of `name`.
+    pub fn get_cloned(&self, name: &str) -> Option<T> {
+        self.map.read().get(name).cloned()
+    }
+
+    /// Returns a clone of the whole map as it is now.
+    pub fn snapshot(&self) -> NamedMap<T> {
+        self.map.read().clone()
+    }
+}
+
+impl<T> Default for ConcurrentNamedMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<NamedMap<T>> for ConcurrentNamedMap<T> {
+    fn from(map: NamedMap<T>) -> Self {
+        Self {
+            map: RwLock::new(map),
+        }
+    }
+}
+
+impl<T> From<ConcurrentNamedMap<T>> for NamedMap<T> {
+    fn from(map: ConcurrentNamedMap<T>) -> Self {
+        map.into_inner()
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::adaptive::FromName;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl<T: Clone + Serialize> Serialize for ConcurrentNamedMap<T> {
+        /// Serializes a [`snapshot`](ConcurrentNamedMap::snapshot), so the
+        /// lock is not held while writing.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.snapshot().serialize(serializer)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for ConcurrentNamedMap<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        /// Deserializes from the same forms as [`NamedMap`].
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            NamedMap::<T>::deserialize(deserializer).map(Self::from)
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    /// A map of `size` interfaces whose MTUs all equal `generation`.
+    fn generation(generation: u32, size: usize) -> NamedMap<u32> {
+        (0..size)
+            .map(|i| (format!("eth{}", i), generation))
+            .collect()
+    }
+
+    #[test]
+    fn test_basic_operations() {
+        let map = ConcurrentNamedMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert("eth0".to_string(), 1500), None);
+        assert_eq!(map.insert("eth0".to_string(), 9000), Some(1500));
+        map.insert("lo".to_string(), 65536);
+        assert_eq!(map.get_cloned("eth0"), Some(9000));
+        assert!(map.contains_key("lo"));
+        assert_eq!(map.remove("lo"), Some(65536));
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.snapshot(), generation(9000, 1));
+        assert_eq!(map.with_read(|map| map.len()), 1);
+        assert_eq!(NamedMap::from(map), generation(9000, 1));
+    }
+
+    #[test]
+    fn test_replace_all_is_never_torn() {
+        const SIZE: usize = 64;
+        let map = Arc::new(ConcurrentNamedMap::from(generation(0, SIZE)));
+        let done = Arc::new(AtomicBool::new(false));
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let map = Arc::clone(&map);
+                let done = Arc::clone(&done);
+                thread::spawn(move || {
+                    let mut reads = 0;
+                    while !done.load(Ordering::Relaxed) || reads == 0 {
+                        let snapshot = map.snapshot();
+                        assert_eq!(snapshot.len(), SIZE);
+                        let first = snapshot["eth0"];
+                        assert!(snapshot.values().all(|mtu| *mtu == first));
+
+                        let consistent = map.with_read(|map| {
+                            let first = map["eth0"];
+                            map.values().all(|mtu| *mtu == first)
+                        });
+                        assert!(consistent);
+                        reads += 1;
+                    }
+                })
+            })
+            .collect();
+
+        for next in 1..=200 {
+            let old = map.replace_all(generation(next, SIZE));
+            assert_eq!(old, generation(next - 1, SIZE));
+        }
+        done.store(true, Ordering::Relaxed);
+        for reader in readers {
+            reader.join().unwrap();
+        }
+        assert_eq!(map.snapshot(), generation(200, SIZE));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_serde_round_trip() {
+        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Interface {
+            mtu: u32,
+        }
+
+        impl crate::adaptive::FromName for Interface {
+            fn from_name(_name: &str) -> Self {
+                Interface { mtu: 1500 }
+            }
+        }
+
+        let map: ConcurrentNamedMap<Interface> =
+            serde_json::from_str(r#"["eth0", {"eth1": {"mtu": 9000}}]"#).unwrap();
+        assert_eq!(map.get_cloned("eth0"), Some(Interface { mtu: 1500 }));
+        assert_eq!(
+            serde_json::to_value(&map).unwrap(),
+            serde_json::json!({"eth0": {"mtu": 1500}, "eth1": {"mtu": 9000}})
+        );
+    }
+}
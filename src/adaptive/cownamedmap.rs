@@ -0,0 +1,252 @@
+// flexicon/src/adaptive/cownamedmap.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Copy-on-write overlays over a borrowed [`NamedMap`].
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use super::map::{HashMap, HashSet};
+use super::NamedMap;
+
+/// Local changes layered over a borrowed base [`NamedMap`], which is never
+/// cloned or modified — e.g. per-request overrides of a shared configuration.
+///
+/// Inserted entries shadow the base, and removed names are hidden through
+/// tombstones. Every read sees the merged view, as does serialization with
+/// the `serde` feature. [`freeze`](Self::freeze) produces a plain map,
+/// cloning only the base entries that are still visible.
+///
+/// # Example
+///
+/// ```rust
+/// use flexicon::adaptive::{CowNamedMap, NamedMap};
+///
+/// let base: NamedMap<u16> = [("http", 80), ("ssh", 22)]
+///     .into_iter()
+///     .map(|(name, port)| (name.to_string(), port))
+///     .collect();
+///
+/// let mut overlay = CowNamedMap::new(&base);
+/// overlay.insert("http".to_string(), 8080);
+/// overlay.remove("ssh");
+/// assert_eq!(overlay.get("http"), Some(&8080));
+/// assert_eq!(overlay.get("ssh"), None);
+/// assert_eq!(base["http"], 80);
+///
+/// let frozen = overlay.freeze();
+/// assert_eq!(frozen.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CowNamedMap<'a, T> {
+    base: &'a NamedMap<T>,
+    overlay: HashMap<String, T>,
+    removed: HashSet<String>,
+}
+
+impl<'a, T> CowNamedMap<'a, T> {
+    /// Creates an overlay with no changes over `base`.
+    pub fn new(base: &'a NamedMap<T>) -> Self {
+        Self {
+            base,
+            overlay: HashMap::new(),
+            removed: HashSet::new(),
+        }
+    }
+
+    /// Returns the base map, without the local changes.
+    pub fn base(&self) -> &'a NamedMap<T> {
+        self.base
+    }
+
+    /// Returns `true` if nothing has been inserted or removed.
+    pub fn is_unchanged(&self) -> bool {
+        self.overlay.is_empty() && self.removed.is_empty()
+    }
+
+    /// Returns the value of `name` in the merged view.
+    pub fn get(&self, name: &str) -> Option<&T> {
+        match self.overlay.get(name) {
+            Some(value) => Some(value),
+            None if self.removed.contains(name) => None,
+            None => self.base.get(name),
+        }
+    }
+
+    /// Returns `true` if `name` is visible in the merged view.
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Iterates over the merged view in arbitrary order: overlay entries
+    /// first, then the base entries they neither shadow nor remove.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &T)> + '_ {
+        self.overlay
+            .iter()
+            .chain(self.base.iter().filter(|(name, _)| {
+                !self.overlay.contains_key(name.as_str()) && !self.removed.contains(name.as_str())
+            }))
+    }
+
+    /// Returns the number of entries in the merged view.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns `true` if the merged view has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+}
+
+impl<'a, T: Clone> CowNamedMap<'a, T> {
+    /// Inserts a value into the overlay, lifting any tombstone for `key`.
+    ///
+    /// Returns the value that was visible before: owned if it came from
+    /// the overlay, borrowed if it came from the base.
+    pub fn insert(&mut self, key: String, value: T) -> Option<Cow<'a, T>> {
+        let was_removed = self.removed.remove(key.as_str());
+        let base = if was_removed {
+            None
+        } else {
+            self.base.get(key.as_str())
+        };
+        match self.overlay.insert(key, value) {
+            Some(previous) => Some(Cow::Owned(previous)),
+            None => base.map(Cow::Borrowed),
+        }
+    }
+
+    /// Hides `name` from the merged view, returning the value that was
+    /// visible: owned if it came from the overlay, borrowed if it came from
+    /// the base.
+    pub fn remove(&mut self, name: &str) -> Option<Cow<'a, T>> {
+        let base = if self.removed.contains(name) {
+            None
+        } else {
+            self.base.get(name)
+        };
+        if base.is_some() {
+            self.removed.insert(String::from(name));
+        }
+        match self.overlay.remove(name) {
+            Some(previous) => Some(Cow::Owned(previous)),
+            None => base.map(Cow::Borrowed),
+        }
+    }
+
+    /// Returns the merged view as a plain map, cloning the base entries
+    /// that are still visible and moving the overlay.
+    pub fn freeze(self) -> NamedMap<T> {
+        let mut map = NamedMap::from(self.overlay);
+        let inner = map.as_inner_mut();
+        for (name, value) in self.base.iter() {
+            if !inner.contains_key(name.as_str()) && !self.removed.contains(name.as_str()) {
+                inner.insert(name.clone(), value.clone());
+            }
+        }
+        map
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for CowNamedMap<'_, T> {
+    /// Serializes the merged view in the object form.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.iter())
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn ports() -> NamedMap<u16> {
+        [("http", 80), ("https", 443), ("ssh", 22)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect()
+    }
+
+    #[test]
+    fn test_overlay_shadows_base() {
+        let base = ports();
+        let mut map = CowNamedMap::new(&base);
+        assert!(map.is_unchanged());
+        assert_eq!(map.len(), 3);
+
+        assert_eq!(
+            map.insert("http".to_string(), 8080),
+            Some(Cow::Borrowed(&80))
+        );
+        assert_eq!(map.insert("http".to_string(), 8081), Some(Cow::Owned(8080)));
+        assert_eq!(map.insert("dns".to_string(), 53), None);
+        assert_eq!(map.get("http"), Some(&8081));
+        assert_eq!(map.get("ssh"), Some(&22));
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.iter().filter(|(name, _)| *name == "http").count(), 1);
+        assert_eq!(base["http"], 80);
+    }
+
+    #[test]
+    fn test_remove_then_reinsert() {
+        let base = ports();
+        let mut map = CowNamedMap::new(&base);
+        assert_eq!(map.remove("ssh"), Some(Cow::Borrowed(&22)));
+        assert_eq!(map.remove("ssh"), None);
+        assert!(!map.contains_key("ssh"));
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.insert("ssh".to_string(), 2222), None);
+        assert_eq!(map.get("ssh"), Some(&2222));
+
+        assert_eq!(map.remove("ssh"), Some(Cow::Owned(2222)));
+        assert_eq!(map.get("ssh"), None);
+        assert_eq!(map.remove("gopher"), None);
+        assert!(!map.is_unchanged());
+    }
+
+    #[test]
+    fn test_freeze() {
+        let base = ports();
+        let mut map = CowNamedMap::new(&base);
+        map.insert("http".to_string(), 8080);
+        map.insert("dns".to_string(), 53);
+        map.remove("https");
+        let frozen = map.freeze();
+
+        let expected: NamedMap<u16> = [("http", 8080), ("ssh", 22), ("dns", 53)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect();
+        assert_eq!(frozen, expected);
+        assert_eq!(CowNamedMap::new(&base).freeze(), base);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_serializes_merged_view() {
+        let base = ports();
+        let mut map = CowNamedMap::new(&base);
+        map.insert("http".to_string(), 8080);
+        map.remove("https");
+        assert_eq!(
+            serde_json::to_value(&map).unwrap(),
+            serde_json::json!({"http": 8080, "ssh": 22})
+        );
+    }
+}
@@ -0,0 +1,326 @@
+// flexicon/src/adaptive/defaults.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Partial object values merged over `FromName` defaults.
+
+use serde::{
+    de::{self, DeserializeOwned, Deserializer, MapAccess, SeqAccess},
+    Serialize,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use super::namedmap::{NamedMapVisitor, Place};
+use super::seq::{next_named_value, SeqItem};
+use super::{DuplicateNameError, DuplicatePolicy, FromName, NamePolicy, NamedMap};
+
+/// Deep-merges `patch` into `base`.
+///
+/// Objects merge key by key, recursively; any other patch value (arrays,
+/// scalars and an explicit `null`) replaces the base value wholesale.
+pub(super) fn deep_merge(base: &mut Value, patch: Value) {
+    match (base, patch) {
+        (Value::Object(base), Value::Object(patch)) => {
+            for (key, value) in patch {
+                match base.get_mut(&key) {
+                    Some(slot) => deep_merge(slot, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, patch) => *base = patch,
+    }
+}
+
+/// Builds the value for `name`: `T::from_name(name)`, with `partial`
+/// deep-merged on top.
+fn resolve<T>(name: &str, partial: Value) -> serde_json::Result<T>
+where
+    T: Serialize + DeserializeOwned + FromName,
+{
+    let mut value = serde_json::to_value(T::from_name(name))?;
+    deep_merge(&mut value, partial);
+    serde_json::from_value(value)
+}
+
+/// [`resolve`], reporting failures as a deserialization error naming the entry.
+fn resolve_entry<T, E>(name: &str, partial: Value) -> Result<T, E>
+where
+    T: Serialize + DeserializeOwned + FromName,
+    E: de::Error,
+{
+    resolve(name, partial)
+        .map_err(|err| E::custom(format_args!("error in entry {:?}: {}", name, err)))
+}
+
+/// The plain map, filled by `NamedMapVisitor` with values read as
+/// partial JSON and resolved as soon as their name is known.
+struct WithDefaults<T, S>(HashMap<String, T, S>);
+
+impl<T, S> Place for WithDefaults<T, S>
+where
+    T: Serialize + DeserializeOwned + FromName,
+    S: BuildHasher,
+{
+    type Value = T;
+    type Meta = ();
+
+    fn name_meta() {}
+
+    fn from_name<E: de::Error>(name: &str) -> Result<T, E> {
+        Ok(T::from_name(name))
+    }
+
+    fn next_item<'de, A>(seq: &mut A, _nullable: bool) -> Result<Option<(SeqItem<T>, ())>, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let item = match seq.next_element::<SeqItem<Value>>()? {
+            None => return Ok(None),
+            Some(SeqItem::Name(name)) => SeqItem::Name(name),
+            Some(SeqItem::Entry(name, partial)) => {
+                let value = resolve_entry(&name, partial)?;
+                SeqItem::Entry(name, value)
+            }
+        };
+        Ok(Some((item, ())))
+    }
+
+    fn next_value<'de, A>(access: &mut A, name: &str, _nullable: bool) -> Result<(T, ()), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let partial = next_named_value(access, name)?;
+        Ok((resolve_entry(name, partial)?, ()))
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn contains_key(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn insert(
+        &mut self,
+        policy: DuplicatePolicy,
+        name: String,
+        value: T,
+        _meta: (),
+        index: usize,
+    ) -> Result<(), DuplicateNameError> {
+        policy.insert(&mut self.0, name, value, index)
+    }
+}
+
+impl<T, S> NamedMap<T, S>
+where
+    T: Serialize + DeserializeOwned + FromName,
+    S: BuildHasher + Default,
+{
+    /// Deserializes a map whose detailed values may be **partial**.
+    ///
+    /// Each value is built as `T::from_name(key)`, converted to JSON,
+    /// deep-merged with the user-provided object, and only then
+    /// deserialized into `T`. Nested objects merge recursively, arrays
+    /// replace wholesale, and an explicit `null` wins over the default.
+    /// Otherwise accepts the same forms, with the same checks, as the
+    /// `Deserialize` impl.
+    ///
+    /// Use as `#[serde(deserialize_with = "NamedMap::deserialize_with_defaults")]`;
+    /// works with any self-describing format, not only JSON. Non-human-readable
+    /// formats (bincode, postcard, ...) read complete values in the object form,
+    /// like the `Deserialize` impl.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use serde::{Deserialize, Serialize};
+    /// use flexicon::adaptive::{FromName, NamedMap};
+    ///
+    /// #[derive(Clone, Serialize, Deserialize)]
+    /// struct Interface { version: String, optional: bool }
+    ///
+    /// impl FromName for Interface {
+    ///     fn from_name(_name: &str) -> Self {
+    ///         Self { version: "latest".into(), optional: false }
+    ///     }
+    /// }
+    ///
+    /// let value = serde_json::json!({ "logger": { "optional": true } });
+    /// let map = NamedMap::<Interface>::from_json_value_with_defaults(value).unwrap();
+    /// assert_eq!(map["logger"].version, "latest");
+    /// assert!(map["logger"].optional);
+    /// ```
+    pub fn deserialize_with_defaults<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            // Binary formats carry exactly what `Serialize` wrote: complete values.
+            return serde::Deserialize::deserialize(deserializer);
+        }
+        let mut map = WithDefaults(HashMap::default());
+        deserializer.deserialize_any(NamedMapVisitor {
+            place: &mut map,
+            policy: DuplicatePolicy::Error,
+            names: NamePolicy::RejectEmpty,
+            nullable: false,
+            self_describing: true,
+        })?;
+        Ok(NamedMap::from(map.0))
+    }
+
+    /// Parse a `NamedMap` from a `serde_json::Value`, merging partial
+    /// values over `FromName` defaults (see [`deserialize_with_defaults`](Self::deserialize_with_defaults)).
+    pub fn from_json_value_with_defaults(value: Value) -> serde_json::Result<Self> {
+        Self::deserialize_with_defaults(value)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Limits {
+        cpu: u32,
+        memory: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Interface {
+        version: String,
+        optional: bool,
+        limits: Limits,
+        tags: Vec<String>,
+        comment: Option<String>,
+    }
+
+    impl FromName for Interface {
+        fn from_name(name: &str) -> Self {
+            Self {
+                version: "latest".to_string(),
+                optional: false,
+                limits: Limits { cpu: 1, memory: 64 },
+                tags: vec!["default".to_string()],
+                comment: Some(format!("{} interface", name)),
+            }
+        }
+    }
+
+    fn parse(value: Value) -> NamedMap<Interface> {
+        NamedMap::from_json_value_with_defaults(value).unwrap()
+    }
+
+    #[test]
+    fn test_partial_value_inherits_defaults() {
+        let map = parse(json!({ "logger": { "optional": true } }));
+        let logger = &map["logger"];
+        assert!(logger.optional);
+        assert_eq!(logger.version, "latest");
+        assert_eq!(logger.comment.as_deref(), Some("logger interface"));
+    }
+
+    #[test]
+    fn test_nested_objects_merge_recursively() {
+        let map = parse(json!({ "http": { "limits": { "memory": 512 } } }));
+        assert_eq!(map["http"].limits, Limits { cpu: 1, memory: 512 });
+    }
+
+    #[test]
+    fn test_arrays_replace_wholesale() {
+        let map = parse(json!({ "http": { "tags": ["a", "b"] } }));
+        assert_eq!(map["http"].tags, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_explicit_null_wins() {
+        let map = parse(json!({ "http": { "comment": null } }));
+        assert_eq!(map["http"].comment, None);
+    }
+
+    #[test]
+    fn test_mixed_array_and_toml() {
+        #[derive(Deserialize)]
+        struct Manifest {
+            #[serde(deserialize_with = "NamedMap::deserialize_with_defaults")]
+            interfaces: NamedMap<Interface>,
+        }
+
+        let manifest: Manifest = toml::from_str(
+            r#"interfaces = ["logger", { http = { version = "0.2" } }]"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.interfaces["logger"], Interface::from_name("logger"));
+        assert_eq!(manifest.interfaces["http"].version, "0.2");
+        assert_eq!(manifest.interfaces["http"].limits, Limits { cpu: 1, memory: 64 });
+    }
+
+    #[test]
+    fn test_checked_like_named_map() {
+        let map = parse(json!("logger"));
+        assert_eq!(map["logger"], Interface::from_name("logger"));
+
+        let err = NamedMap::<Interface>::from_json_value_with_defaults(json!([
+            "logger",
+            { "logger": { "optional": true } }
+        ]))
+        .unwrap_err();
+        assert_eq!(err.to_string(), "duplicate name \"logger\" at index 1");
+
+        let err =
+            NamedMap::<Interface>::from_json_value_with_defaults(json!({ " ": {} })).unwrap_err();
+        assert_eq!(err.to_string(), "empty key \" \"");
+    }
+
+    #[test]
+    fn test_invalid_partial_names_key() {
+        let err = NamedMap::<Interface>::from_json_value_with_defaults(json!({
+            "http": { "optional": "yes" }
+        }))
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "error in entry \"http\": invalid type: string \"yes\", expected a boolean"
+        );
+    }
+
+    #[test]
+    fn test_bincode_roundtrip() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Manifest {
+            #[serde(deserialize_with = "NamedMap::deserialize_with_defaults")]
+            interfaces: NamedMap<Interface>,
+        }
+
+        let original = Manifest {
+            interfaces: parse(json!({ "http": { "limits": { "memory": 512 } } })),
+        };
+        let bytes = bincode::serialize(&original).unwrap();
+        assert_eq!(bincode::deserialize::<Manifest>(&bytes).unwrap(), original);
+    }
+}
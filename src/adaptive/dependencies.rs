@@ -0,0 +1,260 @@
+// flexicon/src/adaptive/dependencies.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Ordering entries so that every entry comes after the entries it
+//! depends on.
+
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::NamedMap;
+
+/// An entry that depends on other entries of the same map, by name.
+pub trait Dependencies {
+    /// The names of the entries that must come before this one.
+    fn depends_on(&self) -> Vec<&str>;
+}
+
+/// Why [`NamedMap::iter_in_dependency_order`] found no order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CycleError {
+    /// An entry depends on a name that is not in the map.
+    MissingDependency {
+        /// The dependent entry.
+        name: String,
+        /// The name it depends on.
+        dependency: String,
+    },
+    /// Entries depend on each other in a loop.
+    Cycle {
+        /// The loop, each entry followed by one it depends on, starting
+        /// and ending with the same name.
+        path: Vec<String>,
+    },
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CycleError::MissingDependency { name, dependency } => {
+                write!(f, "{:?} depends on unknown entry {:?}", name, dependency)
+            }
+            CycleError::Cycle { path } => write!(f, "dependency cycle: {}", path.join(" -> ")),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CycleError {}
+
+impl<T: Dependencies, S> NamedMap<T, S> {
+    /// Returns the entries ordered so that every entry comes after all of
+    /// its dependencies. Among entries whose dependencies are already
+    /// placed, the lexicographically smallest name comes first, so the
+    /// order is stable.
+    ///
+    /// Fails if an entry depends on a name missing from the map (checked
+    /// first, in name order) or if entries depend on each other in a loop.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::{Dependencies, NamedMap};
+    ///
+    /// struct Plugin {
+    ///     after: Vec<String>,
+    /// }
+    ///
+    /// impl Dependencies for Plugin {
+    ///     fn depends_on(&self) -> Vec<&str> {
+    ///         self.after.iter().map(String::as_str).collect()
+    ///     }
+    /// }
+    ///
+    /// let mut map = NamedMap::new();
+    /// map.insert("http".to_string(), Plugin { after: vec!["logger".to_string()] });
+    /// map.insert("logger".to_string(), Plugin { after: vec![] });
+    /// map.insert("auth".to_string(), Plugin { after: vec!["http".to_string()] });
+    ///
+    /// let order: Vec<&str> = map
+    ///     .iter_in_dependency_order()
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|(name, _)| name)
+    ///     .collect();
+    /// assert_eq!(order, ["logger", "http", "auth"]);
+    /// ```
+    pub fn iter_in_dependency_order(&self) -> Result<Vec<(&str, &T)>, CycleError> {
+        let mut entries: Vec<(&str, &T)> = self
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+            .collect();
+        entries.sort_unstable_by_key(|(name, _)| *name);
+
+        // Entries are referred to by their index in `entries`, which
+        // follows name order.
+        let mut dependencies: Vec<Vec<usize>> = Vec::with_capacity(entries.len());
+        for (name, value) in &entries {
+            let mut indices = Vec::new();
+            for dependency in value.depends_on() {
+                match entries.binary_search_by_key(&dependency, |(name, _)| *name) {
+                    Ok(index) => indices.push(index),
+                    Err(_) => {
+                        return Err(CycleError::MissingDependency {
+                            name: name.to_string(),
+                            dependency: dependency.to_string(),
+                        })
+                    }
+                }
+            }
+            indices.sort_unstable();
+            indices.dedup();
+            dependencies.push(indices);
+        }
+
+        let mut dependents: Vec<Vec<usize>> = alloc::vec![Vec::new(); entries.len()];
+        for (index, indices) in dependencies.iter().enumerate() {
+            for &dependency in indices {
+                dependents[dependency].push(index);
+            }
+        }
+        let mut pending: Vec<usize> = dependencies.iter().map(Vec::len).collect();
+        let mut ready: BTreeSet<usize> = (0..entries.len()).filter(|&i| pending[i] == 0).collect();
+
+        let mut order = Vec::with_capacity(entries.len());
+        while let Some(index) = ready.pop_first() {
+            order.push(entries[index]);
+            for &dependent in &dependents[index] {
+                pending[dependent] -= 1;
+                if pending[dependent] == 0 {
+                    ready.insert(dependent);
+                }
+            }
+        }
+        if order.len() == entries.len() {
+            return Ok(order);
+        }
+
+        // Every entry left has a dependency that is also left, so following
+        // the smallest such dependency from the smallest entry must loop.
+        let mut position: Vec<Option<usize>> = alloc::vec![None; entries.len()];
+        let mut path: Vec<usize> = Vec::new();
+        let mut current = (0..entries.len())
+            .find(|&i| pending[i] > 0)
+            .expect("an entry is left unordered");
+        while position[current].is_none() {
+            position[current] = Some(path.len());
+            path.push(current);
+            current = *dependencies[current]
+                .iter()
+                .find(|&&dependency| pending[dependency] > 0)
+                .expect("an unordered entry has an unordered dependency");
+        }
+        let start = position[current].expect("the loop closes on a visited entry");
+        let mut path: Vec<String> = path[start..]
+            .iter()
+            .map(|&index| entries[index].0.to_string())
+            .collect();
+        path.push(entries[current].0.to_string());
+        Err(CycleError::Cycle { path })
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Plugin(Vec<&'static str>);
+
+    impl Dependencies for Plugin {
+        fn depends_on(&self) -> Vec<&str> {
+            self.0.clone()
+        }
+    }
+
+    fn plugins(entries: &[(&str, &[&'static str])]) -> NamedMap<Plugin> {
+        let mut map = NamedMap::new();
+        for (name, dependencies) in entries {
+            map.insert(name.to_string(), Plugin(dependencies.to_vec()));
+        }
+        map
+    }
+
+    fn order(map: &NamedMap<Plugin>) -> Vec<&str> {
+        map.iter_in_dependency_order()
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    #[test]
+    fn test_chain() {
+        let map = plugins(&[("c", &["b"]), ("a", &[]), ("b", &["a"]), ("d", &[])]);
+        assert_eq!(order(&map), ["a", "b", "c", "d"]);
+
+        let map = plugins(&[("a", &["b"]), ("b", &["c"]), ("c", &[])]);
+        assert_eq!(order(&map), ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_diamond() {
+        let map = plugins(&[
+            ("app", &["http", "db"]),
+            ("http", &["logger"]),
+            ("db", &["logger", "logger"]),
+            ("logger", &[]),
+        ]);
+        assert_eq!(order(&map), ["logger", "db", "http", "app"]);
+        assert!(order(&NamedMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_missing_dependency() {
+        let map = plugins(&[("a", &["b"]), ("b", &["x"]), ("c", &["c"])]);
+        let err = map.iter_in_dependency_order().unwrap_err();
+        assert_eq!(
+            err,
+            CycleError::MissingDependency {
+                name: "b".to_string(),
+                dependency: "x".to_string(),
+            }
+        );
+        assert_eq!(err.to_string(), "\"b\" depends on unknown entry \"x\"");
+    }
+
+    #[test]
+    fn test_three_node_cycle() {
+        let map = plugins(&[
+            ("logger", &[]),
+            ("a", &["logger", "c"]),
+            ("b", &["a"]),
+            ("c", &["b"]),
+            ("d", &["c"]),
+        ]);
+        let err = map.iter_in_dependency_order().unwrap_err();
+        assert_eq!(
+            err,
+            CycleError::Cycle {
+                path: ["a", "c", "b", "a"].map(String::from).to_vec(),
+            }
+        );
+        assert_eq!(err.to_string(), "dependency cycle: a -> c -> b -> a");
+
+        let err = plugins(&[("a", &["a"])])
+            .iter_in_dependency_order()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "dependency cycle: a -> a");
+    }
+}
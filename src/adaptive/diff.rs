@@ -0,0 +1,360 @@
+// flexicon/src/adaptive/diff.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+
+use super::NamedMap;
+
+/// A key whose value differs between two maps.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ChangedEntry<'a, T> {
+    /// The shared key.
+    pub key: &'a str,
+    /// The value in the original map.
+    pub old: &'a T,
+    /// The value in the updated map.
+    pub new: &'a T,
+}
+
+// Manual impls: the entry only holds references, so no `T: Clone` bound.
+impl<T> Clone for ChangedEntry<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ChangedEntry<'_, T> {}
+
+/// The key-level difference between two [`NamedMap`]s, as returned by
+/// [`NamedMap::diff`].
+///
+/// Borrows from both maps (no values are cloned). Every list is sorted by
+/// key, so iterating it is deterministic.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NamedMapDiff<'a, T> {
+    /// Entries only present in the updated map.
+    pub added: Vec<(&'a str, &'a T)>,
+    /// Entries only present in the original map.
+    pub removed: Vec<(&'a str, &'a T)>,
+    /// Entries present in both maps with different values.
+    pub changed: Vec<ChangedEntry<'a, T>>,
+}
+
+impl<T> NamedMapDiff<'_, T> {
+    /// Returns `true` if both maps hold the same entries.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl<T: PartialEq, S: BuildHasher> NamedMap<T, S> {
+    /// Computes what changed going from `self` to `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let mut old = NamedMap::new();
+    /// old.insert("logger".to_string(), 1);
+    /// old.insert("http".to_string(), 1);
+    ///
+    /// let mut new = old.clone();
+    /// new.insert("http".to_string(), 2);
+    ///
+    /// let diff = old.diff(&new);
+    /// assert!(diff.added.is_empty() && diff.removed.is_empty());
+    /// assert_eq!(diff.changed[0].key, "http");
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a NamedMap<T, S>) -> NamedMapDiff<'a, T> {
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        let mut changed = Vec::new();
+
+        for (key, old) in self.iter() {
+            match other.get(key) {
+                None => removed.push((key.as_str(), old)),
+                Some(new) if new != old => changed.push(ChangedEntry {
+                    key: key.as_str(),
+                    old,
+                    new,
+                }),
+                Some(_) => {}
+            }
+        }
+        for (key, new) in other.iter() {
+            if !self.contains_key(key) {
+                added.push((key.as_str(), new));
+            }
+        }
+
+        added.sort_unstable_by_key(|(key, _)| *key);
+        removed.sort_unstable_by_key(|(key, _)| *key);
+        changed.sort_unstable_by_key(|entry| entry.key);
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            added = added.len(),
+            removed = removed.len(),
+            changed = changed.len(),
+            "named_map.diff"
+        );
+        NamedMapDiff {
+            added,
+            removed,
+            changed,
+        }
+    }
+}
+
+// === JSON PATCH ===
+
+#[cfg(feature = "serde_json")]
+mod json_patch_impl {
+    use super::NamedMap;
+    use core::hash::BuildHasher;
+    use serde::Serialize;
+    use serde_json::{json, Value};
+    use std::collections::BTreeSet;
+
+    /// Escapes a JSON Pointer reference token (RFC 6901).
+    fn escape(token: &str) -> String {
+        token.replace('~', "~0").replace('/', "~1")
+    }
+
+    /// Appends the operations turning `old` into `new` at `path`, recursing
+    /// into objects and replacing anything else wholesale.
+    fn diff_values(path: &str, old: &Value, new: &Value, ops: &mut Vec<Value>) {
+        if old == new {
+            return;
+        }
+        let (Value::Object(old), Value::Object(new)) = (old, new) else {
+            ops.push(json!({"op": "replace", "path": path, "value": new}));
+            return;
+        };
+        let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+        for key in keys {
+            let path = format!("{}/{}", path, escape(key));
+            match (old.get(key), new.get(key)) {
+                (Some(_), None) => ops.push(json!({"op": "remove", "path": path})),
+                (None, Some(value)) => ops.push(json!({"op": "add", "path": path, "value": value})),
+                (Some(old), Some(new)) => diff_values(&path, old, new, ops),
+                (None, None) => unreachable!("key comes from one of the maps"),
+            }
+        }
+    }
+
+    impl<T: Serialize, S: BuildHasher> NamedMap<T, S> {
+        /// Computes an RFC 6902 JSON Patch (an array of operations) turning
+        /// `self` into `other`, with paths of the form `/entryName/field`.
+        ///
+        /// Entries are added or removed whole; changed entries are diffed
+        /// field by field, down through nested objects, while arrays and
+        /// scalars are replaced. Operations are ordered by path. Applying
+        /// the patch to `self` (e.g. with `apply_json_patch`) yields `other`.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use flexicon::adaptive::NamedMap;
+        /// use serde_json::json;
+        ///
+        /// let mut old = NamedMap::new();
+        /// old.insert("http".to_string(), json!({"port": 80, "tls": false}));
+        /// let mut new = old.clone();
+        /// new.insert("http".to_string(), json!({"port": 443, "tls": false}));
+        ///
+        /// let patch = old.diff_as_json_patch(&new).unwrap();
+        /// assert_eq!(
+        ///     patch,
+        ///     json!([{"op": "replace", "path": "/http/port", "value": 443}])
+        /// );
+        /// ```
+        pub fn diff_as_json_patch(&self, other: &NamedMap<T, S>) -> serde_json::Result<Value> {
+            let mut ops = Vec::new();
+            let names: BTreeSet<&String> = self.keys().chain(other.keys()).collect();
+            for name in names {
+                let path = format!("/{}", escape(name));
+                match (self.get(name), other.get(name)) {
+                    (Some(_), None) => ops.push(json!({"op": "remove", "path": path})),
+                    (None, Some(new)) => {
+                        let value = serde_json::to_value(new)?;
+                        ops.push(json!({"op": "add", "path": path, "value": value}));
+                    }
+                    (Some(old), Some(new)) => {
+                        let old = serde_json::to_value(old)?;
+                        let new = serde_json::to_value(new)?;
+                        diff_values(&path, &old, &new, &mut ops);
+                    }
+                    (None, None) => unreachable!("name comes from one of the maps"),
+                }
+            }
+            Ok(Value::Array(ops))
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(entries: &[(&str, i32)]) -> NamedMap<i32> {
+        let mut map = NamedMap::new();
+        for (k, v) in entries {
+            map.insert(k.to_string(), *v);
+        }
+        map
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        let a = map(&[("a", 1), ("b", 2)]);
+        let b = a.clone();
+        assert!(a.diff(&b).is_empty());
+    }
+
+    #[test]
+    fn test_diff_disjoint() {
+        let a = map(&[("b", 2), ("a", 1)]);
+        let b = map(&[("d", 4), ("c", 3)]);
+        let diff = a.diff(&b);
+        assert_eq!(diff.removed, [("a", &1), ("b", &2)]);
+        assert_eq!(diff.added, [("c", &3), ("d", &4)]);
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_single_changed_value() {
+        let a = map(&[("a", 1), ("b", 2)]);
+        let b = map(&[("a", 1), ("b", 20)]);
+        let diff = a.diff(&b);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(
+            diff.changed,
+            [ChangedEntry {
+                key: "b",
+                old: &2,
+                new: &20
+            }]
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod json_patch {
+        use super::*;
+        use crate::adaptive::FromName;
+        use serde::{Deserialize, Serialize};
+        use serde_json::json;
+        use std::collections::BTreeMap;
+
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct Service {
+            version: String,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            port: Option<u16>,
+            tags: Vec<String>,
+            env: BTreeMap<String, String>,
+        }
+
+        impl FromName for Service {
+            fn from_name(_name: &str) -> Self {
+                Service {
+                    version: "1".to_string(),
+                    port: None,
+                    tags: Vec::new(),
+                    env: BTreeMap::new(),
+                }
+            }
+        }
+
+        fn service(
+            version: &str,
+            port: Option<u16>,
+            tags: &[&str],
+            env: &[(&str, &str)],
+        ) -> Service {
+            Service {
+                version: version.to_string(),
+                port,
+                tags: tags.iter().map(|tag| tag.to_string()).collect(),
+                env: env
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            }
+        }
+
+        fn services(entries: &[(&str, Service)]) -> NamedMap<Service> {
+            let mut map = NamedMap::new();
+            for (name, service) in entries {
+                map.insert(name.to_string(), service.clone());
+            }
+            map
+        }
+
+        #[test]
+        fn test_json_patch_granularity() {
+            let old = services(&[
+                ("http", service("1", Some(80), &["web"], &[("A", "1")])),
+                ("logger", service("1", None, &[], &[])),
+            ]);
+            let new = services(&[
+                (
+                    "http",
+                    service("2", None, &["web"], &[("A", "1"), ("B/c~d", "2")]),
+                ),
+                ("metrics", service("1", None, &[], &[])),
+            ]);
+            assert_eq!(
+                old.diff_as_json_patch(&new).unwrap(),
+                json!([
+                    {"op": "add", "path": "/http/env/B~1c~0d", "value": "2"},
+                    {"op": "remove", "path": "/http/port"},
+                    {"op": "replace", "path": "/http/version", "value": "2"},
+                    {"op": "remove", "path": "/logger"},
+                    {"op": "add", "path": "/metrics", "value": {"version": "1", "tags": [], "env": {}}},
+                ])
+            );
+            assert_eq!(old.diff_as_json_patch(&old).unwrap(), json!([]));
+        }
+
+        #[cfg(feature = "json-patch")]
+        #[test]
+        fn test_json_patch_roundtrip() {
+            let base = service("1", Some(80), &["a", "b"], &[("K", "v")]);
+            let cases = [
+                (services(&[]), services(&[])),
+                (services(&[]), services(&[("a", base.clone())])),
+                (services(&[("a", base.clone())]), services(&[])),
+                (
+                    services(&[("a", base.clone()), ("b", base.clone())]),
+                    services(&[("c", base.clone()), ("d", base.clone())]),
+                ),
+                (
+                    services(&[("a/b", base.clone())]),
+                    services(&[("a/b", service("2", None, &["b"], &[("K", "w"), ("~", "")]))]),
+                ),
+                (
+                    services(&[("x", service("1", None, &[], &[]))]),
+                    services(&[("x", service("1", Some(1), &["t"], &[("E", "1")]))]),
+                ),
+            ];
+            for (old, new) in cases {
+                let patch = old.diff_as_json_patch(&new).unwrap();
+                let patch: ::json_patch::Patch = serde_json::from_value(patch).unwrap();
+                let mut patched = old.clone();
+                patched.apply_json_patch(&patch).unwrap();
+                assert_eq!(patched, new, "patch: {}", patch);
+            }
+        }
+    }
+}
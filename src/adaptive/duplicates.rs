@@ -0,0 +1,409 @@
+// flexicon/src/adaptive/duplicates.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::BuildHasher;
+
+use super::map::{Entry, HashMap};
+use super::{EmptyNameError, FromName, NamePolicy, NamedMap};
+
+/// What to do when a name appears more than once in the simple (array) form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DuplicatePolicy {
+    /// Reject the input, reporting the repeated name and its position.
+    #[default]
+    Error,
+    /// Keep the first occurrence and ignore later ones.
+    KeepFirst,
+    /// Let later occurrences replace earlier ones.
+    KeepLast,
+}
+
+/// A name repeated in a list parsed with [`DuplicatePolicy::Error`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateNameError {
+    /// The repeated name.
+    pub name: String,
+    /// Zero-based position of the repeated occurrence in the list.
+    pub index: usize,
+}
+
+impl fmt::Display for DuplicateNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "duplicate name {:?} at index {}", self.name, self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DuplicateNameError {}
+
+/// Every name repeated in a list, as reported by
+/// [`NamedMap::try_from_names`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateNamesError {
+    /// The repeated names, sorted, each listed once.
+    pub names: Vec<String>,
+}
+
+impl fmt::Display for DuplicateNamesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.names.len() == 1 {
+            "duplicate name "
+        } else {
+            "duplicate names "
+        })?;
+        for (i, name) in self.names.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{:?}", name)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DuplicateNamesError {}
+
+/// Why [`NamedMap::try_from_names`] or [`NamedMap::from_names_with_policy`]
+/// rejected a list of names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryFromNamesError {
+    /// A name is empty or whitespace-only.
+    Empty(EmptyNameError),
+    /// Names appear more than once (`try_from_names`).
+    Duplicates(DuplicateNamesError),
+    /// A name appears more than once under [`DuplicatePolicy::Error`]
+    /// (`from_names_with_policy`).
+    Duplicate(DuplicateNameError),
+}
+
+impl fmt::Display for TryFromNamesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryFromNamesError::Empty(err) => fmt::Display::fmt(err, f),
+            TryFromNamesError::Duplicates(err) => fmt::Display::fmt(err, f),
+            TryFromNamesError::Duplicate(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromNamesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TryFromNamesError::Empty(err) => Some(err),
+            TryFromNamesError::Duplicates(err) => Some(err),
+            TryFromNamesError::Duplicate(err) => Some(err),
+        }
+    }
+}
+
+impl DuplicatePolicy {
+    /// Inserts `name` → `value`, applying the policy if `name` is already
+    /// present. `index` is the position of the entry in its source list.
+    pub(crate) fn insert<T, S: BuildHasher>(
+        self,
+        map: &mut HashMap<String, T, S>,
+        name: String,
+        value: T,
+        index: usize,
+    ) -> Result<(), DuplicateNameError> {
+        match map.entry(name) {
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+            Entry::Occupied(mut entry) => {
+                if self.replaces(entry.key(), index)? {
+                    entry.insert(value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the `index`-th entry of a list replaces the earlier entry
+    /// named `name`, or an error under [`DuplicatePolicy::Error`].
+    pub(crate) fn replaces(self, name: &str, index: usize) -> Result<bool, DuplicateNameError> {
+        match self {
+            DuplicatePolicy::Error => Err(DuplicateNameError {
+                name: name.into(),
+                index,
+            }),
+            DuplicatePolicy::KeepFirst => Ok(false),
+            DuplicatePolicy::KeepLast => Ok(true),
+        }
+    }
+}
+
+impl<T: FromName, S: BuildHasher + Default> NamedMap<T, S> {
+    /// Builds a map from a list of names, handling repeats with `policy`.
+    /// Empty and whitespace-only names are rejected
+    /// ([`NamePolicy::RejectEmpty`]), like in the array form.
+    ///
+    /// Checks the list like the array form does; pass
+    /// [`DuplicatePolicy::Error`], the array form's default, to reject
+    /// repeats. `From<Vec<String>>` is the lenient alternative: it skips
+    /// empty names and keeps the last of repeated ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::{DuplicatePolicy, NamedMap};
+    /// # #[derive(Debug, Clone)]
+    /// # struct Plugin;
+    /// # impl flexicon::adaptive::FromName for Plugin {
+    /// #     fn from_name(_name: &str) -> Self { Plugin }
+    /// # }
+    ///
+    /// let names = vec!["a".to_string(), "b".to_string(), "a".to_string()];
+    /// let err = NamedMap::<Plugin>::from_names_with_policy(names, DuplicatePolicy::Error)
+    ///     .unwrap_err();
+    /// assert_eq!(err.to_string(), "duplicate name \"a\" at index 2");
+    /// ```
+    pub fn from_names_with_policy<I>(
+        names: I,
+        policy: DuplicatePolicy,
+    ) -> Result<Self, TryFromNamesError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut map = HashMap::default();
+        for (index, name) in names.into_iter().enumerate() {
+            let Some(name) = NamePolicy::RejectEmpty
+                .check_name(name, index)
+                .map_err(TryFromNamesError::Empty)?
+            else {
+                continue;
+            };
+            let value = T::from_name(&name);
+            policy
+                .insert(&mut map, name, value, index)
+                .map_err(TryFromNamesError::Duplicate)?;
+        }
+        Ok(NamedMap::from(map))
+    }
+
+    /// Builds a map from a list of names, failing with every repeated name
+    /// instead of stopping at the first one.
+    ///
+    /// The strict counterpart of `From<Vec<String>>`, which keeps the last
+    /// occurrence and skips empty names. Empty and whitespace-only names are
+    /// rejected here ([`NamePolicy::RejectEmpty`]). (It cannot be a
+    /// `TryFrom<Vec<String>>` impl: the blanket impl over `From` already
+    /// provides one.)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    /// # #[derive(Debug, Clone)]
+    /// # struct Plugin;
+    /// # impl flexicon::adaptive::FromName for Plugin {
+    /// #     fn from_name(_name: &str) -> Self { Plugin }
+    /// # }
+    ///
+    /// let names = ["http", "ssh", "http", "dns", "ssh"].map(String::from);
+    /// let err = NamedMap::<Plugin>::try_from_names(names).unwrap_err();
+    /// assert_eq!(err.to_string(), "duplicate names \"http\", \"ssh\"");
+    /// ```
+    pub fn try_from_names<I>(names: I) -> Result<Self, TryFromNamesError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut map = HashMap::default();
+        let mut duplicates = Vec::new();
+        for (index, name) in names.into_iter().enumerate() {
+            let Some(name) = NamePolicy::RejectEmpty
+                .check_name(name, index)
+                .map_err(TryFromNamesError::Empty)?
+            else {
+                continue;
+            };
+            match map.entry(name) {
+                Entry::Vacant(entry) => {
+                    let value = T::from_name(entry.key());
+                    entry.insert(value);
+                }
+                Entry::Occupied(entry) => duplicates.push(entry.key().clone()),
+            }
+        }
+        if duplicates.is_empty() {
+            return Ok(NamedMap::from(map));
+        }
+        duplicates.sort_unstable();
+        duplicates.dedup();
+        Err(TryFromNamesError::Duplicates(DuplicateNamesError { names: duplicates }))
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Item(String);
+
+    impl FromName for Item {
+        fn from_name(name: &str) -> Self {
+            Item(name.to_string())
+        }
+    }
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_policy_error() {
+        let err = NamedMap::<Item>::from_names_with_policy(names(&["a", "b", "a"]), DuplicatePolicy::Error)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TryFromNamesError::Duplicate(DuplicateNameError {
+                name: "a".to_string(),
+                index: 2
+            })
+        );
+
+        for policy in [DuplicatePolicy::Error, DuplicatePolicy::KeepLast] {
+            let err =
+                NamedMap::<Item>::from_names_with_policy(names(&["a", " "]), policy).unwrap_err();
+            assert_eq!(err, TryFromNamesError::Empty(EmptyNameError { index: 1 }));
+        }
+    }
+
+    #[test]
+    fn test_try_from_names() {
+        let map = NamedMap::<Item>::try_from_names(names(&["a", "b"])).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["b"], Item::from_name("b"));
+        assert!(NamedMap::<Item>::try_from_names(Vec::new())
+            .unwrap()
+            .is_empty());
+
+        let err = NamedMap::<Item>::try_from_names(names(&["a", "b", "a", "a"])).unwrap_err();
+        assert_eq!(
+            err,
+            TryFromNamesError::Duplicates(DuplicateNamesError {
+                names: names(&["a"])
+            })
+        );
+        assert_eq!(err.to_string(), "duplicate name \"a\"");
+
+        let err =
+            NamedMap::<Item>::try_from_names(names(&["c", "b", "a", "b", "c", "d"])).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate names \"b\", \"c\"");
+    }
+
+    #[test]
+    fn test_try_from_names_rejects_empty() {
+        for (list, index) in [(&["a", ""][..], 1), (&["  ", "a", "a"][..], 0)] {
+            let err = NamedMap::<Item>::try_from_names(names(list)).unwrap_err();
+            assert_eq!(err, TryFromNamesError::Empty(EmptyNameError { index }));
+            assert_eq!(err.to_string(), format!("empty name at index {}", index));
+        }
+        let map = NamedMap::<Item>::try_from_names(names(&["my logger"])).unwrap();
+        assert_eq!(map["my logger"], Item::from_name("my logger"));
+    }
+
+    #[test]
+    fn test_policy_keep_first_and_last() {
+        // Values carry their position, so each policy's winner is visible.
+        let insert_all = |policy: DuplicatePolicy| {
+            let mut map = HashMap::new();
+            for (index, name) in ["a", "b", "a", "a"].into_iter().enumerate() {
+                policy.insert(&mut map, name.to_string(), index, index).unwrap();
+            }
+            map
+        };
+
+        let first = insert_all(DuplicatePolicy::KeepFirst);
+        assert_eq!(first.len(), 2);
+        assert_eq!((first["a"], first["b"]), (0, 1));
+
+        let last = insert_all(DuplicatePolicy::KeepLast);
+        assert_eq!(last.len(), 2);
+        assert_eq!((last["a"], last["b"]), (3, 1));
+
+        for policy in [DuplicatePolicy::KeepFirst, DuplicatePolicy::KeepLast] {
+            let map = NamedMap::<Item>::from_names_with_policy(names(&["a", "b", "a"]), policy).unwrap();
+            assert_eq!(map.len(), 2);
+            assert_eq!(map["a"], Item::from_name("a"));
+        }
+    }
+
+    #[test]
+    fn test_from_vec_is_lenient() {
+        let list = names(&["a", "b", "", "a"]);
+        let map = NamedMap::<Item>::from(list.clone());
+        let expected =
+            NamedMap::from_names_with_policy(names(&["a", "b", "a"]), DuplicatePolicy::KeepLast);
+        assert_eq!(map, expected.unwrap());
+
+        // The checked constructors reject what `From` lets through.
+        assert!(NamedMap::<Item>::try_from_names(list.clone()).is_err());
+        assert!(NamedMap::<Item>::from_names_with_policy(list, DuplicatePolicy::Error).is_err());
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod serde {
+        use super::*;
+        use ::serde::Deserialize;
+
+        #[derive(Debug, Clone, PartialEq, Deserialize)]
+        struct Value {
+            v: u32,
+        }
+
+        impl FromName for Value {
+            fn from_name(_name: &str) -> Self {
+                Value { v: 0 }
+            }
+        }
+
+        const INPUT: &str = r#"["a", {"a": {"v": 1}}, "b"]"#;
+
+        #[derive(Deserialize)]
+        struct First {
+            #[serde(deserialize_with = "NamedMap::deserialize_keep_first")]
+            map: NamedMap<Value>,
+        }
+
+        #[derive(Deserialize)]
+        struct Last {
+            #[serde(deserialize_with = "NamedMap::deserialize_keep_last")]
+            map: NamedMap<Value>,
+        }
+
+        #[test]
+        fn test_deserialize_error_policy() {
+            let err = serde_json::from_str::<NamedMap<Value>>(INPUT).unwrap_err();
+            assert!(err.to_string().contains("duplicate name \"a\" at index 1"), "{}", err);
+        }
+
+        #[test]
+        fn test_deserialize_keep_first() {
+            let first: First = serde_json::from_str(&format!(r#"{{"map": {}}}"#, INPUT)).unwrap();
+            assert_eq!(first.map["a"], Value { v: 0 });
+            assert_eq!(first.map.len(), 2);
+        }
+
+        #[test]
+        fn test_deserialize_keep_last() {
+            let last: Last = serde_json::from_str(&format!(r#"{{"map": {}}}"#, INPUT)).unwrap();
+            assert_eq!(last.map["a"], Value { v: 1 });
+            assert_eq!(last.map.len(), 2);
+        }
+    }
+}
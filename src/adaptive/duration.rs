@@ -0,0 +1,639 @@
+// flexicon/src/adaptive/duration.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Durations written as `"1h30m"`-style strings or as plain numbers.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::str::FromStr;
+use core::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// String suffixes and their length in nanoseconds, largest first.
+const UNITS: [(&str, u128); 7] = [
+    ("d", 86_400 * NANOS_PER_SEC),
+    ("h", 3_600 * NANOS_PER_SEC),
+    ("m", 60 * NANOS_PER_SEC),
+    ("s", NANOS_PER_SEC),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+/// The unit a bare number is read in by an [`AdaptiveDuration`].
+pub trait DurationUnit {
+    /// Length of one unit in nanoseconds.
+    const NANOS: u64;
+}
+
+macro_rules! duration_units {
+    ($($(#[$doc:meta])* $unit:ident = $nanos:expr;)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $unit;
+
+            impl DurationUnit for $unit {
+                const NANOS: u64 = $nanos;
+            }
+        )*
+    };
+}
+
+duration_units! {
+    /// Reads bare numbers as nanoseconds.
+    Nanoseconds = 1;
+    /// Reads bare numbers as microseconds.
+    Microseconds = 1_000;
+    /// Reads bare numbers as milliseconds.
+    Milliseconds = 1_000_000;
+    /// Reads bare numbers as seconds (the default).
+    Seconds = 1_000_000_000;
+    /// Reads bare numbers as minutes.
+    Minutes = 60_000_000_000;
+    /// Reads bare numbers as hours.
+    Hours = 3_600_000_000_000;
+    /// Reads bare numbers as days.
+    Days = 86_400_000_000_000;
+}
+
+/// A string or number that is not a valid duration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationParseError {
+    /// The rejected input, as written.
+    pub input: String,
+    /// What is wrong with it.
+    pub reason: &'static str,
+}
+
+impl DurationParseError {
+    fn new(input: impl ToString, reason: &'static str) -> Self {
+        DurationParseError {
+            input: input.to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for DurationParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid duration {:?}: {}", self.input, self.reason)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DurationParseError {}
+
+/// A [`Duration`] that can be written the way people write timeouts:
+/// `"30s"`, `"1h30m"`, `"1.5s"`, `"250ms"`, or a bare number of `U`
+/// (seconds by default, so `2.5` is two and a half seconds).
+///
+/// Strings are one or more `<number><unit>` parts, optionally separated
+/// by whitespace, with units `ns`, `us` (or `µs`), `ms`, `s`, `m`, `h` and
+/// `d`. Parts are added up, and fractions must come out to whole
+/// nanoseconds. A bare number in a string (`"30"`) is rejected, since its
+/// unit would be a guess.
+///
+/// With the `serde` feature it deserializes from either form and
+/// serializes as the shortest string that reads back exactly, e.g.
+/// `"90m"` or `"1.5s"`. Formats that are not human-readable (bincode,
+/// postcard, ...) store the [`Duration`] itself.
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// use std::time::Duration;
+/// use flexicon::adaptive::{AdaptiveDuration, Milliseconds};
+///
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() {
+/// let timeout: AdaptiveDuration = serde_json::from_str(r#""1h30m""#).unwrap();
+/// assert_eq!(*timeout, Duration::from_secs(5400));
+/// assert_eq!(serde_json::to_string(&timeout).unwrap(), r#""90m""#);
+///
+/// let retry: AdaptiveDuration<Milliseconds> = serde_json::from_str("1500").unwrap();
+/// assert_eq!(retry.to_string(), "1.5s");
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AdaptiveDuration<U = Seconds> {
+    duration: Duration,
+    _unit: PhantomData<U>,
+}
+
+impl<U> AdaptiveDuration<U> {
+    /// Wraps `duration`.
+    pub fn new(duration: Duration) -> Self {
+        AdaptiveDuration {
+            duration,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped duration.
+    pub fn into_inner(self) -> Duration {
+        self.duration
+    }
+}
+
+impl<U> Deref for AdaptiveDuration<U> {
+    type Target = Duration;
+
+    fn deref(&self) -> &Duration {
+        &self.duration
+    }
+}
+
+impl<U> From<Duration> for AdaptiveDuration<U> {
+    fn from(duration: Duration) -> Self {
+        Self::new(duration)
+    }
+}
+
+impl<U> From<AdaptiveDuration<U>> for Duration {
+    fn from(duration: AdaptiveDuration<U>) -> Self {
+        duration.duration
+    }
+}
+
+impl<U> fmt::Display for AdaptiveDuration<U> {
+    /// Writes the shortest string that parses back to the same duration.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_nanos(self.duration.as_nanos()))
+    }
+}
+
+impl<U> FromStr for AdaptiveDuration<U> {
+    type Err = DurationParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+            .map(Self::new)
+            .map_err(|reason| DurationParseError::new(s, reason))
+    }
+}
+
+impl<U: DurationUnit> AdaptiveDuration<U> {
+    /// Reads `value` units of `U`.
+    fn from_units(value: u64) -> Result<Self, DurationParseError> {
+        let nanos = u128::from(value) * u128::from(U::NANOS);
+        from_nanos(nanos)
+            .map(Self::new)
+            .ok_or_else(|| DurationParseError::new(value, "too large"))
+    }
+
+    /// Reads a fractional number of `U`.
+    fn from_units_f64(value: f64) -> Result<Self, DurationParseError> {
+        if value.is_sign_negative() && value != 0.0 {
+            return Err(DurationParseError::new(value, "must not be negative"));
+        }
+        Duration::try_from_secs_f64(value * U::NANOS as f64 / NANOS_PER_SEC as f64)
+            .map(Self::new)
+            .map_err(|_| DurationParseError::new(value, "not a representable duration"))
+    }
+}
+
+fn from_nanos(nanos: u128) -> Option<Duration> {
+    let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+    Some(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+}
+
+fn unit_nanos(suffix: &str) -> Option<u128> {
+    if suffix == "µs" {
+        return Some(1_000);
+    }
+    UNITS
+        .iter()
+        .find(|(unit, _)| *unit == suffix)
+        .map(|(_, nanos)| *nanos)
+}
+
+/// Parses `"1h30m"`-style input, returning the reason on failure.
+fn parse(input: &str) -> Result<Duration, &'static str> {
+    let mut rest = input.trim();
+    if rest.is_empty() {
+        return Err("empty string");
+    }
+    let mut total: u128 = 0;
+    while !rest.is_empty() {
+        if rest.starts_with('-') {
+            return Err("must not be negative");
+        }
+
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let (number, after) = rest.split_at(number_len);
+        let after = after.trim_start();
+        let unit_len = after
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(after.len());
+        let (suffix, after) = after.split_at(unit_len);
+
+        if number.is_empty() {
+            return Err("missing number");
+        }
+        if suffix.is_empty() {
+            return Err("missing unit (ns, us, ms, s, m, h or d)");
+        }
+        let unit = unit_nanos(suffix).ok_or("unknown unit (ns, us, ms, s, m, h or d)")?;
+        total = total
+            .checked_add(part_nanos(number, unit)?)
+            .ok_or("too large")?;
+        rest = after.trim_start();
+    }
+    from_nanos(total).ok_or("too large")
+}
+
+/// Converts one `<number>` of a unit `unit` nanoseconds long.
+fn part_nanos(number: &str, unit: u128) -> Result<u128, &'static str> {
+    let (whole, fraction) = match number.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction.trim_end_matches('0')),
+        None => (number, ""),
+    };
+    if whole.is_empty() || number.ends_with('.') || fraction.contains('.') {
+        return Err("malformed number");
+    }
+    let whole: u128 = whole.parse().map_err(|_| "too large")?;
+    let mut nanos = whole.checked_mul(unit).ok_or("too large")?;
+    if !fraction.is_empty() {
+        // Any fraction this long is finer than a nanosecond for every unit.
+        if fraction.len() > 20 {
+            return Err("finer than a nanosecond");
+        }
+        let scale = 10u128.pow(fraction.len() as u32);
+        let scaled = fraction.parse::<u128>().map_err(|_| "malformed number")? * unit;
+        if scaled % scale != 0 {
+            return Err("finer than a nanosecond");
+        }
+        nanos = nanos.checked_add(scaled / scale).ok_or("too large")?;
+    }
+    Ok(nanos)
+}
+
+/// Picks the shortest of the compound form (`"1h30m"`) and every exact
+/// single-unit form (`"90m"`, `"1.5h"`).
+fn format_nanos(nanos: u128) -> String {
+    if nanos == 0 {
+        return "0s".to_string();
+    }
+
+    let mut best = String::new();
+    let mut rest = nanos;
+    for (suffix, unit) in UNITS {
+        if rest >= unit {
+            best.push_str(&format!("{}{}", rest / unit, suffix));
+            rest %= unit;
+        }
+    }
+
+    for (suffix, unit) in UNITS {
+        let whole = nanos / unit;
+        let remainder = nanos % unit;
+        let candidate = if remainder == 0 {
+            format!("{}{}", whole, suffix)
+        } else {
+            let Some(digits) = (1..=9u32).find(|&k| (remainder * 10u128.pow(k)) % unit == 0) else {
+                continue;
+            };
+            let fraction = remainder * 10u128.pow(digits) / unit;
+            format!(
+                "{}.{:0width$}{}",
+                whole,
+                fraction,
+                suffix,
+                width = digits as usize
+            )
+        };
+        if candidate.len() < best.len() {
+            best = candidate;
+        }
+    }
+    best
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use serde::{
+        de::{self, Deserializer, Visitor},
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+
+    struct AdaptiveDurationVisitor<U> {
+        _unit: PhantomData<U>,
+    }
+
+    impl<'de, U: DurationUnit> Visitor<'de> for AdaptiveDurationVisitor<U> {
+        type Value = AdaptiveDuration<U>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "a duration such as \"30s\" or \"1h30m\", or a non-negative number"
+            )
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            AdaptiveDuration::from_units(v).map_err(E::custom)
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match u64::try_from(v) {
+                Ok(v) => self.visit_u64(v),
+                Err(_) => Err(E::custom(DurationParseError::new(
+                    v,
+                    "must not be negative",
+                ))),
+            }
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            AdaptiveDuration::from_units_f64(v).map_err(E::custom)
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            v.parse().map_err(E::custom)
+        }
+    }
+
+    impl<U> Serialize for AdaptiveDuration<U> {
+        /// Serializes as the shortest exact string, or as the [`Duration`]
+        /// in formats that are not human-readable.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if serializer.is_human_readable() {
+                serializer.collect_str(self)
+            } else {
+                self.duration.serialize(serializer)
+            }
+        }
+    }
+
+    impl<'de, U: DurationUnit> Deserialize<'de> for AdaptiveDuration<U> {
+        /// Deserializes from a duration string or a number of `U`.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(AdaptiveDurationVisitor { _unit: PhantomData })
+            } else {
+                Duration::deserialize(deserializer).map(Self::new)
+            }
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_ok(s: &str) -> Duration {
+        s.parse::<AdaptiveDuration>()
+            .unwrap_or_else(|err| panic!("{}", err))
+            .into_inner()
+    }
+
+    fn parse_err(s: &str) -> DurationParseError {
+        s.parse::<AdaptiveDuration>().unwrap_err()
+    }
+
+    #[test]
+    fn test_parse_every_unit() {
+        assert_eq!(parse_ok("7ns"), Duration::from_nanos(7));
+        assert_eq!(parse_ok("7us"), Duration::from_micros(7));
+        assert_eq!(parse_ok("7µs"), Duration::from_micros(7));
+        assert_eq!(parse_ok("7ms"), Duration::from_millis(7));
+        assert_eq!(parse_ok("7s"), Duration::from_secs(7));
+        assert_eq!(parse_ok("7m"), Duration::from_secs(7 * 60));
+        assert_eq!(parse_ok("7h"), Duration::from_secs(7 * 3600));
+        assert_eq!(parse_ok("7d"), Duration::from_secs(7 * 86_400));
+        assert_eq!(parse_ok("0s"), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_parse_compound_and_fractions() {
+        assert_eq!(parse_ok("1h30m"), Duration::from_secs(5400));
+        assert_eq!(
+            parse_ok("1d2h3m4s5ms6us7ns"),
+            Duration::new(93_784, 5_006_007)
+        );
+        assert_eq!(parse_ok(" 1h 30m "), Duration::from_secs(5400));
+        assert_eq!(parse_ok("30 s"), Duration::from_secs(30));
+        assert_eq!(parse_ok("30m1h"), Duration::from_secs(5400));
+        assert_eq!(parse_ok("1.5s"), Duration::from_millis(1500));
+        assert_eq!(parse_ok("1.5h"), Duration::from_secs(5400));
+        assert_eq!(parse_ok("0.25ms"), Duration::from_micros(250));
+        assert_eq!(parse_ok("2.500s"), Duration::from_millis(2500));
+        assert_eq!(parse_ok("0.000000001s"), Duration::from_nanos(1));
+        assert_eq!(parse_ok("007s"), Duration::from_secs(7));
+    }
+
+    #[test]
+    fn test_parse_errors_keep_input() {
+        for (input, reason) in [
+            ("", "empty string"),
+            ("   ", "empty string"),
+            ("30", "missing unit"),
+            ("1h30", "missing unit"),
+            ("abc", "missing number"),
+            ("s", "missing number"),
+            ("-5s", "must not be negative"),
+            ("1h-5m", "must not be negative"),
+            ("5x", "unknown unit"),
+            ("5sec", "unknown unit"),
+            ("5S", "unknown unit"),
+            ("1.5.2s", "malformed number"),
+            (".5s", "malformed number"),
+            ("5.s", "malformed number"),
+            ("0.5ns", "finer than a nanosecond"),
+            ("0.0000000001s", "finer than a nanosecond"),
+            ("1.000000000000000000000001s", "finer than a nanosecond"),
+            ("999999999999999999999999999999999999999999d", "too large"),
+            ("213503982334602d", "too large"),
+        ] {
+            let err = parse_err(input);
+            assert_eq!(err.input, input);
+            assert!(err.reason.starts_with(reason), "{}: {}", input, err);
+            assert!(err.to_string().contains(&format!("{:?}", input)));
+        }
+        assert_eq!(
+            parse_err("5x").to_string(),
+            "invalid duration \"5x\": unknown unit (ns, us, ms, s, m, h or d)"
+        );
+    }
+
+    #[test]
+    fn test_display_is_shortest() {
+        for (duration, text) in [
+            (Duration::ZERO, "0s"),
+            (Duration::from_nanos(1), "1ns"),
+            (Duration::from_micros(250), "250us"),
+            (Duration::from_millis(1500), "1.5s"),
+            (Duration::from_millis(1001), "1s1ms"),
+            (Duration::from_secs(30), "30s"),
+            (Duration::from_secs(90), "90s"),
+            (Duration::from_secs(5400), "90m"),
+            (Duration::from_secs(3600), "1h"),
+            (Duration::from_secs(3601), "1h1s"),
+            (Duration::from_secs(86_400 * 2), "2d"),
+            (Duration::from_secs(129_600), "36h"),
+            (Duration::from_millis(12_500), "12.5s"),
+            (Duration::new(1, 1), "1s1ns"),
+            (Duration::new(61, 5), "1m1s5ns"),
+        ] {
+            assert_eq!(AdaptiveDuration::<Seconds>::new(duration).to_string(), text);
+        }
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let mut nanos: u64 = 1;
+        for step in 0..200u64 {
+            nanos = nanos
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(step)
+                >> 3;
+            for duration in [
+                Duration::from_nanos(nanos),
+                Duration::from_millis(nanos % 100_000_000),
+                Duration::from_secs(nanos % 10_000_000),
+            ] {
+                let text = AdaptiveDuration::<Seconds>::new(duration).to_string();
+                assert_eq!(parse_ok(&text), duration, "{}", text);
+            }
+        }
+        let max = AdaptiveDuration::<Seconds>::new(Duration::MAX).to_string();
+        assert_eq!(parse_ok(&max), Duration::MAX);
+    }
+
+    #[test]
+    fn test_numbers_use_unit() {
+        let secs = AdaptiveDuration::<Seconds>::from_units(90).unwrap();
+        assert_eq!(*secs, Duration::from_secs(90));
+        let millis = AdaptiveDuration::<Milliseconds>::from_units(1500).unwrap();
+        assert_eq!(*millis, Duration::from_millis(1500));
+        let half = AdaptiveDuration::<Minutes>::from_units_f64(0.5).unwrap();
+        assert_eq!(*half, Duration::from_secs(30));
+        assert_eq!(
+            AdaptiveDuration::<Days>::from_units(u64::MAX)
+                .unwrap_err()
+                .reason,
+            "too large"
+        );
+        let err = AdaptiveDuration::<Seconds>::from_units_f64(-2.5).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid duration \"-2.5\": must not be negative"
+        );
+        assert!(AdaptiveDuration::<Seconds>::from_units_f64(f64::NAN).is_err());
+        assert!(AdaptiveDuration::<Hours>::from_units_f64(1e300).is_err());
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod serde_tests {
+        use super::*;
+        use crate::adaptive::{FromName, NamedMap};
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+        struct Service {
+            #[serde(default)]
+            timeout: AdaptiveDuration,
+            #[serde(default)]
+            retry: AdaptiveDuration<Milliseconds>,
+        }
+
+        impl FromName for Service {
+            fn from_name(_name: &str) -> Self {
+                Service::default()
+            }
+        }
+
+        #[test]
+        fn test_json_inputs() {
+            let service: Service =
+                serde_json::from_str(r#"{"timeout": "1h30m", "retry": 1500}"#).unwrap();
+            assert_eq!(*service.timeout, Duration::from_secs(5400));
+            assert_eq!(*service.retry, Duration::from_millis(1500));
+
+            let service: Service =
+                serde_json::from_str(r#"{"timeout": 2.5, "retry": "2s"}"#).unwrap();
+            assert_eq!(*service.timeout, Duration::from_millis(2500));
+            assert_eq!(*service.retry, Duration::from_secs(2));
+
+            for (input, text) in [
+                (r#"{"timeout": -5}"#, "\"-5\""),
+                (r#"{"timeout": -0.5}"#, "\"-0.5\""),
+                (r#"{"timeout": "5 minutes"}"#, "\"5 minutes\""),
+                (r#"{"timeout": "-1s"}"#, "\"-1s\""),
+            ] {
+                let err = serde_json::from_str::<Service>(input).unwrap_err();
+                assert!(err.to_string().contains(text), "{}", err);
+            }
+            let err = serde_json::from_str::<Service>(r#"{"timeout": true}"#).unwrap_err();
+            assert!(err.to_string().contains("a duration such as"), "{}", err);
+        }
+
+        #[test]
+        fn test_round_trip_inside_named_map() {
+            let services: NamedMap<Service> = toml::from_str(
+                "[web]\ntimeout = \"30s\"\nretry = 250\n\n\
+                 [batch]\ntimeout = 5400\nretry = \"1.5s\"\n",
+            )
+            .unwrap();
+            assert_eq!(*services["web"].retry, Duration::from_millis(250));
+            assert_eq!(*services["batch"].timeout, Duration::from_secs(5400));
+
+            let json = serde_json::to_value(&services).unwrap();
+            assert_eq!(json["batch"]["timeout"], "90m");
+            assert_eq!(json["web"]["retry"], "250ms");
+            let reread: NamedMap<Service> = serde_json::from_value(json).unwrap();
+            assert_eq!(reread, services);
+
+            let text = toml::to_string(&services).unwrap();
+            assert_eq!(
+                toml::from_str::<NamedMap<Service>>(&text).unwrap(),
+                services
+            );
+
+            let bytes = bincode::serialize(&services).unwrap();
+            assert_eq!(
+                bincode::deserialize::<NamedMap<Service>>(&bytes).unwrap(),
+                services
+            );
+        }
+    }
+}
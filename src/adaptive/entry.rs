@@ -0,0 +1,208 @@
+// flexicon/src/adaptive/entry.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::hash::BuildHasher;
+
+use super::map::{DefaultHasher, HashMap};
+use super::{FromName, NamedMap};
+
+/// A view into a single entry of a [`NamedMap`], as returned by
+/// [`NamedMap::entry`].
+pub struct NamedEntry<'a, T, S = DefaultHasher> {
+    inner: Inner<'a, T, S>,
+}
+
+enum Inner<'a, T, S> {
+    /// The name as given by the caller: no owned key is needed.
+    Occupied {
+        name: Cow<'a, str>,
+        value: &'a mut T,
+    },
+    /// The name to insert under, and the map to insert into.
+    Vacant {
+        name: String,
+        map: &'a mut HashMap<String, T, S>,
+    },
+}
+
+impl<T, S: BuildHasher> NamedMap<T, S> {
+    /// Gets the entry for `name`, for in-place manipulation.
+    ///
+    /// Shadows `HashMap::entry` (still reachable through
+    /// [`as_inner_mut`](Self::as_inner_mut)). A borrowed name is only
+    /// turned into an owned `String` when the entry is vacant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::{FromName, NamedMap};
+    ///
+    /// #[derive(Clone)]
+    /// struct Plugin {
+    ///     calls: u32,
+    /// }
+    ///
+    /// impl FromName for Plugin {
+    ///     fn from_name(_name: &str) -> Self {
+    ///         Plugin { calls: 0 }
+    ///     }
+    /// }
+    ///
+    /// let mut map = NamedMap::new();
+    /// for name in ["logger", "http", "logger"] {
+    ///     map.entry(name)
+    ///         .and_modify(|plugin: &mut Plugin| plugin.calls += 1)
+    ///         .or_insert_from_name();
+    /// }
+    /// assert_eq!(map["logger"].calls, 1);
+    /// assert_eq!(map["http"].calls, 0);
+    /// ```
+    pub fn entry<'a>(&'a mut self, name: impl Into<Cow<'a, str>>) -> NamedEntry<'a, T, S> {
+        let name = name.into();
+        let map = self.as_inner_mut();
+        // Two lookups: returning the `get_mut` borrow from one branch and
+        // keeping the map in the other does not pass the borrow checker.
+        let inner = if map.contains_key(name.as_ref()) {
+            let value = map.get_mut(name.as_ref()).expect("checked above");
+            Inner::Occupied { name, value }
+        } else {
+            Inner::Vacant {
+                name: name.into_owned(),
+                map,
+            }
+        };
+        NamedEntry { inner }
+    }
+}
+
+impl<'a, T, S: BuildHasher> NamedEntry<'a, T, S> {
+    /// The entry's name.
+    pub fn key(&self) -> &str {
+        match &self.inner {
+            Inner::Occupied { name, .. } => name,
+            Inner::Vacant { name, .. } => name,
+        }
+    }
+
+    /// Returns `true` if the entry is present in the map.
+    pub fn is_occupied(&self) -> bool {
+        matches!(self.inner, Inner::Occupied { .. })
+    }
+
+    /// Calls `f` on the value if the entry is present.
+    pub fn and_modify(mut self, f: impl FnOnce(&mut T)) -> Self {
+        if let Inner::Occupied { value, .. } = &mut self.inner {
+            f(value);
+        }
+        self
+    }
+
+    /// Inserts `default` if the entry is vacant, and returns the value.
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        self.or_insert_with(|| default)
+    }
+
+    /// Inserts the result of `f` if the entry is vacant, and returns the
+    /// value.
+    pub fn or_insert_with(self, f: impl FnOnce() -> T) -> &'a mut T {
+        match self.inner {
+            Inner::Occupied { value, .. } => value,
+            Inner::Vacant { name, map } => map.entry(name).or_insert(f()),
+        }
+    }
+}
+
+impl<'a, T: FromName, S: BuildHasher> NamedEntry<'a, T, S> {
+    /// Inserts `T::from_name(name)` if the entry is vacant, and returns the
+    /// value.
+    pub fn or_insert_from_name(self) -> &'a mut T {
+        match self.inner {
+            Inner::Occupied { value, .. } => value,
+            Inner::Vacant { name, map } => {
+                let value = T::from_name(&name);
+                map.entry(name).or_insert(value)
+            }
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Counter {
+        name: String,
+        hits: u32,
+    }
+
+    impl FromName for Counter {
+        fn from_name(name: &str) -> Self {
+            Counter {
+                name: name.to_string(),
+                hits: 0,
+            }
+        }
+    }
+
+    #[test]
+    fn test_vacant_entry() {
+        let mut map = NamedMap::<Counter>::new();
+        let entry = map.entry("logger");
+        assert_eq!(entry.key(), "logger");
+        assert!(!entry.is_occupied());
+        let value = entry.or_insert_from_name();
+        value.hits += 1;
+        assert_eq!(
+            map["logger"],
+            Counter {
+                name: "logger".to_string(),
+                hits: 1
+            }
+        );
+
+        let value = map.entry(String::from("http")).or_insert_with(|| Counter {
+            name: "custom".to_string(),
+            hits: 5,
+        });
+        assert_eq!(value.hits, 5);
+    }
+
+    #[test]
+    fn test_occupied_entry() {
+        let mut map = NamedMap::<Counter>::from(vec!["logger".to_string()]);
+        let name = String::from("logger");
+        let entry = map.entry(name.as_str());
+        assert_eq!(entry.key(), "logger");
+        assert!(entry.is_occupied());
+        let value = entry.or_insert_with(|| unreachable!("entry exists"));
+        assert_eq!(value.name, "logger");
+        assert_eq!(
+            map.entry("logger").or_insert(Counter::from_name("x")).name,
+            "logger"
+        );
+    }
+
+    #[test]
+    fn test_and_modify_chain() {
+        let mut map = NamedMap::<Counter>::new();
+        for name in ["a", "b", "a", "a"] {
+            map.entry(name)
+                .and_modify(|counter| counter.hits += 1)
+                .or_insert_from_name();
+        }
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["a"].hits, 2);
+        assert_eq!(map["b"].hits, 0);
+    }
+}
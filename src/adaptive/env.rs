@@ -0,0 +1,456 @@
+// flexicon/src/adaptive/env.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Overriding entry fields from environment variables.
+//!
+//! The staging machinery here is shared with the dotted `--set`-style
+//! overrides in `set.rs`.
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use super::{FromName, NamedMap};
+
+/// An override applied by [`NamedMap::apply_env_overrides`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedOverride {
+    /// The environment variable, e.g. `APP__LOGGER__VERSION`.
+    pub variable: String,
+    /// The entry it applied to, e.g. `logger`.
+    pub entry: String,
+    /// The field path inside the entry, e.g. `["version"]`; empty when the
+    /// variable replaces the whole entry.
+    pub path: Vec<String>,
+    /// Whether the entry did not exist and was created with `FromName`.
+    pub created: bool,
+}
+
+/// An override that could not be applied. No override is applied when
+/// any of them fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverrideError {
+    /// The offending environment variable or override spec.
+    pub variable: String,
+    /// What went wrong.
+    pub message: String,
+}
+
+impl fmt::Display for OverrideError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error in override {:?}: {}", self.variable, self.message)
+    }
+}
+
+impl Error for OverrideError {}
+
+/// How override segments are matched against entry names and object keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyMatch {
+    /// Match case-insensitively, creating missing keys in lowercase.
+    Lenient,
+    /// Match exactly; fields must already exist in the entry.
+    Strict,
+}
+
+impl KeyMatch {
+    /// Finds the key matching `segment`, falling back to the name to create.
+    fn resolve<'a>(self, mut keys: impl Iterator<Item = &'a String>, segment: &str) -> String {
+        match self {
+            KeyMatch::Lenient => keys
+                .find(|key| key.eq_ignore_ascii_case(segment))
+                .cloned()
+                .unwrap_or_else(|| segment.to_ascii_lowercase()),
+            KeyMatch::Strict => segment.to_string(),
+        }
+    }
+}
+
+/// Sets `new` at `path` inside `value` and returns the resolved (properly
+/// cased) path. `Lenient` creates intermediate objects and fields, `Strict`
+/// requires them to exist.
+fn patch(
+    value: &mut Value,
+    path: &[&str],
+    new: Value,
+    mode: KeyMatch,
+    walked: &mut Vec<String>,
+) -> Result<(), String> {
+    let Some((first, rest)) = path.split_first() else {
+        *value = new;
+        return Ok(());
+    };
+    if value.is_null() && mode == KeyMatch::Lenient {
+        *value = Value::Object(Map::new());
+    }
+    let Value::Object(object) = value else {
+        return Err(format!(
+            "cannot set field {:?} on a non-object value",
+            first
+        ));
+    };
+    let key = mode.resolve(object.keys(), first);
+    walked.push(key.clone());
+    let slot = match mode {
+        KeyMatch::Lenient => object.entry(key).or_insert(Value::Null),
+        KeyMatch::Strict => object
+            .get_mut(&key)
+            .ok_or_else(|| format!("unknown field {:?}", walked.join(".")))?,
+    };
+    patch(slot, rest, new, mode, walked)
+}
+
+/// Reads the value at `path`, if any.
+fn lookup<'a>(value: &'a Value, path: &[&str], mode: KeyMatch) -> Option<&'a Value> {
+    path.iter().try_fold(value, |value, segment| {
+        let object = value.as_object()?;
+        object.get(&mode.resolve(object.keys(), segment))
+    })
+}
+
+/// Overrides collected against a map, committed all at once.
+pub(crate) struct Staged<'a, T> {
+    map: &'a mut NamedMap<T>,
+    /// Patched entries as JSON, with their deserialized form.
+    entries: BTreeMap<String, (Value, T)>,
+    mode: KeyMatch,
+}
+
+impl<'a, T> Staged<'a, T>
+where
+    T: Serialize + DeserializeOwned + FromName,
+{
+    pub(crate) fn new(map: &'a mut NamedMap<T>, mode: KeyMatch) -> Self {
+        Staged {
+            map,
+            entries: BTreeMap::new(),
+            mode,
+        }
+    }
+
+    /// Stages `raw` at `path` inside `entry`, trying it as JSON first and
+    /// then as a string (only as a string if the field holds one).
+    pub(crate) fn set(
+        &mut self,
+        variable: &str,
+        entry: &str,
+        path: &[&str],
+        raw: &str,
+    ) -> Result<AppliedOverride, String> {
+        let keys = self.entries.keys().chain(self.map.as_inner().keys());
+        let entry = self.mode.resolve(keys, entry);
+        let created = !self.map.contains_key(&entry) && !self.entries.contains_key(&entry);
+        let current = match self.entries.remove(&entry) {
+            Some((value, _)) => value,
+            None => {
+                let value = match self.map.get(&entry) {
+                    Some(value) => serde_json::to_value(value),
+                    None => serde_json::to_value(T::from_name(&entry)),
+                };
+                value.map_err(|err| err.to_string())?
+            }
+        };
+
+        let mut candidates = Vec::with_capacity(2);
+        if !matches!(lookup(&current, path, self.mode), Some(Value::String(_))) {
+            if let Ok(parsed) = serde_json::from_str::<Value>(raw) {
+                candidates.push(parsed);
+            }
+        }
+        candidates.push(Value::String(raw.to_string()));
+
+        let mut last_error = String::new();
+        for candidate in candidates {
+            let mut patched = current.clone();
+            // Starts with the entry so that errors name the full path.
+            let mut resolved = vec![entry.clone()];
+            patch(&mut patched, path, candidate, self.mode, &mut resolved)?;
+            resolved.remove(0);
+            match serde_json::from_value::<T>(patched.clone()) {
+                Ok(typed) => {
+                    self.entries.insert(entry.clone(), (patched, typed));
+                    return Ok(AppliedOverride {
+                        variable: variable.to_string(),
+                        entry,
+                        path: resolved,
+                        created,
+                    });
+                }
+                Err(err) => last_error = format!("error in entry {:?}: {}", entry, err),
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Writes the staged entries into the map.
+    pub(crate) fn commit(self) {
+        for (entry, (_, typed)) in self.entries {
+            self.map.insert(entry, typed);
+        }
+    }
+}
+
+impl<T> NamedMap<T>
+where
+    T: Serialize + DeserializeOwned + FromName,
+{
+    /// Overrides entry fields from environment variables named
+    /// `{prefix}__{ENTRY}__{FIELD}[__{NESTED}...]`.
+    ///
+    /// # Normalization
+    ///
+    /// The prefix must match exactly. The remaining `__`-separated segments
+    /// name the entry, then the (nested) field. Each segment matches an
+    /// existing entry name or object key case-insensitively; if none
+    /// matches, the segment is used in lowercase. Single underscores are
+    /// kept, so `APP__HTTP__MAX_BODY` sets `max_body` on entry `http`.
+    /// Without a field segment (`APP__HTTP`), the value replaces the entry.
+    ///
+    /// # Values
+    ///
+    /// A value is tried as JSON first (`true`, `42`, `1.5`, `[1, 2]`) and
+    /// then as a plain string, keeping the first that deserializes into `T`;
+    /// a field that currently holds a string always gets the string. So
+    /// `APP__LOGGER__VERSION=2.0` works for a `String` field and
+    /// `APP__LOGGER__OPTIONAL=true` for a `bool` one.
+    ///
+    /// Entries named by a variable but missing from the map are created with
+    /// `T::from_name` first. Variables are applied in name order, and either
+    /// all of them are applied or (on error) none.
+    ///
+    /// Variables outside the prefix are ignored even if they are not valid
+    /// Unicode; one under the prefix whose name or value is not is an error.
+    pub fn apply_env_overrides(
+        &mut self,
+        prefix: &str,
+    ) -> Result<Vec<AppliedOverride>, OverrideError> {
+        let marker = format!("{}__", prefix);
+        let mut vars = Vec::new();
+        for (name, value) in std::env::vars_os() {
+            if !name.to_string_lossy().starts_with(&marker) {
+                continue;
+            }
+            match (name.into_string(), value.into_string()) {
+                (Ok(name), Ok(value)) => vars.push((name, value)),
+                (name, _) => {
+                    return Err(OverrideError {
+                        variable: name.unwrap_or_else(|name| name.to_string_lossy().into_owned()),
+                        message: "not valid UTF-8".to_string(),
+                    })
+                }
+            }
+        }
+        self.apply_overrides_from(prefix, vars)
+    }
+
+    /// Like [`apply_env_overrides`](Self::apply_env_overrides), reading the
+    /// variables from `vars` instead of the process environment.
+    pub fn apply_overrides_from<I>(
+        &mut self,
+        prefix: &str,
+        vars: I,
+    ) -> Result<Vec<AppliedOverride>, OverrideError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let marker = format!("{}__", prefix);
+        let vars: BTreeMap<String, String> = vars
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(&marker))
+            .collect();
+
+        let mut staged = Staged::new(self, KeyMatch::Lenient);
+        let mut applied = Vec::with_capacity(vars.len());
+        for (variable, raw) in &vars {
+            let error = |message: String| OverrideError {
+                variable: variable.clone(),
+                message,
+            };
+            let segments: Vec<&str> = variable[marker.len()..].split("__").collect();
+            let (entry, path) = segments.split_first().expect("split yields one item");
+            if segments.iter().any(|segment| segment.is_empty()) {
+                return Err(error("empty name segment".to_string()));
+            }
+            applied.push(staged.set(variable, entry, path, raw).map_err(error)?);
+        }
+        staged.commit();
+        Ok(applied)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Limits {
+        cpu: u32,
+        max_memory: f64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Interface {
+        version: String,
+        optional: bool,
+        limits: Limits,
+    }
+
+    impl FromName for Interface {
+        fn from_name(_name: &str) -> Self {
+            Interface {
+                version: "latest".to_string(),
+                optional: false,
+                limits: Limits {
+                    cpu: 1,
+                    max_memory: 0.5,
+                },
+            }
+        }
+    }
+
+    fn vars(list: &[(&str, &str)]) -> Vec<(String, String)> {
+        list.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn sample() -> NamedMap<Interface> {
+        NamedMap::from(vec!["logger".to_string(), "HTTP".to_string()])
+    }
+
+    #[test]
+    fn test_fields_and_coercion() {
+        let mut map = sample();
+        let applied = map
+            .apply_overrides_from(
+                "APP",
+                vars(&[
+                    ("APP__LOGGER__VERSION", "2.0"),
+                    ("APP__LOGGER__OPTIONAL", "true"),
+                    ("APP__HTTP__LIMITS__CPU", "4"),
+                    ("APP__HTTP__LIMITS__MAX_MEMORY", "1.5"),
+                    ("OTHER__LOGGER__VERSION", "9"),
+                ]),
+            )
+            .unwrap();
+
+        assert_eq!(map["logger"].version, "2.0");
+        assert!(map["logger"].optional);
+        assert_eq!(
+            map["HTTP"].limits,
+            Limits {
+                cpu: 4,
+                max_memory: 1.5
+            }
+        );
+        assert_eq!(applied.len(), 4);
+        assert_eq!(applied[0].entry, "HTTP");
+        assert_eq!(applied[0].path, ["limits", "cpu"]);
+        assert!(!applied[0].created);
+    }
+
+    #[test]
+    fn test_missing_entry_is_created() {
+        let mut map = sample();
+        let applied = map
+            .apply_overrides_from("APP", vars(&[("APP__METRICS__VERSION", "1")]))
+            .unwrap();
+        assert!(applied[0].created);
+        assert_eq!(applied[0].entry, "metrics");
+        assert_eq!(map["metrics"].version, "1");
+        assert!(!map["metrics"].optional);
+    }
+
+    #[test]
+    fn test_whole_entry_replacement() {
+        let mut map = sample();
+        map.apply_overrides_from(
+            "APP",
+            vars(&[(
+                "APP__LOGGER",
+                r#"{"version": "3", "optional": true, "limits": {"cpu": 2, "max_memory": 1}}"#,
+            )]),
+        )
+        .unwrap();
+        assert_eq!(map["logger"].version, "3");
+        assert_eq!(map["logger"].limits.cpu, 2);
+    }
+
+    #[test]
+    fn test_errors_apply_nothing() {
+        let mut map = sample();
+        let err = map
+            .apply_overrides_from(
+                "APP",
+                vars(&[
+                    ("APP__HTTP__VERSION", "2"),
+                    ("APP__LOGGER__OPTIONAL", "maybe"),
+                ]),
+            )
+            .unwrap_err();
+        assert_eq!(err.variable, "APP__LOGGER__OPTIONAL");
+        assert!(
+            err.message.contains("error in entry \"logger\""),
+            "{}",
+            err
+        );
+        assert_eq!(map, sample());
+
+        let err = map
+            .apply_overrides_from("APP", vars(&[("APP__LOGGER__VERSION__X", "1")]))
+            .unwrap_err();
+        assert!(err.message.contains("non-object"), "{}", err);
+
+        assert!(map
+            .apply_overrides_from("APP", vars(&[("APP__", "1")]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_process_environment() {
+        std::env::set_var("FLEXICON_ENV_TEST__LOGGER__VERSION", "7");
+        let mut map = sample();
+        let applied = map.apply_env_overrides("FLEXICON_ENV_TEST").unwrap();
+        std::env::remove_var("FLEXICON_ENV_TEST__LOGGER__VERSION");
+        assert_eq!(applied.len(), 1);
+        assert_eq!(map["logger"].version, "7");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_process_environment_non_utf8() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let invalid = OsStr::from_bytes(b"caf\xe9");
+        std::env::set_var("FLEXICON_ENV_UNRELATED", invalid);
+        std::env::set_var("FLEXICON_ENV_UTF8__LOGGER__VERSION", "8");
+        let mut map = sample();
+        let applied = map.apply_env_overrides("FLEXICON_ENV_UTF8");
+        std::env::remove_var("FLEXICON_ENV_UTF8__LOGGER__VERSION");
+        assert_eq!(applied.unwrap().len(), 1);
+        assert_eq!(map["logger"].version, "8");
+
+        std::env::set_var("FLEXICON_ENV_UTF8__LOGGER__VERSION", invalid);
+        let mut map = sample();
+        let err = map.apply_env_overrides("FLEXICON_ENV_UTF8").unwrap_err();
+        std::env::remove_var("FLEXICON_ENV_UTF8__LOGGER__VERSION");
+        std::env::remove_var("FLEXICON_ENV_UNRELATED");
+        assert_eq!(
+            err.to_string(),
+            "error in override \"FLEXICON_ENV_UTF8__LOGGER__VERSION\": not valid UTF-8"
+        );
+        assert_eq!(map, sample());
+    }
+}
@@ -0,0 +1,511 @@
+// flexicon/src/adaptive/extends.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Entries inheriting from other entries through an `extends` field.
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use super::defaults::deep_merge;
+use super::{FromName, NamedMap, RawNamedMap};
+
+/// The reserved field naming an entry's parents.
+const EXTENDS: &str = "extends";
+
+/// Why [`NamedMap::resolve_extends`] failed.
+#[derive(Debug)]
+pub enum ExtendsError {
+    /// `extends` is neither a name nor a list of names.
+    InvalidMarker {
+        /// The entry carrying the marker.
+        name: String,
+    },
+    /// `extends` names an entry that does not exist.
+    UnknownParent {
+        /// The entry carrying the marker.
+        name: String,
+        /// The missing parent.
+        parent: String,
+    },
+    /// Entries extend each other in a loop.
+    Cycle {
+        /// The loop, starting and ending with the same name.
+        path: Vec<String>,
+    },
+    /// An entry could not be converted to or from JSON.
+    InvalidEntry {
+        /// The entry's name.
+        name: String,
+        /// The underlying serde error.
+        source: serde_json::Error,
+    },
+}
+
+impl fmt::Display for ExtendsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtendsError::InvalidMarker { name } => write!(
+                f,
+                "`extends` of {:?} must be a name or a list of names",
+                name
+            ),
+            ExtendsError::UnknownParent { name, parent } => {
+                write!(f, "{:?} extends unknown entry {:?}", name, parent)
+            }
+            ExtendsError::Cycle { path } => write!(f, "extends cycle: {}", path.join(" -> ")),
+            ExtendsError::InvalidEntry { name, source } => {
+                write!(f, "error in entry {:?}: {}", name, source)
+            }
+        }
+    }
+}
+
+impl Error for ExtendsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ExtendsError::InvalidEntry { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The parts of `value` that differ from `default`, recursing into
+/// objects; `None` if there are none.
+fn overrides(value: Value, default: &Value) -> Option<Value> {
+    match (value, default) {
+        (Value::Object(value), Value::Object(default)) => {
+            let changed: Map<String, Value> = value
+                .into_iter()
+                .filter_map(|(key, value)| match default.get(&key) {
+                    Some(default) => overrides(value, default).map(|value| (key, value)),
+                    None => Some((key, value)),
+                })
+                .collect();
+            (!changed.is_empty()).then_some(Value::Object(changed))
+        }
+        (value, default) => (value != *default).then_some(value),
+    }
+}
+
+/// Entries in their JSON form, holding only the fields each one sets;
+/// resolved on demand.
+struct Resolver {
+    values: HashMap<String, Value>,
+    resolved: HashMap<String, Value>,
+}
+
+impl Resolver {
+    fn parents(&self, name: &str) -> Result<Vec<String>, ExtendsError> {
+        let invalid = || ExtendsError::InvalidMarker {
+            name: name.to_string(),
+        };
+        let parents = match self.values[name].get(EXTENDS) {
+            None | Some(Value::Null) => Vec::new(),
+            Some(Value::String(parent)) => vec![parent.clone()],
+            Some(Value::Array(parents)) => parents
+                .iter()
+                .map(|parent| parent.as_str().map(str::to_string).ok_or_else(invalid))
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err(invalid()),
+        };
+        for parent in &parents {
+            if !self.values.contains_key(parent) {
+                return Err(ExtendsError::UnknownParent {
+                    name: name.to_string(),
+                    parent: parent.clone(),
+                });
+            }
+        }
+        Ok(parents)
+    }
+
+    /// Resolves `name` and, first, its ancestors, into the fields set by
+    /// the entry or inherited; `stack` holds the chain of entries being
+    /// resolved, to report cycles.
+    fn resolve(&mut self, name: &str, stack: &mut Vec<String>) -> Result<(), ExtendsError> {
+        if self.resolved.contains_key(name) {
+            return Ok(());
+        }
+        if let Some(start) = stack.iter().position(|entry| entry == name) {
+            let mut path = stack[start..].to_vec();
+            path.push(name.to_string());
+            return Err(ExtendsError::Cycle { path });
+        }
+
+        let parents = self.parents(name)?;
+        stack.push(name.to_string());
+        for parent in &parents {
+            self.resolve(parent, stack)?;
+        }
+        stack.pop();
+
+        let mut value = Value::Object(Map::new());
+        for parent in &parents {
+            deep_merge(&mut value, self.resolved[parent].clone());
+        }
+        deep_merge(&mut value, self.values[name].clone());
+        if let Value::Object(fields) = &mut value {
+            fields.remove(EXTENDS);
+        }
+        self.resolved.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    /// Builds the entry `name`: `T::from_name(name)` with the resolved
+    /// fields deep-merged on top.
+    fn build<T>(&mut self, name: &str) -> Result<T, ExtendsError>
+    where
+        T: Serialize + DeserializeOwned + FromName,
+    {
+        self.resolve(name, &mut Vec::new())?;
+        let mut value = default_value::<T>(name)?;
+        deep_merge(&mut value, self.resolved[name].clone());
+        serde_json::from_value(value).map_err(|source| ExtendsError::InvalidEntry {
+            name: name.to_string(),
+            source,
+        })
+    }
+}
+
+fn default_value<T: Serialize + FromName>(name: &str) -> Result<Value, ExtendsError> {
+    serde_json::to_value(T::from_name(name)).map_err(|source| ExtendsError::InvalidEntry {
+        name: name.to_string(),
+        source,
+    })
+}
+
+impl<T> NamedMap<T>
+where
+    T: Serialize + DeserializeOwned + FromName,
+{
+    /// Resolves `extends` inheritance between entries.
+    ///
+    /// An entry whose JSON form has an `extends` field naming another entry
+    /// (or a list of entries) starts from `T::from_name`, takes every field
+    /// its parents set away from their defaults, in order, and finally its
+    /// own such fields; objects merge recursively. Chains resolve
+    /// transitively, the marker is removed, and the result is deserialized
+    /// back into `T`, so `T` should declare `extends` as an optional field.
+    ///
+    /// The map no longer knows which fields were written, so a field left
+    /// at its `from_name` default counts as unset and is inherited; resolve
+    /// the input with [`RawNamedMap::typed_with_extends`] to keep such
+    /// fields. Fails on unknown parents and on cycles, reporting the cycle
+    /// path; on error the map is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flexicon::adaptive::{FromName, NamedMap};
+    /// # use serde::{Deserialize, Serialize};
+    /// #[derive(Clone, Serialize, Deserialize)]
+    /// struct Database {
+    ///     #[serde(default, skip_serializing_if = "Option::is_none")]
+    ///     extends: Option<String>,
+    ///     #[serde(default)]
+    ///     host: String,
+    ///     #[serde(default)]
+    ///     port: u16,
+    /// }
+    /// # impl FromName for Database {
+    /// #     fn from_name(_name: &str) -> Self {
+    /// #         Database { extends: None, host: String::new(), port: 0 }
+    /// #     }
+    /// # }
+    ///
+    /// let mut map: NamedMap<Database> = serde_json::from_value(serde_json::json!({
+    ///     "base": {"host": "db.local", "port": 5432},
+    ///     "replica": {"extends": "base", "port": 5433},
+    /// }))
+    /// .unwrap();
+    /// map.resolve_extends().unwrap();
+    /// assert_eq!(map["replica"].host, "db.local");
+    /// assert_eq!(map["replica"].port, 5433);
+    /// assert!(map["replica"].extends.is_none());
+    /// ```
+    pub fn resolve_extends(&mut self) -> Result<(), ExtendsError> {
+        let mut values = HashMap::with_capacity(self.len());
+        for (name, value) in self.iter() {
+            let value =
+                serde_json::to_value(value).map_err(|source| ExtendsError::InvalidEntry {
+                    name: name.clone(),
+                    source,
+                })?;
+            let set = overrides(value, &default_value::<T>(name)?)
+                .unwrap_or_else(|| Value::Object(Map::new()));
+            values.insert(name.clone(), set);
+        }
+        let mut resolver = Resolver {
+            values,
+            resolved: HashMap::new(),
+        };
+
+        // Sorted so that the reported error does not depend on hash order.
+        let mut extending: Vec<String> = resolver
+            .values
+            .iter()
+            .filter(|(_, value)| value.get(EXTENDS).is_some_and(|marker| !marker.is_null()))
+            .map(|(name, _)| name.clone())
+            .collect();
+        extending.sort_unstable();
+
+        let mut updates = Vec::with_capacity(extending.len());
+        for name in extending {
+            let value = resolver.build::<T>(&name)?;
+            updates.push((name, value));
+        }
+
+        for (name, value) in updates {
+            self.insert(name, value);
+        }
+        Ok(())
+    }
+}
+
+impl RawNamedMap {
+    /// Converts every entry into a `T`, resolving `extends` inheritance as
+    /// [`NamedMap::resolve_extends`] does, but on the fields each entry
+    /// actually sets: a child that writes a field back to its default
+    /// keeps it rather than inheriting the parent's.
+    ///
+    /// Every entry starts from `T::from_name`, so values may be partial; a
+    /// bare name sets no fields. Fails like `resolve_extends`, and with
+    /// [`ExtendsError::InvalidEntry`] on an entry that does not deserialize.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flexicon::adaptive::{FromName, RawNamedMap};
+    /// # use serde::{Deserialize, Serialize};
+    /// #[derive(Clone, Serialize, Deserialize)]
+    /// struct Server {
+    ///     #[serde(default, skip_serializing_if = "Option::is_none")]
+    ///     extends: Option<String>,
+    ///     tls: bool,
+    /// }
+    /// # impl FromName for Server {
+    /// #     fn from_name(_name: &str) -> Self {
+    /// #         Server { extends: None, tls: false }
+    /// #     }
+    /// # }
+    ///
+    /// let raw: RawNamedMap = serde_json::from_value(serde_json::json!({
+    ///     "base": {"tls": true},
+    ///     "local": {"extends": "base", "tls": false},
+    /// }))
+    /// .unwrap();
+    /// let map = raw.typed_with_extends::<Server>().unwrap();
+    /// assert!(map["base"].tls);
+    /// assert!(!map["local"].tls);
+    /// ```
+    pub fn typed_with_extends<T>(self) -> Result<NamedMap<T>, ExtendsError>
+    where
+        T: Serialize + DeserializeOwned + FromName,
+    {
+        let values: HashMap<String, Value> = self
+            .into_inner()
+            .into_iter()
+            .map(|(name, value)| match value {
+                Value::Null => (name, Value::Object(Map::new())),
+                value => (name, value),
+            })
+            .collect();
+        // Sorted so that the reported error does not depend on hash order.
+        let mut names: Vec<String> = values.keys().cloned().collect();
+        names.sort_unstable();
+        let mut resolver = Resolver {
+            values,
+            resolved: HashMap::new(),
+        };
+
+        let mut map = NamedMap::with_capacity(names.len());
+        for name in names {
+            let value = resolver.build::<T>(&name)?;
+            map.insert(name, value);
+        }
+        Ok(map)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Pool {
+        min: u32,
+        max: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Database {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        extends: Option<Value>,
+        host: String,
+        port: u16,
+        tls: bool,
+        pool: Pool,
+    }
+
+    impl FromName for Database {
+        fn from_name(_name: &str) -> Self {
+            Database {
+                extends: None,
+                host: "localhost".to_string(),
+                port: 5432,
+                tls: false,
+                pool: Pool { min: 1, max: 10 },
+            }
+        }
+    }
+
+    fn parse(value: Value) -> NamedMap<Database> {
+        NamedMap::from_json_value_with_defaults(value).unwrap()
+    }
+
+    #[test]
+    fn test_two_level_chain() {
+        let mut map = parse(json!({
+            "base": {"host": "db.local", "tls": true, "pool": {"max": 50}},
+            "replica": {"extends": "base", "port": 5433},
+            "analytics": {"extends": "replica", "pool": {"min": 5}},
+        }));
+        map.resolve_extends().unwrap();
+
+        assert_eq!(map["replica"].host, "db.local");
+        assert_eq!(map["replica"].port, 5433);
+        assert!(map["replica"].tls);
+        assert_eq!(map["replica"].extends, None);
+
+        let analytics = &map["analytics"];
+        assert_eq!(analytics.host, "db.local");
+        assert_eq!(analytics.port, 5433);
+        assert_eq!(analytics.pool, Pool { min: 5, max: 50 });
+        assert_eq!(analytics.extends, None);
+
+        assert_eq!(map["base"].pool, Pool { min: 1, max: 50 });
+    }
+
+    #[test]
+    fn test_diamond() {
+        let mut map = parse(json!({
+            "base": {"host": "db.local", "tls": true},
+            "left": {"extends": "base", "pool": {"max": 20}},
+            "right": {"extends": "base", "port": 6000, "pool": {"min": 4}},
+            "bottom": {"extends": ["left", "right"], "host": "db.bottom"},
+        }));
+        map.resolve_extends().unwrap();
+
+        let bottom = &map["bottom"];
+        assert_eq!(bottom.host, "db.bottom");
+        assert_eq!(bottom.port, 6000);
+        assert!(bottom.tls);
+        assert_eq!(bottom.pool, Pool { min: 4, max: 20 });
+        assert_eq!(bottom.extends, None);
+    }
+
+    #[test]
+    fn test_raw_child_resets_field_to_default() {
+        let raw: RawNamedMap = serde_json::from_value(json!({
+            "base": {"host": "db.local", "tls": true, "pool": {"max": 50}},
+            "local": {"extends": "base", "tls": false, "pool": {"max": 10}},
+            "edge": {"extends": ["local"], "port": 6000},
+            "metrics": null,
+        }))
+        .unwrap();
+        let map = raw.typed_with_extends::<Database>().unwrap();
+
+        let local = &map["local"];
+        assert_eq!(local.host, "db.local");
+        assert!(!local.tls);
+        assert_eq!(local.pool, Pool { min: 1, max: 10 });
+        assert_eq!(local.extends, None);
+        assert!(!map["edge"].tls);
+        assert_eq!(map["edge"].port, 6000);
+        assert!(map["base"].tls);
+        assert_eq!(map["metrics"], Database::from_name("metrics"));
+
+        // Resolved after deserialization, the reset is indistinguishable
+        // from an unset field.
+        let mut map = parse(json!({
+            "base": {"tls": true},
+            "local": {"extends": "base", "tls": false},
+        }));
+        map.resolve_extends().unwrap();
+        assert!(map["local"].tls);
+    }
+
+    #[test]
+    fn test_raw_errors() {
+        let raw: RawNamedMap = serde_json::from_value(json!({
+            "a": {"extends": "b"},
+            "b": {"extends": "a"},
+        }))
+        .unwrap();
+        assert_eq!(
+            raw.typed_with_extends::<Database>()
+                .unwrap_err()
+                .to_string(),
+            "extends cycle: a -> b -> a"
+        );
+
+        let raw: RawNamedMap =
+            serde_json::from_value(json!({"a": {"extends": "b"}, "b": {"port": "x"}})).unwrap();
+        assert!(matches!(
+            raw.typed_with_extends::<Database>(),
+            Err(ExtendsError::InvalidEntry { name, .. }) if name == "a"
+        ));
+    }
+
+    #[test]
+    fn test_cycle_error() {
+        let mut map = parse(json!({
+            "a": {"extends": "b"},
+            "b": {"extends": "c", "port": 1},
+            "c": {"extends": "a"},
+            "d": {"host": "db.local"},
+        }));
+        let before = map.clone();
+        let err = map.resolve_extends().unwrap_err();
+        match &err {
+            ExtendsError::Cycle { path } => assert_eq!(path, &["a", "b", "c", "a"]),
+            other => panic!("expected a cycle, got {:?}", other),
+        }
+        assert_eq!(err.to_string(), "extends cycle: a -> b -> c -> a");
+        assert_eq!(map, before);
+
+        let mut map = parse(json!({"a": {"extends": "a"}}));
+        assert_eq!(
+            map.resolve_extends().unwrap_err().to_string(),
+            "extends cycle: a -> a"
+        );
+    }
+
+    #[test]
+    fn test_invalid_markers() {
+        let mut map = parse(json!({"a": {"extends": "missing"}}));
+        assert_eq!(
+            map.resolve_extends().unwrap_err().to_string(),
+            "\"a\" extends unknown entry \"missing\""
+        );
+
+        let mut map = parse(json!({"a": {"extends": 1}}));
+        assert!(matches!(
+            map.resolve_extends(),
+            Err(ExtendsError::InvalidMarker { name }) if name == "a"
+        ));
+    }
+}
@@ -0,0 +1,167 @@
+// flexicon/src/adaptive/fast.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `FastNamedMap`: a `NamedMap` hashed with aHash instead of SipHash.
+
+use super::NamedMap;
+
+/// A [`NamedMap`] using `ahash::RandomState`, for maps on hot lookup paths.
+///
+/// Still randomly seeded, so it remains resistant to HashDoS, but faster
+/// than the default SipHash on short names. Serde support and every
+/// helper are the same as for `NamedMap`.
+///
+/// # Example
+///
+/// ```rust
+/// use flexicon::adaptive::{FastNamedMap, FastNamedMapExt};
+///
+/// let mut map = FastNamedMap::new();
+/// map.insert("http".to_string(), 80);
+/// assert_eq!(map["http"], 80);
+/// ```
+pub type FastNamedMap<T> = NamedMap<T, ahash::RandomState>;
+
+/// Constructors for [`FastNamedMap`] that do not name the hasher.
+///
+/// These live in a trait because a second inherent `new` on `NamedMap`
+/// would make a plain `NamedMap::new()` ambiguous.
+pub trait FastNamedMapExt {
+    /// Creates an empty map.
+    fn new() -> Self;
+
+    /// Creates an empty map with room for at least `capacity` entries.
+    fn with_capacity(capacity: usize) -> Self;
+}
+
+impl<T> FastNamedMapExt for FastNamedMap<T> {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, ahash::RandomState::new())
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adaptive::MergeStrategy;
+
+    #[test]
+    fn test_constructors() {
+        let mut map = FastNamedMap::new();
+        map.insert("a".to_string(), 1u32);
+        assert_eq!(map["a"], 1);
+
+        let map = FastNamedMap::<u32>::with_capacity(32);
+        assert!(map.capacity() >= 32);
+
+        // The default-hasher constructor still infers `RandomState`.
+        let mut plain = NamedMap::new();
+        plain.insert("a".to_string(), 1u32);
+        let _: NamedMap<u32> = plain;
+    }
+
+    #[test]
+    fn test_set_and_group_ops_keep_the_hasher() {
+        let map: FastNamedMap<u16> = [("db.primary", 5432), ("http", 80)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect();
+        let other: FastNamedMap<u16> = [("http".to_string(), 8080)].into_iter().collect();
+
+        let both: FastNamedMap<u16> = map.intersection(&other);
+        assert_eq!(both["http"], 80);
+        assert_eq!(map.difference(&other).len(), 1);
+        assert_eq!(map.filter_prefix("db.").len(), 1);
+        let all = map.clone().union(other, MergeStrategy::KeepExisting);
+        assert_eq!(all["http"], 80);
+
+        let groups = map.clone().group_by_separator('.', "default");
+        let db: &FastNamedMap<u16> = &groups["db"];
+        assert_eq!(db["primary"], 5432);
+        assert_eq!(groups.flatten_groups('.', "default").unwrap(), map);
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod serde_json {
+        use super::*;
+        use crate::adaptive::{DuplicatePolicy, FromName};
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct Device {
+            version: String,
+            #[serde(default)]
+            enabled: bool,
+        }
+
+        impl FromName for Device {
+            fn from_name(_name: &str) -> Self {
+                Device {
+                    version: "latest".to_string(),
+                    enabled: true,
+                }
+            }
+        }
+
+        fn entries<S>(map: &NamedMap<Device, S>) -> Vec<(&String, &Device)> {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by_key(|(name, _)| *name);
+            entries
+        }
+
+        #[test]
+        fn test_same_behavior_as_default_hasher() {
+            let documents = [
+                r#"{"logger": {"version": "1"}, "http": {"version": "2", "enabled": true}}"#,
+                r#"["logger", {"http": {"version": "2"}}, "metrics"]"#,
+                r#""logger""#,
+                r#"{}"#,
+            ];
+            for document in documents {
+                let sip = NamedMap::<Device>::from_json_str(document).unwrap();
+                let fast = FastNamedMap::<Device>::from_json_str(document).unwrap();
+                assert_eq!(entries(&sip), entries(&fast), "{}", document);
+                assert_eq!(sip.to_json_value().unwrap(), fast.to_json_value().unwrap());
+                assert_eq!(sip.to_simple_form(), fast.to_simple_form());
+            }
+
+            for document in [r#"["a", "a"]"#, r#"{"a": {"version": 1}}"#, r#"[1]"#] {
+                let sip = NamedMap::<Device>::from_json_str(document).unwrap_err();
+                let fast = FastNamedMap::<Device>::from_json_str(document).unwrap_err();
+                assert_eq!(sip.to_string(), fast.to_string());
+            }
+        }
+
+        #[test]
+        fn test_helpers_keep_the_hasher() {
+            let mut fast = FastNamedMap::<Device>::from_comma_separated("logger, http").unwrap();
+            fast.entry("metrics").or_insert_from_name();
+            let names = ["logger", "metrics"].map(String::from);
+            let other =
+                FastNamedMap::<Device>::from_names_with_policy(names, DuplicatePolicy::Error)
+                    .unwrap();
+            assert_eq!(fast.diff(&other).removed, [("http", &fast["http"])]);
+            fast.merge(other, MergeStrategy::KeepExisting);
+            assert_eq!(fast.len(), 3);
+
+            let value = ::serde_json::json!({ "logger": { "version": "1" } });
+            let map = FastNamedMap::<Device>::from_json_value_with_defaults(value.clone()).unwrap();
+            assert!(map["logger"].enabled);
+            let (map, errors) = FastNamedMap::<Device>::from_json_value_lossy(value);
+            assert!(errors.is_empty());
+            assert!(!map["logger"].enabled);
+        }
+    }
+}
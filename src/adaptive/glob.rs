@@ -0,0 +1,282 @@
+// flexicon/src/adaptive/glob.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Shell-style glob patterns over names.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::BuildHasher;
+
+use super::NamedMap;
+
+/// A glob pattern that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobError {
+    /// The pattern as given.
+    pub pattern: String,
+    /// Byte offset of the `[` that is never closed.
+    pub index: usize,
+}
+
+impl fmt::Display for GlobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid glob {:?}: unclosed `[` at index {}",
+            self.pattern, self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GlobError {}
+
+enum Token {
+    Literal(char),
+    /// `?`
+    AnyChar,
+    /// `*`
+    AnyRun,
+    /// `[...]`, as inclusive ranges.
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl Token {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Token::Literal(literal) => *literal == c,
+            Token::AnyChar => true,
+            Token::AnyRun => false,
+            Token::Class { negated, ranges } => {
+                ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi) != *negated
+            }
+        }
+    }
+}
+
+/// A parsed pattern; see [`NamedMap::matching_glob`] for the syntax.
+pub(crate) struct Glob(Vec<Token>);
+
+impl Glob {
+    pub(crate) fn new(pattern: &str) -> Result<Self, GlobError> {
+        let mut tokens = Vec::new();
+        let mut chars = pattern.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            let token = match c {
+                '*' => Token::AnyRun,
+                '?' => Token::AnyChar,
+                '[' => {
+                    let negated = chars.next_if(|&(_, c)| c == '!' || c == '^').is_some();
+                    let mut ranges = Vec::new();
+                    loop {
+                        let Some((_, lo)) = chars.next() else {
+                            return Err(GlobError {
+                                pattern: pattern.to_string(),
+                                index,
+                            });
+                        };
+                        // A `]` right after the opening bracket is a literal.
+                        if lo == ']' && !ranges.is_empty() {
+                            break;
+                        }
+                        let hi = match chars.peek() {
+                            Some(&(_, '-')) => {
+                                let mut ahead = chars.clone();
+                                ahead.next();
+                                match ahead.next() {
+                                    Some((_, hi)) if hi != ']' => {
+                                        chars = ahead;
+                                        hi
+                                    }
+                                    _ => lo,
+                                }
+                            }
+                            _ => lo,
+                        };
+                        ranges.push((lo, hi));
+                    }
+                    Token::Class { negated, ranges }
+                }
+                c => Token::Literal(c),
+            };
+            tokens.push(token);
+        }
+        Ok(Glob(tokens))
+    }
+
+    pub(crate) fn is_match(&self, name: &str) -> bool {
+        let tokens = &self.0;
+        let (mut t, mut n) = (0, 0);
+        // Where to resume after the last `*`: the token after it and the
+        // byte offset it is currently assumed to have consumed up to.
+        let mut resume: Option<(usize, usize)> = None;
+        loop {
+            if let Some(token) = tokens.get(t) {
+                if let Token::AnyRun = token {
+                    resume = Some((t + 1, n));
+                    t += 1;
+                    continue;
+                }
+                if let Some(c) = name[n..].chars().next() {
+                    if token.matches(c) {
+                        t += 1;
+                        n += c.len_utf8();
+                        continue;
+                    }
+                }
+            } else if n == name.len() {
+                return true;
+            }
+            match resume {
+                Some((after_star, from)) => match name[from..].chars().next() {
+                    Some(c) => {
+                        let from = from + c.len_utf8();
+                        resume = Some((after_star, from));
+                        t = after_star;
+                        n = from;
+                    }
+                    None => return false,
+                },
+                None => return false,
+            }
+        }
+    }
+}
+
+impl<T, S> NamedMap<T, S> {
+    /// Returns the entries whose names match the glob `pattern`, in
+    /// arbitrary order.
+    ///
+    /// `*` matches any run of characters, `?` any single character and
+    /// `[...]` one character from a class such as `[abc]`, `[a-z]` or
+    /// `[!0-9]` (`^` also negates). Everything else, including a `]` right
+    /// after the opening bracket, matches itself. Fails if a `[` is never
+    /// closed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let map: NamedMap<u16> = [("http", 80), ("https", 443), ("ssh", 22)]
+    ///     .into_iter()
+    ///     .map(|(name, port)| (name.to_string(), port))
+    ///     .collect();
+    ///
+    /// let mut web: Vec<_> = map.matching_glob("http*").unwrap().map(|(name, _)| name).collect();
+    /// web.sort();
+    /// assert_eq!(web, ["http", "https"]);
+    /// assert!(map.matching_glob("[a-z").is_err());
+    /// ```
+    pub fn matching_glob<'a>(
+        &'a self,
+        pattern: &str,
+    ) -> Result<impl Iterator<Item = (&'a str, &'a T)> + 'a, GlobError> {
+        let glob = Glob::new(pattern)?;
+        Ok(self
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+            .filter(move |(name, _)| glob.is_match(name)))
+    }
+}
+
+impl<T, S: BuildHasher + Clone> NamedMap<T, S> {
+    /// Removes the entries whose names match the glob `pattern` and
+    /// returns them as a new map. See [`matching_glob`](Self::matching_glob)
+    /// for the syntax; an invalid pattern leaves the map untouched.
+    pub fn remove_matching_glob(&mut self, pattern: &str) -> Result<NamedMap<T, S>, GlobError> {
+        let glob = Glob::new(pattern)?;
+        Ok(self.retain_names(|name, _| !glob.is_match(name)))
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        Glob::new(pattern).unwrap().is_match(name)
+    }
+
+    fn names(map: &NamedMap<i32>, pattern: &str) -> Vec<String> {
+        let mut names: Vec<String> = map
+            .matching_glob(pattern)
+            .unwrap()
+            .map(|(name, _)| name.to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_wildcards_at_start_middle_and_end() {
+        assert!(matches("http*", "http"));
+        assert!(matches("http*", "https-proxy"));
+        assert!(!matches("http*", "xhttp"));
+        assert!(matches("*-proxy", "https-proxy"));
+        assert!(!matches("*-proxy", "proxy"));
+        assert!(matches("eth*.vlan", "eth0.vlan"));
+        assert!(matches("eth*.vlan", "eth.vlan"));
+        assert!(!matches("eth*.vlan", "eth0.vlan2"));
+        assert!(matches("*a*b*", "xxaxxbxx"));
+        assert!(matches("eth?", "eth0"));
+        assert!(!matches("eth?", "eth"));
+        assert!(matches("caf?", "café"));
+        assert!(matches("*", ""));
+        assert!(matches("", ""));
+        assert!(!matches("", "a"));
+    }
+
+    #[test]
+    fn test_bracket_classes() {
+        assert!(matches("eth[0-3]", "eth2"));
+        assert!(!matches("eth[0-3]", "eth4"));
+        assert!(matches("eth[!0-3]", "eth4"));
+        assert!(matches("eth[^0-3]", "eth4"));
+        assert!(matches("[abc]x", "bx"));
+        assert!(matches("[]]", "]"));
+        assert!(matches("[a-]", "-"));
+        assert!(matches("*[0-9][0-9]", "vlan42"));
+        assert!(!matches("*[0-9][0-9]", "vlan4"));
+
+        for pattern in ["[", "eth[0-3", "[]", "a[!"] {
+            let err = Glob::new(pattern).err().unwrap();
+            assert_eq!(err.index, pattern.find('[').unwrap(), "{}", pattern);
+        }
+        assert_eq!(
+            Glob::new("eth[0-3").err().unwrap().to_string(),
+            "invalid glob \"eth[0-3\": unclosed `[` at index 3"
+        );
+    }
+
+    #[test]
+    fn test_matching_and_removing() {
+        let mut map = NamedMap::new();
+        for (name, port) in [("http", 80), ("https", 443), ("eth0", 0), ("eth1", 1)] {
+            map.insert(name.to_string(), port);
+        }
+        assert_eq!(names(&map, "http*"), ["http", "https"]);
+        assert_eq!(names(&map, "*s"), ["https"]);
+        assert_eq!(names(&map, "eth[1-9]"), ["eth1"]);
+        assert!(names(&map, "ssh*").is_empty());
+
+        assert!(map.remove_matching_glob("eth[").is_err());
+        assert_eq!(map.len(), 4);
+
+        let removed = map.remove_matching_glob("eth?").unwrap();
+        assert_eq!(names(&removed, "*"), ["eth0", "eth1"]);
+        assert_eq!(names(&map, "*"), ["http", "https"]);
+    }
+}
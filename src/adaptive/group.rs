@@ -0,0 +1,232 @@
+// flexicon/src/adaptive/group.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Slicing keys that follow a `group.name` convention.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::BuildHasher;
+
+use super::map::HashMap;
+use super::NamedMap;
+
+/// Two nested entries that [`NamedMap::flatten_groups`] would join into
+/// the same key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupCollision {
+    /// The shared flat key.
+    pub key: String,
+    /// The two entries as `(group, name)`, sorted.
+    pub entries: [(String, String); 2],
+}
+
+impl fmt::Display for GroupCollision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [(group_a, name_a), (group_b, name_b)] = &self.entries;
+        write!(
+            f,
+            "{:?} in group {:?} and {:?} in group {:?} would both become {:?}",
+            name_a, group_a, name_b, group_b, self.key
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GroupCollision {}
+
+impl<T: Clone, S: BuildHasher + Clone> NamedMap<T, S> {
+    /// Returns the entries whose keys start with `prefix`, keys unchanged.
+    pub fn filter_prefix(&self, prefix: &str) -> NamedMap<T, S> {
+        self.filter_cloned(|name| name.starts_with(prefix))
+    }
+}
+
+impl<T, S: BuildHasher + Clone> NamedMap<T, S> {
+    /// Nests the map by splitting every key on the first `sep`:
+    /// `db.primary` becomes `primary` in group `db`, and `db.pool.max`
+    /// becomes `pool.max` in group `db`. Keys without `sep` go, unchanged,
+    /// into `default_group`. Keys that spell out the default group
+    /// (`default.x`) go there too, but keep their full key, so they never
+    /// meet a bare `x`. Every map of the result uses `self`'s hasher.
+    ///
+    /// [`flatten_groups`](Self::flatten_groups) with the same arguments
+    /// reverses it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let map: NamedMap<u16> = [("db.primary", 5432), ("db.replica", 5433), ("http", 80)]
+    ///     .into_iter()
+    ///     .map(|(name, port)| (name.to_string(), port))
+    ///     .collect();
+    ///
+    /// let groups = map.clone().group_by_separator('.', "default");
+    /// assert_eq!(groups["db"]["replica"], 5433);
+    /// assert_eq!(groups["default"]["http"], 80);
+    /// assert_eq!(groups.flatten_groups('.', "default").unwrap(), map);
+    /// ```
+    pub fn group_by_separator(self, sep: char, default_group: &str) -> NamedMap<NamedMap<T, S>, S> {
+        let hasher = self.hasher().clone();
+        let mut groups = HashMap::with_hasher(hasher.clone());
+        for (key, value) in self {
+            let (group, name) = match key.split_once(sep) {
+                Some((group, _)) if group == default_group => (group.to_string(), key.clone()),
+                Some((group, name)) => (group.to_string(), name.to_string()),
+                None => (default_group.to_string(), key),
+            };
+            groups
+                .entry(group)
+                .or_insert_with(|| NamedMap::with_hasher(hasher.clone()))
+                .insert(name, value);
+        }
+        NamedMap::from(groups)
+    }
+}
+
+impl<T, S: BuildHasher + Clone> NamedMap<NamedMap<T, S>, S> {
+    /// Joins a nested map back into flat `group<sep>name` keys; entries of
+    /// `default_group` keep their names as they are. The inverse of
+    /// [`group_by_separator`](NamedMap::group_by_separator).
+    ///
+    /// Fails if two entries join into the same key, e.g. `b.c` in group `a`
+    /// and `c` in group `a.b`.
+    pub fn flatten_groups(
+        self,
+        sep: char,
+        default_group: &str,
+    ) -> Result<NamedMap<T, S>, GroupCollision> {
+        let hasher = self.hasher().clone();
+        let mut entries: Vec<(String, String, String, T)> = Vec::new();
+        for (group, members) in self {
+            for (name, value) in members {
+                let key = if group == default_group {
+                    name.clone()
+                } else {
+                    let mut key = String::with_capacity(group.len() + sep.len_utf8() + name.len());
+                    key.push_str(&group);
+                    key.push(sep);
+                    key.push_str(&name);
+                    key
+                };
+                entries.push((key, group.clone(), name, value));
+            }
+        }
+        entries.sort_unstable_by(|a, b| (&a.0, &a.1, &a.2).cmp(&(&b.0, &b.1, &b.2)));
+
+        if let Some(pair) = entries.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(GroupCollision {
+                key: pair[0].0.clone(),
+                entries: [
+                    (pair[0].1.clone(), pair[0].2.clone()),
+                    (pair[1].1.clone(), pair[1].2.clone()),
+                ],
+            });
+        }
+        let mut map = HashMap::with_capacity_and_hasher(entries.len(), hasher);
+        map.extend(entries.into_iter().map(|(key, _, _, value)| (key, value)));
+        Ok(NamedMap::from(map))
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(entries: &[(&str, i32)]) -> NamedMap<i32> {
+        let mut map = NamedMap::new();
+        for (k, v) in entries {
+            map.insert(k.to_string(), *v);
+        }
+        map
+    }
+
+    #[test]
+    fn test_filter_prefix() {
+        let all = map(&[
+            ("db.primary", 1),
+            ("db.replica", 2),
+            ("dbx", 3),
+            ("cache.local", 4),
+        ]);
+        assert_eq!(
+            all.filter_prefix("db."),
+            map(&[("db.primary", 1), ("db.replica", 2)])
+        );
+        assert_eq!(all.filter_prefix(""), all);
+        assert!(all.filter_prefix("queue.").is_empty());
+    }
+
+    #[test]
+    fn test_group_splits_on_first_separator() {
+        let flat = map(&[
+            ("db.primary", 1),
+            ("db.pool.max", 2),
+            ("cache.local", 3),
+            ("http", 4),
+            ("db.", 5),
+        ]);
+        let groups = flat.clone().group_by_separator('.', "default");
+        assert_eq!(groups.len(), 3);
+        assert_eq!(
+            groups["db"],
+            map(&[("primary", 1), ("pool.max", 2), ("", 5)])
+        );
+        assert_eq!(groups["cache"], map(&[("local", 3)]));
+        assert_eq!(groups["default"], map(&[("http", 4)]));
+
+        assert_eq!(groups.flatten_groups('.', "default").unwrap(), flat);
+    }
+
+    #[test]
+    fn test_group_without_separators() {
+        let flat = map(&[("http", 1), ("https", 2)]);
+        let groups = flat.clone().group_by_separator('/', "");
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[""], flat);
+        assert_eq!(groups.flatten_groups('/', "").unwrap(), flat);
+
+        let groups = NamedMap::<i32>::new().group_by_separator('.', "default");
+        assert!(groups.is_empty());
+    }
+
+    #[test]
+    fn test_explicit_default_group_round_trips() {
+        let flat = map(&[("default.http", 1), ("db.primary", 2)]);
+        let groups = flat.clone().group_by_separator('.', "default");
+        assert_eq!(groups["default"], map(&[("default.http", 1)]));
+        assert_eq!(groups.flatten_groups('.', "default").unwrap(), flat);
+
+        let flat = map(&[("http", 1), ("default.http", 2), ("default.a.b", 3)]);
+        let groups = flat.clone().group_by_separator('.', "default");
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups.flatten_groups('.', "default").unwrap(), flat);
+
+        let flat = map(&[(".x", 1), ("x", 2), ("a.x", 3)]);
+        let groups = flat.clone().group_by_separator('.', "");
+        assert_eq!(groups[""], map(&[(".x", 1), ("x", 2)]));
+        assert_eq!(groups.flatten_groups('.', "").unwrap(), flat);
+    }
+
+    #[test]
+    fn test_flatten_collisions() {
+        let mut groups = NamedMap::new();
+        groups.insert("a".to_string(), map(&[("b.c", 1)]));
+        groups.insert("a.b".to_string(), map(&[("c", 2)]));
+        let err = groups.flatten_groups('.', "default").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\"b.c\" in group \"a\" and \"c\" in group \"a.b\" would both become \"a.b.c\""
+        );
+    }
+}
@@ -0,0 +1,373 @@
+// flexicon/src/adaptive/interned.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Maps keyed by shared `Arc<str>` names, for many maps with similar keys.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use super::NamedMap;
+
+/// A set of names shared between maps, so that each distinct name is
+/// allocated once.
+///
+/// Share it as an `Arc<Interner>` between every [`InternedNamedMap`] that
+/// should reuse names. Names are never evicted.
+#[derive(Debug, Default)]
+pub struct Interner {
+    names: Mutex<HashSet<Arc<str>>>,
+}
+
+impl Interner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared allocation for `name`, creating it on first use.
+    pub fn intern(&self, name: &str) -> Arc<str> {
+        let mut names = self.names.lock().unwrap_or_else(PoisonError::into_inner);
+        match names.get(name) {
+            Some(interned) => Arc::clone(interned),
+            None => {
+                let interned: Arc<str> = Arc::from(name);
+                names.insert(Arc::clone(&interned));
+                interned
+            }
+        }
+    }
+
+    /// Returns the number of distinct names interned so far.
+    pub fn len(&self) -> usize {
+        self.names
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns `true` if no name has been interned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A named map keyed by `Arc<str>`, whose keys can come from a shared
+/// [`Interner`] so that identical names across maps share one allocation.
+///
+/// Lookups take `&str`. Without an interner every key is its own
+/// allocation, as in [`NamedMap`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use flexicon::adaptive::{InternedNamedMap, Interner};
+///
+/// let interner = Arc::new(Interner::new());
+/// let mut a = InternedNamedMap::with_interner(Arc::clone(&interner));
+/// let mut b = InternedNamedMap::with_interner(Arc::clone(&interner));
+/// a.insert("http", 80);
+/// b.insert("http", 8080);
+///
+/// let key_a = a.get_key_value("http").unwrap().0;
+/// let key_b = b.get_key_value("http").unwrap().0;
+/// assert!(Arc::ptr_eq(key_a, key_b));
+/// assert_eq!(b.get("http"), Some(&8080));
+/// ```
+#[derive(Debug, Clone)]
+pub struct InternedNamedMap<T> {
+    map: HashMap<Arc<str>, T>,
+    interner: Option<Arc<Interner>>,
+}
+
+impl<T> InternedNamedMap<T> {
+    /// Creates an empty map without an interner.
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            interner: None,
+        }
+    }
+
+    /// Creates an empty map whose keys come from `interner`.
+    pub fn with_interner(interner: Arc<Interner>) -> Self {
+        Self {
+            map: HashMap::new(),
+            interner: Some(interner),
+        }
+    }
+
+    /// Converts `map`, taking its keys from `interner`.
+    pub fn from_named_map(map: NamedMap<T>, interner: Arc<Interner>) -> Self {
+        let mut interned = Self::with_interner(interner);
+        interned.map.reserve(map.len());
+        for (name, value) in map {
+            interned.insert(&name, value);
+        }
+        interned
+    }
+
+    /// Returns the interner, if any.
+    pub fn interner(&self) -> Option<&Arc<Interner>> {
+        self.interner.as_ref()
+    }
+
+    fn key(&self, name: &str) -> Arc<str> {
+        match &self.interner {
+            Some(interner) => interner.intern(name),
+            None => Arc::from(name),
+        }
+    }
+
+    /// Inserts a value under `name`, returning the value it replaced, if any.
+    pub fn insert(&mut self, name: &str, value: T) -> Option<T> {
+        if let Some(slot) = self.map.get_mut(name) {
+            return Some(std::mem::replace(slot, value));
+        }
+        let key = self.key(name);
+        self.map.insert(key, value)
+    }
+
+    /// Returns the value of `name`.
+    pub fn get(&self, name: &str) -> Option<&T> {
+        self.map.get(name)
+    }
+
+    /// Returns the stored key and the value of `name`.
+    pub fn get_key_value(&self, name: &str) -> Option<(&Arc<str>, &T)> {
+        self.map.get_key_value(name)
+    }
+
+    /// Returns a mutable reference to the value of `name`.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut T> {
+        self.map.get_mut(name)
+    }
+
+    /// Returns `true` if the map contains `name`.
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.map.contains_key(name)
+    }
+
+    /// Removes the entry for `name`, returning its value.
+    pub fn remove(&mut self, name: &str) -> Option<T> {
+        self.map.remove(name)
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Iterates over the entries in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Arc<str>, &T)> {
+        self.map.iter()
+    }
+
+    /// Iterates over the keys in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = &Arc<str>> {
+        self.map.keys()
+    }
+
+    /// Iterates over the values in arbitrary order.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.map.values()
+    }
+}
+
+impl<T> Default for InternedNamedMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for InternedNamedMap<T> {
+    /// Compares entries only; the interners are ignored.
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Eq> Eq for InternedNamedMap<T> {}
+
+impl<T> core::ops::Index<&str> for InternedNamedMap<T> {
+    type Output = T;
+
+    fn index(&self, name: &str) -> &T {
+        self.get(name).expect("no entry found for name")
+    }
+}
+
+impl<T> From<NamedMap<T>> for InternedNamedMap<T> {
+    /// Converts without an interner.
+    fn from(map: NamedMap<T>) -> Self {
+        let mut interned = Self::new();
+        interned.map.reserve(map.len());
+        for (name, value) in map {
+            interned.map.insert(Arc::from(name), value);
+        }
+        interned
+    }
+}
+
+impl<T> From<InternedNamedMap<T>> for NamedMap<T> {
+    fn from(map: InternedNamedMap<T>) -> Self {
+        map.map
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect()
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::adaptive::FromName;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl<T: Serialize> Serialize for InternedNamedMap<T> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_map(self.map.iter().map(|(name, value)| (&**name, value)))
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for InternedNamedMap<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        /// Deserializes from the same forms as [`NamedMap`], without an
+        /// interner.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            NamedMap::<T>::deserialize(deserializer).map(Self::from)
+        }
+    }
+
+    impl<'de, T> InternedNamedMap<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        /// Deserializes from the same forms as [`NamedMap`], taking the
+        /// keys from `interner`.
+        pub fn deserialize_with<D>(
+            deserializer: D,
+            interner: Arc<Interner>,
+        ) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            NamedMap::<T>::deserialize(deserializer).map(|map| Self::from_named_map(map, interner))
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ports(entries: &[(&str, u16)]) -> NamedMap<u16> {
+        entries
+            .iter()
+            .map(|(name, port)| (name.to_string(), *port))
+            .collect()
+    }
+
+    #[test]
+    fn test_interner_shares_allocations() {
+        let interner = Interner::new();
+        assert!(interner.is_empty());
+        let a = interner.intern("http");
+        let b = interner.intern(&String::from("http"));
+        assert!(Arc::ptr_eq(&a, &b));
+        interner.intern("ssh");
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn test_map_operations_take_str() {
+        let interner = Arc::new(Interner::new());
+        let mut map = InternedNamedMap::with_interner(Arc::clone(&interner));
+        assert_eq!(map.insert("http", 80), None);
+        assert_eq!(map.insert("http", 8080), Some(80));
+        map.insert("ssh", 22);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(map["http"], 8080);
+        assert!(map.contains_key("ssh"));
+        *map.get_mut("ssh").unwrap() = 2222;
+        assert_eq!(map.remove("ssh"), Some(2222));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_named_map_conversions() {
+        let plain = ports(&[("http", 80), ("ssh", 22)]);
+        let interned = InternedNamedMap::from(plain.clone());
+        assert!(interned.interner().is_none());
+        assert_eq!(interned.get("ssh"), Some(&22));
+        assert_eq!(NamedMap::from(interned.clone()), plain);
+
+        let interner = Arc::new(Interner::new());
+        let shared = InternedNamedMap::from_named_map(plain.clone(), interner);
+        assert_eq!(shared, interned);
+        assert_eq!(NamedMap::from(shared), plain);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Port(u16);
+
+    #[cfg(feature = "serde_json")]
+    impl crate::adaptive::FromName for Port {
+        fn from_name(_name: &str) -> Self {
+            Port(0)
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_deserialized_maps_share_keys() {
+        let interner = Arc::new(Interner::new());
+        let mut deserializer = serde_json::Deserializer::from_str(r#"{"http": 80, "ssh": 22}"#);
+        let a =
+            InternedNamedMap::<Port>::deserialize_with(&mut deserializer, Arc::clone(&interner))
+                .unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(r#"["http", "dns"]"#);
+        let b =
+            InternedNamedMap::<Port>::deserialize_with(&mut deserializer, Arc::clone(&interner))
+                .unwrap();
+
+        let (key_a, _) = a.get_key_value("http").unwrap();
+        let (key_b, _) = b.get_key_value("http").unwrap();
+        assert!(Arc::ptr_eq(key_a, key_b));
+        assert_eq!(interner.len(), 3);
+        assert_eq!(b["http"], Port(0));
+
+        let plain: InternedNamedMap<Port> = serde_json::from_str(r#"{"http": 80}"#).unwrap();
+        let (key_plain, _) = plain.get_key_value("http").unwrap();
+        assert!(!Arc::ptr_eq(key_a, key_plain));
+
+        assert_eq!(
+            serde_json::to_value(&a).unwrap(),
+            serde_json::json!({"http": 80, "ssh": 22})
+        );
+    }
+}
@@ -0,0 +1,435 @@
+// flexicon/src/adaptive/load.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Loading and saving `NamedMap`s from files, picking the format from the
+//! file extension.
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::{FromName, NamedMap};
+
+/// A file format understood by [`NamedMap::from_path`].
+///
+/// Every variant always exists, but each one only works when the matching
+/// crate feature (`serde_json`, `toml`, `yaml`, `json5`) is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// `.json`
+    Json,
+    /// `.toml`
+    Toml,
+    /// `.yaml` / `.yml`
+    Yaml,
+    /// `.json5`; saved as plain (pretty) JSON, which is valid JSON5.
+    Json5,
+}
+
+impl Format {
+    /// The format for a file extension (without the dot, case-insensitive).
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_ascii_lowercase().as_str() {
+            "json" => Some(Format::Json),
+            "toml" => Some(Format::Toml),
+            "yaml" | "yml" => Some(Format::Yaml),
+            "json5" => Some(Format::Json5),
+            _ => None,
+        }
+    }
+
+    /// The format implied by `path`'s extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Self::from_extension)
+    }
+
+    /// The crate feature this format needs.
+    fn feature(self) -> &'static str {
+        match self {
+            Format::Json => "serde_json",
+            Format::Toml => "toml",
+            Format::Yaml => "yaml",
+            Format::Json5 => "json5",
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Format::Json => "JSON",
+            Format::Toml => "TOML",
+            Format::Yaml => "YAML",
+            Format::Json5 => "JSON5",
+        })
+    }
+}
+
+/// An error loading or saving a `NamedMap` file; always names the file.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read or written.
+    Io {
+        /// The file involved.
+        path: PathBuf,
+        /// The underlying I/O error.
+        source: io::Error,
+    },
+    /// The extension is missing or not recognized; use
+    /// [`NamedMap::from_path_with_format`] for such files.
+    UnknownFormat {
+        /// The file involved.
+        path: PathBuf,
+    },
+    /// The format is known, but its crate feature is not enabled.
+    FormatDisabled {
+        /// The file involved.
+        path: PathBuf,
+        /// The requested format.
+        format: Format,
+    },
+    /// The contents did not parse, or the map could not be serialized.
+    Format {
+        /// The file involved.
+        path: PathBuf,
+        /// The format in use.
+        format: Format,
+        /// The parser's or serializer's error.
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl LoadError {
+    /// The file the error is about.
+    pub fn path(&self) -> &Path {
+        match self {
+            LoadError::Io { path, .. }
+            | LoadError::UnknownFormat { path }
+            | LoadError::FormatDisabled { path, .. }
+            | LoadError::Format { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            LoadError::UnknownFormat { path } => write!(
+                f,
+                "{}: unknown file format (expected .toml, .json, .yaml, .yml or .json5)",
+                path.display()
+            ),
+            LoadError::FormatDisabled { path, format } => write!(
+                f,
+                "{}: {} support requires the `{}` feature",
+                path.display(),
+                format,
+                format.feature()
+            ),
+            LoadError::Format {
+                path,
+                format,
+                source,
+            } => write!(f, "{}: invalid {}: {}", path.display(), format, source),
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadError::Io { source, .. } => Some(source),
+            LoadError::Format { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Picks the format from the extension of `path`.
+pub(crate) fn detect(path: &Path) -> Result<Format, LoadError> {
+    Format::from_path(path).ok_or_else(|| LoadError::UnknownFormat {
+        path: path.to_path_buf(),
+    })
+}
+
+/// Parses `text` as `format`; `path` is only used for error reporting.
+pub(crate) fn parse<T>(text: &str, format: Format, path: &Path) -> Result<NamedMap<T>, LoadError>
+where
+    T: DeserializeOwned + FromName + Clone,
+{
+    let result: Option<Result<NamedMap<T>, Box<dyn Error + Send + Sync>>> = match format {
+        #[cfg(feature = "serde_json")]
+        Format::Json => Some(NamedMap::from_json_str(text).map_err(Into::into)),
+        #[cfg(feature = "toml")]
+        Format::Toml => Some(NamedMap::from_toml_str(text).map_err(Into::into)),
+        #[cfg(feature = "yaml")]
+        Format::Yaml => Some(NamedMap::from_yaml_str(text).map_err(Into::into)),
+        #[cfg(feature = "json5")]
+        Format::Json5 => Some(NamedMap::from_json5_str(text).map_err(Into::into)),
+        #[allow(unreachable_patterns)]
+        _ => {
+            let _ = text;
+            None
+        }
+    };
+    match result {
+        Some(result) => result.map_err(|source| LoadError::Format {
+            path: path.to_path_buf(),
+            format,
+            source,
+        }),
+        None => Err(LoadError::FormatDisabled {
+            path: path.to_path_buf(),
+            format,
+        }),
+    }
+}
+
+/// Renders `map` as `format`; `path` is only used for error reporting.
+pub(crate) fn render<T>(map: &NamedMap<T>, format: Format, path: &Path) -> Result<String, LoadError>
+where
+    T: Serialize,
+{
+    let result: Option<Result<String, Box<dyn Error + Send + Sync>>> = match format {
+        #[cfg(feature = "serde_json")]
+        Format::Json | Format::Json5 => Some(map.to_json_string_pretty().map_err(Into::into)),
+        #[cfg(feature = "toml")]
+        Format::Toml => Some(map.to_toml_string().map_err(Into::into)),
+        #[cfg(feature = "yaml")]
+        Format::Yaml => Some(map.to_yaml_string().map_err(Into::into)),
+        #[allow(unreachable_patterns)]
+        _ => {
+            let _ = map;
+            None
+        }
+    };
+    match result {
+        Some(result) => result.map_err(|source| LoadError::Format {
+            path: path.to_path_buf(),
+            format,
+            source,
+        }),
+        None => Err(LoadError::FormatDisabled {
+            path: path.to_path_buf(),
+            format,
+        }),
+    }
+}
+
+/// Reports a finished load, with the time spent reading and parsing.
+#[cfg(feature = "tracing")]
+pub(crate) fn trace_load(path: &Path, format: Format, started: std::time::Instant, ok: bool) {
+    tracing::trace!(
+        path = %path.display(),
+        format = %format,
+        duration = ?started.elapsed(),
+        ok,
+        "named_map.load"
+    );
+}
+
+pub(crate) fn io_error(path: &Path, source: io::Error) -> LoadError {
+    LoadError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+impl<T> NamedMap<T>
+where
+    T: DeserializeOwned + FromName + Clone,
+{
+    /// Loads a map from a file, choosing the parser from its extension
+    /// (`.toml`, `.json`, `.yaml`/`.yml`, `.json5`).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[derive(Clone, serde::Deserialize)]
+    /// # struct Plugin;
+    /// # impl flexicon::adaptive::FromName for Plugin {
+    /// #     fn from_name(_name: &str) -> Self { Plugin }
+    /// # }
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let plugins = NamedMap::<Plugin>::from_path("plugins.toml")?;
+    /// # Ok::<(), flexicon::adaptive::LoadError>(())
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        Self::from_path_with_format(path, detect(path)?)
+    }
+
+    /// Like [`from_path`](Self::from_path), ignoring the extension.
+    pub fn from_path_with_format(
+        path: impl AsRef<Path>,
+        format: Format,
+    ) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+        let text = std::fs::read_to_string(path).map_err(|err| io_error(path, err))?;
+        let result = parse(&text, format, path);
+        #[cfg(feature = "tracing")]
+        trace_load(path, format, started, result.is_ok());
+        result
+    }
+}
+
+impl<T> NamedMap<T>
+where
+    T: Serialize,
+{
+    /// Writes the map to a file in the format implied by its extension.
+    ///
+    /// `.json5` files are written as plain JSON.
+    pub fn save_to_path(&self, path: impl AsRef<Path>) -> Result<(), LoadError> {
+        let path = path.as_ref();
+        self.save_to_path_with_format(path, detect(path)?)
+    }
+
+    /// Like [`save_to_path`](Self::save_to_path), ignoring the extension.
+    pub fn save_to_path_with_format(
+        &self,
+        path: impl AsRef<Path>,
+        format: Format,
+    ) -> Result<(), LoadError> {
+        let path = path.as_ref();
+        let text = render(self, format, path)?;
+        std::fs::write(path, text).map_err(|err| io_error(path, err))
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Plugin {
+        enabled: bool,
+    }
+
+    impl FromName for Plugin {
+        fn from_name(_name: &str) -> Self {
+            Plugin { enabled: true }
+        }
+    }
+
+    /// A fresh, empty directory under the system temp dir.
+    fn temp_dir(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("flexicon-{}-{}", test, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn sample() -> NamedMap<Plugin> {
+        let mut map = NamedMap::from(vec!["logger".to_string()]);
+        map.insert("http".to_string(), Plugin { enabled: false });
+        map
+    }
+
+    #[test]
+    fn test_format_detection() {
+        assert_eq!(Format::from_path(Path::new("a/b.TOML")), Some(Format::Toml));
+        assert_eq!(Format::from_path(Path::new("b.yml")), Some(Format::Yaml));
+        assert_eq!(Format::from_path(Path::new("b")), None);
+    }
+
+    #[test]
+    fn test_unknown_extension() {
+        let dir = temp_dir("unknown");
+        let path = dir.join("plugins.ini");
+        std::fs::write(&path, "").unwrap();
+
+        let err = NamedMap::<Plugin>::from_path(&path).unwrap_err();
+        assert!(matches!(err, LoadError::UnknownFormat { .. }), "{}", err);
+        assert_eq!(err.path(), path);
+        assert!(sample().save_to_path(&path).is_err());
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let path = temp_dir("missing").join("absent.json");
+        let err = NamedMap::<Plugin>::from_path(&path).unwrap_err();
+        assert!(matches!(err, LoadError::Io { .. }), "{}", err);
+        assert!(err.to_string().contains("absent.json"), "{}", err);
+    }
+
+    #[cfg(all(feature = "serde_json", feature = "toml", feature = "yaml"))]
+    #[test]
+    fn test_roundtrip_each_format() {
+        let dir = temp_dir("roundtrip");
+        for name in [
+            "plugins.json",
+            "plugins.toml",
+            "plugins.yaml",
+            "plugins.yml",
+        ] {
+            let path = dir.join(name);
+            sample().save_to_path(&path).unwrap();
+            assert_eq!(NamedMap::from_path(&path).unwrap(), sample(), "{}", name);
+        }
+
+        let path = dir.join("plugins");
+        sample()
+            .save_to_path_with_format(&path, Format::Toml)
+            .unwrap();
+        assert_eq!(
+            NamedMap::from_path_with_format(&path, Format::Toml).unwrap(),
+            sample()
+        );
+    }
+
+    #[cfg(all(feature = "serde_json", feature = "yaml"))]
+    #[test]
+    fn test_simple_form_and_parse_errors() {
+        let dir = temp_dir("simple");
+        let path = dir.join("plugins.yaml");
+        std::fs::write(&path, "- logger\n- http\n").unwrap();
+        assert_eq!(NamedMap::<Plugin>::from_path(&path).unwrap().len(), 2);
+
+        let path = dir.join("plugins.json");
+        std::fs::write(&path, r#"{"http": {"enabled": 1}}"#).unwrap();
+        let err = NamedMap::<Plugin>::from_path(&path).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                LoadError::Format {
+                    format: Format::Json,
+                    ..
+                }
+            ),
+            "{}",
+            err
+        );
+        assert!(
+            err.to_string().contains("error in entry \"http\""),
+            "{}",
+            err
+        );
+    }
+
+    #[cfg(feature = "json5")]
+    #[test]
+    fn test_json5() {
+        let dir = temp_dir("json5");
+        let path = dir.join("plugins.json5");
+        std::fs::write(&path, "// comment\n['logger', 'http',]").unwrap();
+        assert_eq!(NamedMap::<Plugin>::from_path(&path).unwrap().len(), 2);
+    }
+}
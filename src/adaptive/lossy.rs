@@ -0,0 +1,305 @@
+// flexicon/src/adaptive/lossy.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Best-effort loading that skips broken entries instead of failing.
+
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::Deserialize;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::hash::BuildHasher;
+
+use super::{DuplicatePolicy, EmptyNameError, NamePolicy, NamedMap, TryFromName};
+
+/// An entry skipped by [`NamedMap::from_json_value_lossy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryError {
+    /// The entry's name, or `[i]` for an array item that has no usable name.
+    /// Empty when the input as a whole was not a map, array or string.
+    pub key: String,
+    /// Why the entry was skipped.
+    pub message: String,
+}
+
+impl fmt::Display for EntryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error in entry {:?}: {}", self.key, self.message)
+    }
+}
+
+impl Error for EntryError {}
+
+/// Accumulates good entries and per-entry failures.
+struct Lossy<T, S> {
+    map: HashMap<String, T, S>,
+    errors: Vec<EntryError>,
+}
+
+impl<T, S> Lossy<T, S>
+where
+    T: DeserializeOwned + TryFromName,
+    S: BuildHasher,
+{
+    fn error(&mut self, key: String, message: impl fmt::Display) {
+        self.errors.push(EntryError {
+            key,
+            message: message.to_string(),
+        });
+    }
+
+    fn insert(&mut self, name: String, value: T, index: Option<usize>) {
+        match index {
+            Some(index) => {
+                if let Err(err) = DuplicatePolicy::Error.insert(&mut self.map, name, value, index) {
+                    self.error(err.name.clone(), err);
+                }
+            }
+            None => {
+                self.map.insert(name, value);
+            }
+        }
+    }
+
+    /// Applies [`NamePolicy::RejectEmpty`], recording a rejected name;
+    /// `index` is its position in the array form.
+    fn check(&mut self, name: String, index: Option<usize>) -> Option<String> {
+        match NamePolicy::RejectEmpty.check(Cow::Owned(name)) {
+            Ok(name) => name.map(Cow::into_owned),
+            Err(name) => {
+                match index {
+                    Some(index) => self.error(format!("[{}]", index), EmptyNameError { index }),
+                    None => self.error(name.into_owned(), "empty name"),
+                }
+                None
+            }
+        }
+    }
+
+    fn name(&mut self, name: String, index: Option<usize>) {
+        let Some(name) = self.check(name, index) else {
+            return;
+        };
+        match T::try_from_name(&name) {
+            Ok(value) => self.insert(name, value, index),
+            Err(err) => self.error(name, format_args!("invalid name: {}", err)),
+        }
+    }
+
+    fn value(&mut self, name: String, value: Value, index: Option<usize>) {
+        let Some(name) = self.check(name, index) else {
+            return;
+        };
+        match T::deserialize(value) {
+            Ok(value) => self.insert(name, value, index),
+            Err(err) => self.error(name, format_args!("invalid value: {}", err)),
+        }
+    }
+}
+
+impl<T, S> NamedMap<T, S>
+where
+    T: DeserializeOwned + TryFromName,
+    S: BuildHasher + Default,
+{
+    /// Parses a `NamedMap` from a `serde_json::Value`, skipping entries that
+    /// fail instead of rejecting the whole map.
+    ///
+    /// Accepts the same forms as the `Deserialize` impl. Every skipped entry
+    /// is reported as an [`EntryError`]: values that fail to deserialize,
+    /// empty and whitespace-only names and keys, names rejected by
+    /// [`TryFromName`], array items that are neither a name nor a single-key
+    /// map, and names repeated in the array form (the first occurrence is
+    /// kept).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use serde::Deserialize;
+    /// use flexicon::adaptive::{FromName, NamedMap};
+    ///
+    /// #[derive(Clone, Deserialize)]
+    /// struct Plugin { enabled: bool }
+    ///
+    /// impl FromName for Plugin {
+    ///     fn from_name(_name: &str) -> Self { Plugin { enabled: true } }
+    /// }
+    ///
+    /// let value = serde_json::json!({ "http": { "enabled": false }, "broken": 42 });
+    /// let (map, errors) = NamedMap::<Plugin>::from_json_value_lossy(value);
+    /// assert_eq!(map.len(), 1);
+    /// assert_eq!(errors[0].key, "broken");
+    /// ```
+    pub fn from_json_value_lossy(value: Value) -> (Self, Vec<EntryError>) {
+        let mut lossy = Lossy {
+            map: HashMap::default(),
+            errors: Vec::new(),
+        };
+        match value {
+            Value::Object(entries) => {
+                lossy.map.reserve(entries.len());
+                for (name, value) in entries {
+                    lossy.value(name, value, None);
+                }
+            }
+            Value::Array(items) => {
+                for (index, item) in items.into_iter().enumerate() {
+                    match item {
+                        Value::String(name) => lossy.name(name, Some(index)),
+                        Value::Object(entry) if entry.len() == 1 => {
+                            let (name, value) = entry.into_iter().next().expect("one entry");
+                            lossy.value(name, value, Some(index));
+                        }
+                        other => lossy.error(
+                            format!("[{}]", index),
+                            format_args!("expected a name or a single-key map, found {}", other),
+                        ),
+                    }
+                }
+            }
+            Value::String(name) => lossy.name(name, None),
+            other => lossy.error(
+                String::new(),
+                format_args!("expected a map, a sequence or a name, found {}", other),
+            ),
+        }
+        (NamedMap::from(lossy.map), lossy.errors)
+    }
+
+    /// Like [`from_json_value_lossy`](Self::from_json_value_lossy), for any
+    /// self-describing format: the input is buffered as a `serde_json::Value`
+    /// first. Only input that cannot be read at all is a hard error.
+    pub fn deserialize_lossy<'de, D>(deserializer: D) -> Result<(Self, Vec<EntryError>), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Value::deserialize(deserializer).map(Self::from_json_value_lossy)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adaptive::FromName;
+    use serde_json::json;
+
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    struct Plugin {
+        enabled: bool,
+    }
+
+    impl FromName for Plugin {
+        fn from_name(_name: &str) -> Self {
+            Plugin { enabled: true }
+        }
+    }
+
+    fn keys(errors: &[EntryError]) -> Vec<&str> {
+        let mut keys: Vec<&str> = errors.iter().map(|e| e.key.as_str()).collect();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn test_object_first_middle_last_malformed() {
+        let (map, errors) = NamedMap::<Plugin>::from_json_value_lossy(json!({
+            "a": 1,
+            "b": { "enabled": true },
+            "c": { "enabled": "yes" },
+            "d": { "enabled": false },
+            "e": null,
+        }));
+        assert_eq!(map.len(), 2);
+        assert!(!map["d"].enabled);
+        assert_eq!(keys(&errors), ["a", "c", "e"]);
+        assert!(
+            errors[0].message.starts_with("invalid value"),
+            "{}",
+            errors[0]
+        );
+    }
+
+    #[test]
+    fn test_array_first_middle_last_malformed() {
+        let (map, errors) = NamedMap::<Plugin>::from_json_value_lossy(json!([
+            { "a": 1 },
+            "b",
+            42,
+            { "d": { "enabled": false } },
+            "b",
+        ]));
+        let mut names: Vec<&String> = map.keys().collect();
+        names.sort();
+        assert_eq!(names, ["b", "d"]);
+        assert_eq!(keys(&errors), ["[2]", "a", "b"]);
+        assert!(errors[2].message.contains("at index 4"), "{}", errors[2]);
+    }
+
+    #[test]
+    fn test_empty_names_are_collected() {
+        let (map, errors) = NamedMap::<Plugin>::from_json_value_lossy(json!({
+            "": { "enabled": false },
+            "b": { "enabled": false },
+        }));
+        assert_eq!(map.len(), 1);
+        assert_eq!(errors[0].to_string(), "error in entry \"\": empty name");
+
+        let (map, errors) = NamedMap::<Plugin>::from_json_value_lossy(
+            json!(["a", " ", { "": { "enabled": false } }]),
+        );
+        assert_eq!(map.len(), 1);
+        assert_eq!(keys(&errors), ["[1]", "[2]"]);
+        assert_eq!(errors[0].message, "empty name at index 1");
+
+        let (map, errors) = NamedMap::<Plugin>::from_json_value_lossy(json!("  "));
+        assert!(map.is_empty());
+        assert_eq!(errors[0].to_string(), "error in entry \"  \": empty name");
+    }
+
+    #[test]
+    fn test_invalid_names_are_collected() {
+        #[derive(Debug, Deserialize)]
+        struct Port(#[allow(dead_code)] u16);
+
+        impl TryFromName for Port {
+            type Error = std::num::ParseIntError;
+
+            fn try_from_name(name: &str) -> Result<Self, Self::Error> {
+                name.parse().map(Port)
+            }
+        }
+
+        let (map, errors) = NamedMap::<Port>::from_json_value_lossy(json!(["http", "80", "x"]));
+        assert_eq!(map.len(), 1);
+        assert_eq!(keys(&errors), ["http", "x"]);
+        assert!(
+            errors[0].message.starts_with("invalid name"),
+            "{}",
+            errors[0]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_lossy_toml() {
+        let value: toml::Value =
+            toml::from_str(r#"plugins = { a = { enabled = 1 }, b = { enabled = true } }"#).unwrap();
+        let (map, errors) =
+            NamedMap::<Plugin>::deserialize_lossy(value["plugins"].clone()).unwrap();
+        assert_eq!(map.len(), 1);
+        assert_eq!(keys(&errors), ["a"]);
+
+        let (map, errors) = NamedMap::<Plugin>::from_json_value_lossy(json!(5));
+        assert!(map.is_empty());
+        assert_eq!(keys(&errors), [""]);
+    }
+}
@@ -0,0 +1,25 @@
+// flexicon/src/adaptive/map.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The hash map behind `NamedMap`: `std`'s, or `hashbrown`'s (with its
+//! default hasher) when built without `std`.
+
+#[cfg(feature = "std")]
+pub(crate) use std::collections::hash_map::{
+    Entry, HashMap, IntoIter, Iter, IterMut, RandomState as DefaultHasher,
+};
+
+#[cfg(feature = "std")]
+pub(crate) use std::collections::HashSet;
+
+#[cfg(not(feature = "std"))]
+pub(crate) use hashbrown::{
+    hash_map::{Entry, HashMap, IntoIter, Iter, IterMut},
+    DefaultHashBuilder as DefaultHasher, HashSet,
+};
@@ -0,0 +1,219 @@
+// flexicon/src/adaptive/merge.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::BuildHasher;
+
+use super::map::Entry;
+use super::NamedMap;
+
+/// How [`NamedMap::merge`] resolves a key present in both maps.
+pub enum MergeStrategy<T> {
+    /// Keep the value already in `self`; the incoming value is dropped.
+    KeepExisting,
+    /// Replace the existing value with the incoming one.
+    Overwrite,
+    /// Resolve the conflict with a custom function.
+    ///
+    /// Called as `f(key, existing, incoming)`; the returned value is stored.
+    WithFn(fn(&str, T, T) -> T),
+}
+
+impl<T> Clone for MergeStrategy<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MergeStrategy<T> {}
+
+impl<T> fmt::Debug for MergeStrategy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeStrategy::KeepExisting => f.write_str("KeepExisting"),
+            MergeStrategy::Overwrite => f.write_str("Overwrite"),
+            MergeStrategy::WithFn(_) => f.write_str("WithFn(..)"),
+        }
+    }
+}
+
+/// What [`NamedMap::merge`] did, key by key.
+///
+/// Each list is sorted, so the summary can be logged deterministically.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergeSummary {
+    /// Keys that were only present in the incoming map.
+    pub added: Vec<String>,
+    /// Conflicting keys whose value was replaced (by `Overwrite` or `WithFn`).
+    pub replaced: Vec<String>,
+    /// Conflicting keys whose existing value was kept (`KeepExisting`).
+    pub kept: Vec<String>,
+}
+
+impl MergeSummary {
+    /// Returns `true` if the merge did not touch any key.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.replaced.is_empty() && self.kept.is_empty()
+    }
+}
+
+impl<T, S: BuildHasher> NamedMap<T, S> {
+    /// Merges `other` into `self`, resolving shared keys with `strategy`.
+    ///
+    /// Keys only present in `other` are always added. Returns a summary of
+    /// added, replaced and kept keys.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::{MergeStrategy, NamedMap};
+    ///
+    /// let mut system = NamedMap::new();
+    /// system.insert("logger".to_string(), 1);
+    ///
+    /// let mut user = NamedMap::new();
+    /// user.insert("logger".to_string(), 2);
+    /// user.insert("http".to_string(), 3);
+    ///
+    /// let summary = system.merge(user, MergeStrategy::Overwrite);
+    /// assert_eq!(summary.added, ["http"]);
+    /// assert_eq!(summary.replaced, ["logger"]);
+    /// assert_eq!(system["logger"], 2);
+    /// ```
+    pub fn merge(&mut self, other: NamedMap<T, S>, strategy: MergeStrategy<T>) -> MergeSummary {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("named_map.merge", strategy = ?strategy).entered();
+        let mut summary = MergeSummary::default();
+        for (key, incoming) in other.into_inner() {
+            match self.as_inner_mut().entry(key) {
+                Entry::Vacant(entry) => {
+                    summary.added.push(entry.key().clone());
+                    entry.insert(incoming);
+                }
+                Entry::Occupied(mut entry) => match strategy {
+                    MergeStrategy::KeepExisting => {
+                        #[cfg(feature = "tracing")]
+                        tracing::trace!(key = entry.key().as_str(), "kept existing value");
+                        summary.kept.push(entry.key().clone());
+                    }
+                    MergeStrategy::Overwrite => {
+                        #[cfg(feature = "tracing")]
+                        tracing::trace!(key = entry.key().as_str(), "overwritten");
+                        entry.insert(incoming);
+                        summary.replaced.push(entry.key().clone());
+                    }
+                    MergeStrategy::WithFn(resolve) => {
+                        let (key, existing) = entry.remove_entry();
+                        #[cfg(feature = "tracing")]
+                        tracing::trace!(key = key.as_str(), "overwritten");
+                        let merged = resolve(&key, existing, incoming);
+                        summary.replaced.push(key.clone());
+                        self.as_inner_mut().insert(key, merged);
+                    }
+                },
+            }
+        }
+        summary.added.sort();
+        summary.replaced.sort();
+        summary.kept.sort();
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            added = summary.added.len(),
+            replaced = summary.replaced.len(),
+            kept = summary.kept.len(),
+            "merged"
+        );
+        summary
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(entries: &[(&str, i32)]) -> NamedMap<i32> {
+        let mut map = NamedMap::new();
+        for (k, v) in entries {
+            map.insert(k.to_string(), *v);
+        }
+        map
+    }
+
+    fn sum(_key: &str, a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    #[test]
+    fn test_merge_empty_maps() {
+        for strategy in [
+            MergeStrategy::KeepExisting,
+            MergeStrategy::Overwrite,
+            MergeStrategy::WithFn(sum),
+        ] {
+            let mut base = map(&[]);
+            assert!(base.merge(map(&[]), strategy).is_empty());
+            assert!(base.is_empty());
+
+            let mut base = map(&[]);
+            let summary = base.merge(map(&[("a", 1)]), strategy);
+            assert_eq!(summary.added, ["a"]);
+            assert_eq!(base, map(&[("a", 1)]));
+
+            let mut base = map(&[("a", 1)]);
+            assert!(base.merge(map(&[]), strategy).is_empty());
+            assert_eq!(base, map(&[("a", 1)]));
+        }
+    }
+
+    #[test]
+    fn test_merge_disjoint_keys() {
+        for strategy in [
+            MergeStrategy::KeepExisting,
+            MergeStrategy::Overwrite,
+            MergeStrategy::WithFn(sum),
+        ] {
+            let mut base = map(&[("a", 1)]);
+            let summary = base.merge(map(&[("c", 3), ("b", 2)]), strategy);
+            assert_eq!(summary.added, ["b", "c"]);
+            assert!(summary.replaced.is_empty());
+            assert!(summary.kept.is_empty());
+            assert_eq!(base, map(&[("a", 1), ("b", 2), ("c", 3)]));
+        }
+    }
+
+    #[test]
+    fn test_merge_overlap_keep_existing() {
+        let mut base = map(&[("a", 1), ("b", 2)]);
+        let summary = base.merge(map(&[("a", 10), ("b", 20)]), MergeStrategy::KeepExisting);
+        assert_eq!(summary.kept, ["a", "b"]);
+        assert!(summary.added.is_empty() && summary.replaced.is_empty());
+        assert_eq!(base, map(&[("a", 1), ("b", 2)]));
+    }
+
+    #[test]
+    fn test_merge_overlap_overwrite() {
+        let mut base = map(&[("a", 1), ("b", 2)]);
+        let summary = base.merge(map(&[("a", 10), ("b", 20)]), MergeStrategy::Overwrite);
+        assert_eq!(summary.replaced, ["a", "b"]);
+        assert!(summary.added.is_empty() && summary.kept.is_empty());
+        assert_eq!(base, map(&[("a", 10), ("b", 20)]));
+    }
+
+    #[test]
+    fn test_merge_overlap_with_fn() {
+        let mut base = map(&[("a", 1), ("b", 2)]);
+        let summary = base.merge(map(&[("a", 10), ("b", 20)]), MergeStrategy::WithFn(sum));
+        assert_eq!(summary.replaced, ["a", "b"]);
+        assert_eq!(base, map(&[("a", 11), ("b", 22)]));
+    }
+}
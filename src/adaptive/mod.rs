@@ -7,5 +7,217 @@
 // This file may not be copied, modified, or distributed
 // except according to those terms.
 
+mod map;
+
 mod namedmap;
 pub use namedmap::*;
+
+/// Derive macro for [`FromName`]; see the `flexicon-derive` crate for the
+/// supported attributes.
+#[cfg(feature = "derive")]
+pub use flexicon_derive::FromName;
+
+mod trynamedmap;
+pub use trynamedmap::*;
+
+mod duplicates;
+pub use duplicates::*;
+
+mod namepolicy;
+pub use namepolicy::*;
+
+mod shorthand;
+pub use shorthand::*;
+
+mod oneormany;
+pub use oneormany::*;
+
+mod stringorstruct;
+pub use stringorstruct::*;
+
+mod toggle;
+pub use toggle::*;
+
+mod duration;
+pub use duration::*;
+
+mod bytesize;
+pub use bytesize::*;
+
+mod comma;
+
+#[cfg(feature = "serde")]
+pub mod named_map;
+
+#[cfg(feature = "schemars")]
+mod schema;
+
+#[cfg(feature = "utoipa")]
+mod openapi;
+
+#[cfg(feature = "serde_with")]
+mod as_named_map;
+#[cfg(feature = "serde_with")]
+pub use as_named_map::*;
+
+mod merge;
+pub use merge::*;
+
+mod setops;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+
+#[cfg(feature = "proptest")]
+mod strategy;
+#[cfg(feature = "proptest")]
+pub use strategy::*;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+mod group;
+pub use group::*;
+
+mod dependencies;
+pub use dependencies::*;
+
+mod priority;
+pub use priority::*;
+
+mod nameaware;
+pub use nameaware::*;
+
+mod redact;
+pub use redact::*;
+
+mod projection;
+pub use projection::*;
+
+#[cfg(feature = "std")]
+mod interned;
+#[cfg(feature = "std")]
+pub use interned::*;
+
+mod glob;
+pub use glob::*;
+
+mod diff;
+pub use diff::*;
+
+mod rename;
+pub use rename::*;
+
+#[cfg(feature = "indexmap")]
+mod orderednamedmap;
+#[cfg(feature = "indexmap")]
+pub use orderednamedmap::*;
+
+mod sortednamedmap;
+pub use sortednamedmap::*;
+
+mod namedmultimap;
+pub use namedmultimap::*;
+
+mod caseinsensitivenamedmap;
+pub use caseinsensitivenamedmap::*;
+
+mod validatednamedmap;
+pub use validatednamedmap::*;
+
+mod trackednamedmap;
+pub use trackednamedmap::*;
+
+mod cownamedmap;
+pub use cownamedmap::*;
+
+#[cfg(feature = "spans")]
+mod spannednamedmap;
+#[cfg(feature = "spans")]
+pub use spannednamedmap::*;
+
+#[cfg(feature = "concurrent")]
+mod concurrentnamedmap;
+#[cfg(feature = "concurrent")]
+pub use concurrentnamedmap::*;
+
+#[cfg(feature = "serde")]
+mod seq;
+
+#[cfg(feature = "serde_json")]
+mod defaults;
+
+#[cfg(feature = "serde_json")]
+mod lossy;
+#[cfg(feature = "serde_json")]
+pub use lossy::*;
+
+#[cfg(feature = "serde_json")]
+mod canonical;
+
+#[cfg(feature = "fs")]
+mod load;
+#[cfg(feature = "fs")]
+pub use load::*;
+
+#[cfg(feature = "tokio")]
+mod async_load;
+
+#[cfg(feature = "serde_json")]
+mod raw;
+#[cfg(feature = "serde_json")]
+pub use raw::*;
+
+#[cfg(feature = "serde_json")]
+mod env;
+#[cfg(feature = "serde_json")]
+pub use env::*;
+
+#[cfg(feature = "serde_json")]
+mod set;
+
+#[cfg(feature = "figment")]
+mod provider;
+#[cfg(feature = "figment")]
+pub use provider::*;
+
+#[cfg(feature = "config")]
+mod source;
+#[cfg(feature = "config")]
+pub use source::*;
+
+#[cfg(feature = "clap")]
+mod cli;
+#[cfg(feature = "clap")]
+pub use cli::*;
+
+#[cfg(feature = "serde_json")]
+mod patch;
+#[cfg(feature = "serde_json")]
+pub use patch::*;
+
+#[cfg(feature = "serde_json")]
+mod extends;
+#[cfg(feature = "serde_json")]
+pub use extends::*;
+
+#[cfg(feature = "serde_json")]
+mod namedarray;
+#[cfg(feature = "serde_json")]
+pub use namedarray::*;
+
+mod entry;
+pub use entry::*;
+
+#[cfg(feature = "ahash")]
+mod fast;
+#[cfg(feature = "ahash")]
+pub use fast::*;
+
+#[cfg(feature = "unicode")]
+mod normalizednamedmap;
+#[cfg(feature = "unicode")]
+pub use normalizednamedmap::*;
+
+#[cfg(feature = "regex")]
+mod regex;
@@ -0,0 +1,402 @@
+// flexicon/src/adaptive/nameaware.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Values that store their own name, kept in step with their keys.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+use super::map::{Entry, HashMap};
+use super::{DuplicateNamesError, NamedMap};
+
+/// A value that carries its own name, usually a `name: String` field.
+pub trait NameAware {
+    /// The name the value holds.
+    fn name(&self) -> &str;
+
+    /// Replaces the name the value holds.
+    fn set_name(&mut self, name: &str);
+}
+
+/// An entry whose value holds a name other than its key, as reported by
+/// [`NamedMap::check_names`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The entry's key.
+    pub key: String,
+    /// The name the value holds.
+    pub name: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} holds a value named {:?}", self.key, self.name)
+    }
+}
+
+impl<T: NameAware, S> NamedMap<T, S> {
+    /// Writes every key into its value, so that `value.name() == key`
+    /// throughout; values that already agree are left alone.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::{NameAware, NamedMap};
+    ///
+    /// struct Plugin {
+    ///     name: String,
+    /// }
+    ///
+    /// impl NameAware for Plugin {
+    ///     fn name(&self) -> &str {
+    ///         &self.name
+    ///     }
+    ///     fn set_name(&mut self, name: &str) {
+    ///         self.name = name.to_string();
+    ///     }
+    /// }
+    ///
+    /// let mut map = NamedMap::new();
+    /// map.insert("logger".to_string(), Plugin { name: "log".to_string() });
+    /// assert_eq!(map.check_names()[0].to_string(), "\"logger\" holds a value named \"log\"");
+    ///
+    /// map.sync_names();
+    /// assert_eq!(map["logger"].name, "logger");
+    /// assert!(map.check_names().is_empty());
+    /// ```
+    pub fn sync_names(&mut self) {
+        for (key, value) in self.as_inner_mut().iter_mut() {
+            if value.name() != key {
+                value.set_name(key);
+            }
+        }
+    }
+
+    /// Reports every entry whose value holds a name other than its key,
+    /// sorted by key, without changing anything.
+    pub fn check_names(&self) -> Vec<Mismatch> {
+        let mut mismatches: Vec<Mismatch> = self
+            .iter()
+            .filter(|(key, value)| value.name() != key.as_str())
+            .map(|(key, value)| Mismatch {
+                key: key.clone(),
+                name: value.name().to_string(),
+            })
+            .collect();
+        mismatches.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+        mismatches
+    }
+}
+
+impl<T: NameAware> NamedMap<T> {
+    /// Builds a map keyed by each value's own name, failing with every
+    /// repeated name instead of stopping at the first one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::{NameAware, NamedMap};
+    ///
+    /// #[derive(Debug)]
+    /// struct Plugin {
+    ///     name: String,
+    /// }
+    ///
+    /// impl NameAware for Plugin {
+    ///     fn name(&self) -> &str {
+    ///         &self.name
+    ///     }
+    ///     fn set_name(&mut self, name: &str) {
+    ///         self.name = name.to_string();
+    ///     }
+    /// }
+    ///
+    /// let plugins = ["http", "logger"].map(|name| Plugin { name: name.to_string() });
+    /// let map = NamedMap::from_values(plugins.into()).unwrap();
+    /// assert_eq!(map.names(), ["http", "logger"]);
+    ///
+    /// let names: Vec<String> = map.into_values_sorted().into_iter().map(|p| p.name).collect();
+    /// assert_eq!(names, ["http", "logger"]);
+    /// ```
+    pub fn from_values(values: Vec<T>) -> Result<Self, DuplicateNamesError> {
+        let mut map = HashMap::with_capacity(values.len());
+        let mut duplicates = Vec::new();
+        for value in values {
+            match map.entry(value.name().to_string()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+                Entry::Occupied(entry) => duplicates.push(entry.key().clone()),
+            }
+        }
+        if duplicates.is_empty() {
+            return Ok(NamedMap::from(map));
+        }
+        duplicates.sort_unstable();
+        duplicates.dedup();
+        Err(DuplicateNamesError { names: duplicates })
+    }
+
+    /// Like [`from_values`](Self::from_values), but a repeated name keeps
+    /// the last value.
+    pub fn from_values_overwriting(values: Vec<T>) -> Self {
+        values
+            .into_iter()
+            .map(|value| (value.name().to_string(), value))
+            .collect()
+    }
+}
+
+impl<T, S> NamedMap<T, S> {
+    /// Consumes the map into its values, in the lexicographic order of
+    /// their names; the inverse of [`from_values`](NamedMap::from_values).
+    pub fn into_values_sorted(self) -> Vec<T> {
+        let mut entries: Vec<(String, T)> = self.into_iter().collect();
+        entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        entries.into_iter().map(|(_, value)| value).collect()
+    }
+}
+
+/// A [`NamedMap`] whose values always hold their own key as their name.
+///
+/// Deserialization, [`insert`](Self::insert) and the conversion from a
+/// [`NamedMap`] all run [`NamedMap::sync_names`], so a detailed entry such
+/// as `{"logger": {"name": "log"}}` ends up named `logger`. The map
+/// dereferences to a read-only [`NamedMap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncedNamedMap<T> {
+    map: NamedMap<T>,
+}
+
+impl<T: NameAware> SyncedNamedMap<T> {
+    /// Creates an empty `SyncedNamedMap`.
+    pub fn new() -> Self {
+        Self {
+            map: NamedMap::new(),
+        }
+    }
+
+    /// Inserts a key-value pair, writing `key` into the value first, and
+    /// returns the value it replaced, if any.
+    pub fn insert(&mut self, key: String, mut value: T) -> Option<T> {
+        if value.name() != key {
+            value.set_name(&key);
+        }
+        self.map.insert(key, value)
+    }
+
+    /// Removes the entry for `name`, returning its value.
+    pub fn remove(&mut self, name: &str) -> Option<T> {
+        self.map.as_inner_mut().remove(name)
+    }
+
+    /// Returns the synchronized map.
+    pub fn into_inner(self) -> NamedMap<T> {
+        self.map
+    }
+}
+
+impl<T: NameAware> Default for SyncedNamedMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Read-only: mutable access could rename values behind their keys.
+impl<T> Deref for SyncedNamedMap<T> {
+    type Target = NamedMap<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<T: NameAware> From<NamedMap<T>> for SyncedNamedMap<T> {
+    /// Writes every key of `map` into its value.
+    fn from(mut map: NamedMap<T>) -> Self {
+        map.sync_names();
+        Self { map }
+    }
+}
+
+impl<T> From<SyncedNamedMap<T>> for NamedMap<T> {
+    fn from(map: SyncedNamedMap<T>) -> Self {
+        map.map
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::adaptive::FromName;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl<T: Serialize> Serialize for SyncedNamedMap<T> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.map.serialize(serializer)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for SyncedNamedMap<T>
+    where
+        T: Deserialize<'de> + FromName + NameAware,
+    {
+        /// Deserializes from the same forms as [`NamedMap`], then writes
+        /// every key into its value.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            NamedMap::<T>::deserialize(deserializer).map(Self::from)
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct Plugin {
+        #[cfg_attr(feature = "serde", serde(default))]
+        name: String,
+        enabled: bool,
+    }
+
+    impl NameAware for Plugin {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn set_name(&mut self, name: &str) {
+            self.name = name.to_string();
+        }
+    }
+
+    impl crate::adaptive::FromName for Plugin {
+        fn from_name(name: &str) -> Self {
+            Plugin {
+                name: name.to_string(),
+                enabled: true,
+            }
+        }
+    }
+
+    fn plugin(name: &str) -> Plugin {
+        Plugin {
+            name: name.to_string(),
+            enabled: false,
+        }
+    }
+
+    #[test]
+    fn test_mismatch_detected_and_fixed() {
+        let mut map = NamedMap::new();
+        map.insert("logger".to_string(), plugin("log"));
+        map.insert("http".to_string(), plugin("http"));
+        map.insert("auth".to_string(), plugin("authn"));
+
+        let mismatches = map.check_names();
+        assert_eq!(
+            mismatches,
+            [
+                Mismatch {
+                    key: "auth".to_string(),
+                    name: "authn".to_string(),
+                },
+                Mismatch {
+                    key: "logger".to_string(),
+                    name: "log".to_string(),
+                },
+            ]
+        );
+        assert_eq!(map["logger"].name, "log");
+
+        map.sync_names();
+        assert!(map.check_names().is_empty());
+        assert_eq!(map["logger"], plugin("logger"));
+        assert_eq!(map["auth"], plugin("auth"));
+    }
+
+    #[test]
+    fn test_empty_names_filled_from_keys() {
+        let mut map = NamedMap::new();
+        map.insert("logger".to_string(), plugin(""));
+        map.insert("http".to_string(), plugin(""));
+        assert_eq!(map.check_names().len(), 2);
+
+        let synced = SyncedNamedMap::from(map);
+        assert_eq!(synced["logger"].name, "logger");
+        assert_eq!(synced["http"].name, "http");
+
+        let mut synced = SyncedNamedMap::new();
+        synced.insert("auth".to_string(), plugin(""));
+        assert_eq!(synced["auth"].name, "auth");
+        assert!(synced.check_names().is_empty());
+    }
+
+    #[test]
+    fn test_from_values_duplicates() {
+        let values = ["http", "ssh", "http", "dns", "ssh", "ssh"].map(plugin);
+        let err = NamedMap::from_values(values.to_vec()).unwrap_err();
+        assert_eq!(err.names, ["http", "ssh"]);
+        assert_eq!(err.to_string(), "duplicate names \"http\", \"ssh\"");
+
+        let mut values = values.to_vec();
+        values[2].enabled = true;
+        let map = NamedMap::from_values_overwriting(values);
+        assert_eq!(map.names(), ["dns", "http", "ssh"]);
+        assert!(map["http"].enabled);
+    }
+
+    #[test]
+    fn test_from_values_empty() {
+        let map = NamedMap::<Plugin>::from_values(Vec::new()).unwrap();
+        assert!(map.is_empty());
+        assert!(NamedMap::<Plugin>::from_values_overwriting(Vec::new()).is_empty());
+        assert!(map.into_values_sorted().is_empty());
+    }
+
+    #[test]
+    fn test_values_round_trip() {
+        let values = vec![plugin("logger"), plugin("auth"), plugin("http")];
+        let map = NamedMap::from_values(values.clone()).unwrap();
+        assert!(map.check_names().is_empty());
+        assert_eq!(map["auth"], plugin("auth"));
+
+        let sorted = map.clone().into_values_sorted();
+        assert_eq!(sorted, [plugin("auth"), plugin("http"), plugin("logger")]);
+        assert_eq!(NamedMap::from_values(sorted).unwrap(), map);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_deserialize_syncs_names() {
+        let map: SyncedNamedMap<Plugin> = serde_json::from_str(
+            r#"{"logger": {"name": "log", "enabled": false}, "http": {"enabled": true}}"#,
+        )
+        .unwrap();
+        assert_eq!(map["logger"], plugin("logger"));
+        assert_eq!(map["http"].name, "http");
+        assert!(map.check_names().is_empty());
+
+        let map: SyncedNamedMap<Plugin> = serde_json::from_str(r#"["auth"]"#).unwrap();
+        assert_eq!(map["auth"].name, "auth");
+    }
+}
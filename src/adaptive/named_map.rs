@@ -0,0 +1,119 @@
+// flexicon/src/adaptive/named_map.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Adaptive parsing for plain `HashMap<String, T>` fields (`hashbrown`'s
+//! without the `std` feature).
+//!
+//! For structs that cannot use [`NamedMap`] directly (e.g. because they are
+//! shared with another crate), this module plugs the same dual-format
+//! parsing into a regular map field:
+//!
+//! ```rust
+//! # use serde::{Deserialize, Serialize};
+//! # #[cfg(feature = "std")]
+//! # use std::collections::HashMap;
+//! # #[cfg(not(feature = "std"))]
+//! # use hashbrown::HashMap;
+//! # #[derive(Clone, Serialize, Deserialize)]
+//! # struct Interface { version: String }
+//! # impl flexicon::adaptive::FromName for Interface {
+//! #     fn from_name(_name: &str) -> Self { Self { version: "latest".into() } }
+//! # }
+//! #[derive(Serialize, Deserialize)]
+//! struct Manifest {
+//!     #[serde(with = "flexicon::adaptive::named_map")]
+//!     interfaces: HashMap<String, Interface>,
+//! }
+//! ```
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use alloc::string::String;
+
+use super::map::HashMap;
+use super::{FromName, NamedMap};
+
+/// Serializes the map in the detailed (object) form.
+pub fn serialize<T, S>(map: &HashMap<String, T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    serializer.collect_map(map)
+}
+
+/// Deserializes the map from any form accepted by [`NamedMap`].
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<HashMap<String, T>, D::Error>
+where
+    T: DeserializeOwned + FromName,
+    D: Deserializer<'de>,
+{
+    NamedMap::deserialize(deserializer).map(NamedMap::into_inner)
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Interface {
+        version: String,
+    }
+
+    impl FromName for Interface {
+        fn from_name(_name: &str) -> Self {
+            Self {
+                version: "latest".to_string(),
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Manifest {
+        name: String,
+        #[serde(with = "crate::adaptive::named_map")]
+        interfaces: HashMap<String, Interface>,
+        enabled: bool,
+    }
+
+    #[test]
+    fn test_toml_field() {
+        let manifest: Manifest = toml::from_str(
+            r#"
+            name = "app"
+            interfaces = ["logger", { http = { version = "0.2" } }]
+            enabled = true
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            manifest.interfaces["logger"],
+            Interface::from_name("logger")
+        );
+        assert_eq!(manifest.interfaces["http"].version, "0.2");
+
+        let restored: Manifest = toml::from_str(&toml::to_string(&manifest).unwrap()).unwrap();
+        assert_eq!(manifest, restored);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_field() {
+        let manifest: Manifest =
+            serde_json::from_str(r#"{"name": "app", "interfaces": ["logger"], "enabled": false}"#)
+                .unwrap();
+        assert_eq!(manifest.interfaces.len(), 1);
+
+        let json = serde_json::to_value(&manifest).unwrap();
+        assert!(json["interfaces"].is_object());
+        let restored: Manifest = serde_json::from_value(json).unwrap();
+        assert_eq!(manifest, restored);
+    }
+}
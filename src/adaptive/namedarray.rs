@@ -0,0 +1,292 @@
+// flexicon/src/adaptive/namedarray.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Reading arrays of objects that carry their own `name` field, the input
+//! counterpart of [`NamedMap::to_named_array`].
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use super::{DuplicateNameError, DuplicatePolicy, EmptyNameError, NamedMap};
+
+/// What [`NamedMap::from_named_array_with`] does with each element's
+/// `name` field.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NameField {
+    /// Remove it before deserializing the rest of the element.
+    #[default]
+    Remove,
+    /// Leave it in, for values that store their own name.
+    Keep,
+}
+
+/// Why an array of named objects could not be read.
+#[derive(Debug)]
+pub enum NamedArrayError {
+    /// The input was not an array.
+    NotAnArray,
+    /// An element was not an object.
+    NotAnObject {
+        /// Zero-based position of the element.
+        index: usize,
+    },
+    /// An element had no string `name` field.
+    MissingName {
+        /// Zero-based position of the element.
+        index: usize,
+    },
+    /// An element's name was empty or whitespace-only.
+    EmptyName(EmptyNameError),
+    /// A name was repeated under [`DuplicatePolicy::Error`].
+    Duplicate(DuplicateNameError),
+    /// An element could not be deserialized.
+    InvalidEntry {
+        /// The element's name.
+        name: String,
+        /// The underlying serde error.
+        source: serde_json::Error,
+    },
+}
+
+impl fmt::Display for NamedArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NamedArrayError::NotAnArray => f.write_str("expected an array of named objects"),
+            NamedArrayError::NotAnObject { index } => {
+                write!(f, "element at index {} is not an object", index)
+            }
+            NamedArrayError::MissingName { index } => {
+                write!(f, "element at index {} has no string `name` field", index)
+            }
+            NamedArrayError::EmptyName(err) => err.fmt(f),
+            NamedArrayError::Duplicate(err) => err.fmt(f),
+            NamedArrayError::InvalidEntry { name, source } => {
+                write!(f, "error in entry {:?}: {}", name, source)
+            }
+        }
+    }
+}
+
+impl Error for NamedArrayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NamedArrayError::EmptyName(err) => Some(err),
+            NamedArrayError::Duplicate(err) => Some(err),
+            NamedArrayError::InvalidEntry { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl<T: DeserializeOwned> NamedMap<T> {
+    /// Reads an array of objects keyed by their `name` field, such as
+    /// `[{"name": "logger", "version": "1"}]` or the YAML list
+    /// `- name: logger`. The field is removed before the rest of each
+    /// element is deserialized into `T`, and repeated names are rejected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Plugin {
+    ///     version: String,
+    /// }
+    ///
+    /// let map = NamedMap::<Plugin>::from_named_array(serde_json::json!([
+    ///     {"name": "logger", "version": "1"},
+    ///     {"name": "http", "version": "2"},
+    /// ]))
+    /// .unwrap();
+    /// assert_eq!(map["http"].version, "2");
+    /// ```
+    pub fn from_named_array(value: Value) -> Result<Self, NamedArrayError> {
+        Self::from_named_array_with(value, NameField::Remove, DuplicatePolicy::Error)
+    }
+
+    /// Like [`from_named_array`](Self::from_named_array), choosing what
+    /// happens to the `name` field and to repeated names.
+    pub fn from_named_array_with(
+        value: Value,
+        name_field: NameField,
+        policy: DuplicatePolicy,
+    ) -> Result<Self, NamedArrayError> {
+        let Value::Array(elements) = value else {
+            return Err(NamedArrayError::NotAnArray);
+        };
+        let mut map = HashMap::with_capacity(elements.len());
+        for (index, element) in elements.into_iter().enumerate() {
+            let Value::Object(mut fields) = element else {
+                return Err(NamedArrayError::NotAnObject { index });
+            };
+            let name = match fields.get("name") {
+                Some(Value::String(name)) => name.clone(),
+                _ => return Err(NamedArrayError::MissingName { index }),
+            };
+            if name.trim().is_empty() {
+                return Err(NamedArrayError::EmptyName(EmptyNameError { index }));
+            }
+            if name_field == NameField::Remove {
+                fields.remove("name");
+            }
+            let value = serde_json::from_value(Value::Object(fields)).map_err(|source| {
+                NamedArrayError::InvalidEntry {
+                    name: name.clone(),
+                    source,
+                }
+            })?;
+            policy
+                .insert(&mut map, name, value, index)
+                .map_err(NamedArrayError::Duplicate)?;
+        }
+        Ok(NamedMap::from(map))
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Plugin {
+        version: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    struct NamedPlugin {
+        name: String,
+        version: String,
+    }
+
+    fn plugin(version: &str) -> Plugin {
+        Plugin {
+            version: version.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_json_array() {
+        let map = NamedMap::<Plugin>::from_named_array(json!([
+            {"name": "logger", "version": "1"},
+            {"name": "http", "version": "2"},
+        ]))
+        .unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["logger"], plugin("1"));
+        assert_eq!(map["http"], plugin("2"));
+
+        let map = NamedMap::<NamedPlugin>::from_named_array_with(
+            json!([{"name": "logger", "version": "1"}]),
+            NameField::Keep,
+            DuplicatePolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(map["logger"].name, "logger");
+
+        let map = NamedMap::<Plugin>::from_named_array(json!([])).unwrap();
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_round_trip_with_to_named_array() {
+        let map = NamedMap::<Plugin>::from_named_array(json!([
+            {"name": "logger", "version": "1"},
+            {"name": "auth", "version": "3"},
+        ]))
+        .unwrap();
+        let array = map.to_named_array().unwrap();
+        assert_eq!(NamedMap::from_named_array(array).unwrap(), map);
+    }
+
+    #[test]
+    fn test_missing_and_invalid_names() {
+        let err = NamedMap::<Plugin>::from_named_array(json!([
+            {"name": "logger", "version": "1"},
+            {"version": "2"},
+        ]))
+        .unwrap_err();
+        assert!(matches!(err, NamedArrayError::MissingName { index: 1 }));
+        assert_eq!(
+            err.to_string(),
+            "element at index 1 has no string `name` field"
+        );
+
+        for (value, message) in [
+            (
+                json!([{"name": 7}]),
+                "element at index 0 has no string `name` field",
+            ),
+            (json!([{"name": " "}]), "empty name at index 0"),
+            (json!(["logger"]), "element at index 0 is not an object"),
+            (json!({"logger": {}}), "expected an array of named objects"),
+        ] {
+            let err = NamedMap::<Plugin>::from_named_array(value).unwrap_err();
+            assert_eq!(err.to_string(), message);
+        }
+
+        let err =
+            NamedMap::<Plugin>::from_named_array(json!([{"name": "a", "version": 1}])).unwrap_err();
+        assert!(
+            err.to_string().starts_with("error in entry \"a\": "),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_duplicate_policy() {
+        let value = json!([
+            {"name": "a", "version": "1"},
+            {"name": "b", "version": "2"},
+            {"name": "a", "version": "3"},
+        ]);
+        let err = NamedMap::<Plugin>::from_named_array(value.clone()).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate name \"a\" at index 2");
+
+        let first = NamedMap::<Plugin>::from_named_array_with(
+            value.clone(),
+            NameField::Remove,
+            DuplicatePolicy::KeepFirst,
+        )
+        .unwrap();
+        assert_eq!(first["a"], plugin("1"));
+        let last = NamedMap::<Plugin>::from_named_array_with(
+            value,
+            NameField::Remove,
+            DuplicatePolicy::KeepLast,
+        )
+        .unwrap();
+        assert_eq!(last["a"], plugin("3"));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_list() {
+        let yaml = "- name: logger\n  version: \"1\"\n- name: http\n  version: \"2\"\n";
+        let value: Value = serde_yaml::from_str(yaml).unwrap();
+        let map = NamedMap::<Plugin>::from_named_array(value).unwrap();
+        assert_eq!(map["logger"], plugin("1"));
+        assert_eq!(map["http"], plugin("2"));
+
+        let yaml = "- name: logger\n  version: \"1\"\n- version: \"2\"\n";
+        let value: Value = serde_yaml::from_str(yaml).unwrap();
+        let err = NamedMap::<Plugin>::from_named_array(value).unwrap_err();
+        assert!(matches!(err, NamedArrayError::MissingName { index: 1 }));
+    }
+}
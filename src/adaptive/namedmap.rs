@@ -7,8 +7,18 @@
 // This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::collections::HashMap;
-use std::ops::{Deref, DerefMut};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt;
+use core::hash::{BuildHasher, Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+use super::map::{DefaultHasher, Entry, HashMap, IntoIter, Iter, IterMut};
+use super::namepolicy::insert_checked;
+use super::NamePolicy;
 
 /// A trait for types that can be constructed from a name string.
 ///
@@ -45,12 +55,60 @@ pub trait FromName: Clone {
     fn from_name(name: &str) -> Self;
 }
 
+/// A fallible counterpart of [`FromName`].
+///
+/// Use it when a malformed name really is an error and no sensible
+/// placeholder exists. [`TryNamedMap`](super::TryNamedMap) uses this trait
+/// to turn a bad name in the simple (array) form into a deserialization
+/// error that mentions the offending name.
+///
+/// Every `FromName` type implements `TryFromName` with
+/// `Error = Infallible`, so existing types keep working unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use flexicon::adaptive::TryFromName;
+///
+/// struct Port(u16);
+///
+/// impl TryFromName for Port {
+///     type Error = std::num::ParseIntError;
+///
+///     fn try_from_name(name: &str) -> Result<Self, Self::Error> {
+///         name.parse().map(Port)
+///     }
+/// }
+///
+/// assert!(Port::try_from_name("8080").is_ok());
+/// assert!(Port::try_from_name("http").is_err());
+/// ```
+pub trait TryFromName: Sized {
+    /// The error returned when the name cannot be turned into a value.
+    type Error: fmt::Display;
+
+    /// Attempt to construct a value from its name.
+    fn try_from_name(name: &str) -> Result<Self, Self::Error>;
+}
+
+impl<T: FromName> TryFromName for T {
+    type Error = Infallible;
+
+    fn try_from_name(name: &str) -> Result<Self, Self::Error> {
+        Ok(T::from_name(name))
+    }
+}
+
 /// A map of named items that supports **adaptive deserialization**:
 ///
 /// - **Human-friendly format**: `["a", "b"]`  
 ///   → each name is converted to a placeholder using `FromName`.
 /// - **Machine-friendly format**: `{ "a": {...}, "b": {...} }`  
 ///   → full structured values are parsed as-is.
+/// - **Mixed format**: `["a", { "b": {...} }]`  
+///   → names and single-key inline entries may be combined in one list.
+/// - **Single name**: `"a"`  
+///   → shorthand for `["a"]`.
 ///
 /// This enables configurations that are **easy to write** and **rich to process**.
 ///
@@ -73,10 +131,15 @@ pub trait FromName: Clone {
 /// use flexicon::adaptive::NamedMap;
 ///
 /// # #[cfg(feature = "serde_json")]
-/// # {
+/// # fn main() -> Result<(), serde_json::Error> {
 /// // Simple config (user-authored)
 /// let simple: NamedMap<Interface> = serde_json::from_str(r#"["logger", "http"]"#)?;
 ///
+/// // Mixed config: most entries by name, one refined inline
+/// let mixed: NamedMap<Interface> = serde_json::from_str(r#"
+/// ["logger", { "http": { "version": "0.2" } }]
+/// "#)?;
+///
 /// // Detailed config (tool-generated)
 /// let detailed: NamedMap<Interface> = serde_json::from_str(r#"
 /// {
@@ -84,21 +147,77 @@ pub trait FromName: Clone {
 ///   "http": { "version": "0.2" }
 /// }
 /// "#)?;
-/// # Ok::<(), serde_json::Error>(())
+/// # Ok(())
 /// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+///
+/// The hasher `S` defaults to the standard `RandomState` (to `hashbrown`'s
+/// default hasher without the `std` feature); any `BuildHasher` (FxHash,
+/// aHash, ...) can be plugged in for small, hot maps:
+///
+/// ```rust
+/// use flexicon::adaptive::NamedMap;
+/// use std::hash::BuildHasherDefault;
+///
+/// type Hasher = BuildHasherDefault<std::collections::hash_map::DefaultHasher>;
+///
+/// let mut map = NamedMap::<u16, Hasher>::default();
+/// map.insert("http".to_string(), 80);
+/// assert_eq!(map["http"], 80);
 /// ```
+#[derive(Debug, Clone)]
+pub struct NamedMap<T, S = DefaultHasher>(HashMap<String, T, S>);
+
+/// The error returned by [`NamedMap::try_insert`] when the key is taken.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct NamedMap<T>(HashMap<String, T>);
+pub struct OccupiedError<T> {
+    /// The key that is already present.
+    pub key: String,
+    /// The value that was not inserted.
+    pub value: T,
+}
+
+impl<T> fmt::Display for OccupiedError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "duplicate key {:?}", self.key)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for OccupiedError<T> {}
 
 impl<T> NamedMap<T> {
     /// Creates an empty `NamedMap`.
+    ///
+    /// Like `HashMap::new`, only available with the default hasher; use
+    /// [`with_hasher`](Self::with_hasher) or `Default` for another one.
     pub fn new() -> Self {
         Self(HashMap::new())
     }
 
-    /// Inserts a key-value pair into the map.
-    pub fn insert(&mut self, key: String, value: T) {
-        self.0.insert(key, value);
+    /// Creates an empty `NamedMap` with room for at least `capacity` entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+}
+
+impl<T, S> NamedMap<T, S> {
+    /// Creates an empty `NamedMap` using `hasher` to hash names.
+    pub fn with_hasher(hasher: S) -> Self {
+        Self(HashMap::with_hasher(hasher))
+    }
+
+    /// Creates an empty `NamedMap` with room for at least `capacity`
+    /// entries, using `hasher` to hash names.
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        Self(HashMap::with_capacity_and_hasher(capacity, hasher))
+    }
+
+    /// Returns the number of entries the map can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
     }
 
     /// Returns `true` if the map contains no elements.
@@ -107,12 +226,12 @@ impl<T> NamedMap<T> {
     }
 
     /// Consumes the map and returns the inner `HashMap`.
-    pub fn into_inner(self) -> HashMap<String, T> {
+    pub fn into_inner(self) -> HashMap<String, T, S> {
         self.0
     }
 
     /// Returns a reference to the inner map.
-    pub fn as_inner(&self) -> &HashMap<String, T> {
+    pub fn as_inner(&self) -> &HashMap<String, T, S> {
         &self.0
     }
 
@@ -121,277 +240,2469 @@ impl<T> NamedMap<T> {
     /// ⚠️ **Warning**: Direct mutation bypasses any future validation or invariants
     /// that `NamedMap` might enforce (e.g., key normalization, version parsing).
     /// Prefer using `insert` or higher-level APIs when possible.
-    pub fn as_inner_mut(&mut self) -> &mut HashMap<String, T> {
+    pub fn as_inner_mut(&mut self) -> &mut HashMap<String, T, S> {
         &mut self.0
     }
+
+    /// Iterates over the entries in lexicographic key order, so that logs
+    /// and reports come out the same on every run.
+    ///
+    /// Sorts a `Vec` of references up front; no keys or values are cloned.
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let map: NamedMap<u16> = [("ssh", 22), ("http", 80)]
+    ///     .into_iter()
+    ///     .map(|(name, port)| (name.to_string(), port))
+    ///     .collect();
+    /// let names: Vec<&String> = map.keys_sorted().collect();
+    /// assert_eq!(names, ["http", "ssh"]);
+    /// ```
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (&String, &T)> {
+        let mut entries: Vec<(&String, &T)> = self.0.iter().collect();
+        entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        entries.into_iter()
+    }
+
+    /// Iterates over the keys in lexicographic order.
+    pub fn keys_sorted(&self) -> impl Iterator<Item = &String> {
+        let mut keys: Vec<&String> = self.0.keys().collect();
+        keys.sort_unstable();
+        keys.into_iter()
+    }
+
+    /// Iterates over the values in the lexicographic order of their keys.
+    pub fn values_sorted_by_key(&self) -> impl Iterator<Item = &T> {
+        self.iter_sorted().map(|(_, value)| value)
+    }
+
+    /// Returns the names, sorted.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = self.names_unsorted();
+        names.sort_unstable();
+        names
+    }
+
+    /// Returns an adapter displaying all names, sorted and separated by
+    /// commas, e.g. for `"available interfaces: {}"`.
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let map: NamedMap<u16> = [("ssh", 22), ("http", 80)]
+    ///     .into_iter()
+    ///     .map(|(name, port)| (name.to_string(), port))
+    ///     .collect();
+    /// assert_eq!(format!("available: {}", map.display_names()), "available: http, ssh");
+    /// ```
+    pub fn display_names(&self) -> DisplayNames<'_, T, S> {
+        DisplayNames(self)
+    }
+
+    /// Returns the names in arbitrary order, without sorting.
+    pub fn names_unsorted(&self) -> Vec<&str> {
+        self.0.keys().map(String::as_str).collect()
+    }
+
+    /// Consumes the map and returns its names, sorted. The keys are moved
+    /// out, not cloned.
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let map: NamedMap<u16> = [("ssh", 22), ("http", 80)]
+    ///     .into_iter()
+    ///     .map(|(name, port)| (name.to_string(), port))
+    ///     .collect();
+    /// assert_eq!(map.names(), ["http", "ssh"]);
+    /// assert_eq!(map.into_names(), ["http", "ssh"]);
+    /// ```
+    pub fn into_names(self) -> Vec<String> {
+        let mut names: Vec<String> = self.0.into_keys().collect();
+        names.sort_unstable();
+        names
+    }
 }
 
-impl<T> Default for NamedMap<T> {
-    fn default() -> Self {
-        Self::new()
+impl<T, S: BuildHasher> NamedMap<T, S> {
+    /// Reserves room for at least `additional` more entries.
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    /// Shrinks the capacity as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
+    /// Inserts a key-value pair into the map, returning the value it
+    /// replaced, if any (like `HashMap::insert`).
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let mut map = NamedMap::new();
+    /// assert_eq!(map.insert("http".to_string(), 80), None);
+    /// assert_eq!(map.insert("http".to_string(), 8080), Some(80));
+    /// ```
+    pub fn insert(&mut self, key: String, value: T) -> Option<T> {
+        self.0.insert(key, value)
+    }
+
+    /// Inserts a key-value pair only if `key` is absent, returning a
+    /// reference to the inserted value.
+    ///
+    /// An existing entry is left untouched and the rejected value is given
+    /// back in the error.
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let mut map = NamedMap::new();
+    /// *map.try_insert("http".to_string(), 80).unwrap() += 1;
+    /// let err = map.try_insert("http".to_string(), 8080).unwrap_err();
+    /// assert_eq!((err.key.as_str(), err.value), ("http", 8080));
+    /// assert_eq!(map["http"], 81);
+    /// ```
+    pub fn try_insert(&mut self, key: String, value: T) -> Result<&mut T, OccupiedError<T>> {
+        match self.0.entry(key) {
+            Entry::Vacant(entry) => Ok(entry.insert(value)),
+            Entry::Occupied(entry) => Err(OccupiedError {
+                key: entry.key().clone(),
+                value,
+            }),
+        }
     }
 }
 
-impl<T> From<HashMap<String, T>> for NamedMap<T> {
-    fn from(map: HashMap<String, T>) -> Self {
-        NamedMap(map)
+// Written by hand: derived impls would require `S: PartialEq`, which
+// `RandomState` does not implement.
+impl<T: PartialEq, S: BuildHasher> PartialEq for NamedMap<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
     }
 }
 
-// Make `NamedMap<T>` behave like a `HashMap` for seamless use.
-impl<T> Deref for NamedMap<T> {
-    type Target = HashMap<String, T>;
+impl<T: Eq, S: BuildHasher> Eq for NamedMap<T, S> {}
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+impl<T: PartialEq, S: BuildHasher, S2: BuildHasher> PartialEq<HashMap<String, T, S2>>
+    for NamedMap<T, S>
+{
+    fn eq(&self, other: &HashMap<String, T, S2>) -> bool {
+        self.0.len() == other.len()
+            && self
+                .0
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
     }
 }
 
-impl<T> DerefMut for NamedMap<T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+impl<T: PartialEq, S: BuildHasher, S2: BuildHasher> PartialEq<NamedMap<T, S>>
+    for HashMap<String, T, S2>
+{
+    fn eq(&self, other: &NamedMap<T, S>) -> bool {
+        other == self
     }
 }
 
-// Allow construction from a simple list of names (e.g., TOML: `interfaces = ["a", "b"]`)
-impl<T: FromName + Clone> From<Vec<String>> for NamedMap<T> {
-    fn from(list: Vec<String>) -> Self {
-        let mut map = HashMap::new();
-        for name in list {
-            map.insert(name.clone(), T::from_name(&name));
-        }
-        NamedMap(map)
+impl<T: PartialEq, S: BuildHasher> PartialEq<BTreeMap<String, T>> for NamedMap<T, S> {
+    fn eq(&self, other: &BTreeMap<String, T>) -> bool {
+        self.0.len() == other.len()
+            && other
+                .iter()
+                .all(|(key, value)| self.0.get(key) == Some(value))
     }
 }
 
-// === SERDE INTEGRATION (format-agnostic) ===
-
-#[cfg(feature = "serde")]
-mod serde_impl {
-    use super::*;
-    use serde::{
-        de::{DeserializeOwned, Deserializer, MapAccess, SeqAccess, Visitor},
-        ser::Serializer,
-        Deserialize, Serialize,
-    };
-    use std::fmt;
-    use std::marker::PhantomData;
+impl<T: PartialEq, S: BuildHasher> PartialEq<NamedMap<T, S>> for BTreeMap<String, T> {
+    fn eq(&self, other: &NamedMap<T, S>) -> bool {
+        other == self
+    }
+}
 
-    /// Visitor that handles both array-of-strings and object formats.
-    #[derive(Debug)]
-    struct NamedMapVisitor<T> {
-        _phantom: PhantomData<T>,
+/// Hashes the number of entries, then every key and value in sorted key
+/// order, so equal maps hash equally whatever their insertion order or
+/// hasher. Sorting allocates a `Vec` of references per call.
+impl<T: Hash, S> Hash for NamedMap<T, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.0.len());
+        for (key, value) in self.iter_sorted() {
+            key.hash(state);
+            value.hash(state);
+        }
     }
+}
 
-    impl<'de, T> Visitor<'de> for NamedMapVisitor<T>
-    where
-        T: DeserializeOwned + FromName + Clone,
-    {
-        type Value = NamedMap<T>;
+/// How many names the `Display` impl of [`NamedMap`] lists before
+/// summarizing the rest.
+const DISPLAY_NAMES: usize = 10;
 
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            write!(formatter, "either a map (e.g., {{ \"a\": {{...}} }}) or a sequence of strings (e.g., [\"a\", \"b\"])")
+/// A compact summary for error messages: the entry count and the sorted
+/// names, without the values. Names past the tenth are counted, not listed.
+///
+/// ```rust
+/// use flexicon::adaptive::NamedMap;
+///
+/// let map: NamedMap<u16> = [("logger", 0), ("http", 80), ("metrics", 9090)]
+///     .into_iter()
+///     .map(|(name, port)| (name.to_string(), port))
+///     .collect();
+/// assert_eq!(map.to_string(), "NamedMap{3 entries: http, logger, metrics}");
+/// ```
+impl<T, S> fmt::Display for NamedMap<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = self.names();
+        let noun = if names.len() == 1 { "entry" } else { "entries" };
+        write!(f, "NamedMap{{{} {}", names.len(), noun)?;
+        for (i, name) in names.iter().take(DISPLAY_NAMES).enumerate() {
+            f.write_str(if i == 0 { ": " } else { ", " })?;
+            f.write_str(name)?;
         }
+        if names.len() > DISPLAY_NAMES {
+            write!(f, ", … and {} more", names.len() - DISPLAY_NAMES)?;
+        }
+        f.write_str("}")
+    }
+}
 
-        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-        where
-            A: SeqAccess<'de>,
-        {
-            let mut map = HashMap::new();
-            while let Some(name) = seq.next_element::<String>()? {
-                map.insert(name.clone(), T::from_name(&name));
+/// Displays every name of a map, sorted and separated by commas; returned
+/// by [`NamedMap::display_names`].
+pub struct DisplayNames<'a, T, S = DefaultHasher>(&'a NamedMap<T, S>);
+
+impl<T, S> fmt::Display for DisplayNames<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, name) in self.0.names().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
             }
-            Ok(NamedMap(map))
+            f.write_str(name)?;
         }
+        Ok(())
+    }
+}
 
-        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
-        where
-            A: MapAccess<'de>,
-        {
-            // Delegate to standard map deserialization
-            let inner = Deserialize::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
-            Ok(NamedMap(inner))
-        }
+impl<T, S: Default> Default for NamedMap<T, S> {
+    fn default() -> Self {
+        Self(HashMap::default())
     }
+}
 
-    impl<T> Serialize for NamedMap<T>
-    where
-        T: Serialize,
-    {
-        /// Serializes as a JSON/TOML/YAML object (never as an array).
-        /// This ensures canonical, lossless output.
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            self.0.serialize(serializer)
-        }
+impl<T, S> From<HashMap<String, T, S>> for NamedMap<T, S> {
+    fn from(map: HashMap<String, T, S>) -> Self {
+        NamedMap(map)
     }
+}
 
-    impl<'de, T> Deserialize<'de> for NamedMap<T>
-    where
-        T: DeserializeOwned + FromName + Clone,
-    {
-        /// Deserializes from either:
-        /// - An object (detailed form)
-        /// - An array of strings (simple form)
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            deserializer.deserialize_any(NamedMapVisitor {
-                _phantom: PhantomData,
-            })
-        }
+impl<T, S> From<NamedMap<T, S>> for HashMap<String, T, S> {
+    fn from(map: NamedMap<T, S>) -> Self {
+        map.0
     }
 }
 
-// === JSON-SPECIFIC CONVENIENCE METHODS ===
+impl<T, S: BuildHasher + Default> From<BTreeMap<String, T>> for NamedMap<T, S> {
+    fn from(map: BTreeMap<String, T>) -> Self {
+        map.into_iter().collect()
+    }
+}
 
-#[cfg(feature = "serde_json")]
-impl<T> NamedMap<T>
-where
-    T: serde::Serialize,
-{
-    /// Serialize this map to a `serde_json::Value`.
-    ///
-    /// Equivalent to `serde_json::to_value(&map)`, but with explicit intent.
-    pub fn to_json_value(&self) -> serde_json::Result<serde_json::Value> {
-        serde_json::to_value(&self.0)
+impl<T, S> From<NamedMap<T, S>> for BTreeMap<String, T> {
+    /// Moves the entries into a map sorted by name.
+    fn from(map: NamedMap<T, S>) -> Self {
+        map.0.into_iter().collect()
     }
+}
 
-    /// Serialize this map to a compact JSON string.
-    pub fn to_json_string(&self) -> serde_json::Result<String> {
-        serde_json::to_string(&self.0)
+// Make `NamedMap<T>` behave like a `HashMap` for seamless use.
+impl<T, S> Deref for NamedMap<T, S> {
+    type Target = HashMap<String, T, S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
     }
 }
 
-#[cfg(feature = "serde_json")]
-impl<T> NamedMap<T>
+impl<T, S> DerefMut for NamedMap<T, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+// A lenient conversion from a list of names: empty names are skipped and
+// repeats collapse, whereas the array form rejects both by default.
+impl<T, S> From<Vec<String>> for NamedMap<T, S>
 where
-    T: for<'de> serde::Deserialize<'de> + FromName + Clone,
+    T: FromName + Clone,
+    S: BuildHasher + Default,
 {
-    /// Parse a `NamedMap` from a `serde_json::Value`.
+    /// Skips empty and whitespace-only names ([`NamePolicy::SkipEmpty`])
+    /// and keeps the last of repeated names
+    /// ([`DuplicatePolicy::KeepLast`](super::DuplicatePolicy::KeepLast)).
     ///
-    /// Supports the same dual formats as the general `Deserialize` impl:
-    /// - Object: `{ "a": {...} }`
-    /// - Array: `["a", "b"]`
-    pub fn from_json_value(value: serde_json::Value) -> serde_json::Result<Self> {
-        match &value {
-            serde_json::Value::Object(_) => {
-                let inner = serde_json::from_value(value)?;
-                Ok(NamedMap(inner))
-            }
-            serde_json::Value::Array(arr) => {
-                let mut map = HashMap::new();
-                for item in arr {
-                    let s = item
-                        .as_str()
-                        .ok_or_else(|| serde_json::Error::custom("array items must be strings"))?;
-                    map.insert(s.to_string(), T::from_name(s));
-                }
-                Ok(NamedMap(map))
-            }
-            _ => Err(serde_json::Error::custom(
-                "NamedMap must be an object or array of strings",
-            )),
-        }
+    /// To check a list the way the array form does, use
+    /// [`NamedMap::try_from_names`] or [`NamedMap::from_names_with_policy`]
+    /// instead.
+    fn from(list: Vec<String>) -> Self {
+        let mut map = HashMap::with_capacity_and_hasher(list.len(), S::default());
+        // `SkipEmpty` never fails.
+        let _ = insert_checked(&mut map, list, NamePolicy::SkipEmpty);
+        NamedMap(map)
     }
+}
 
-    /// Parse a `NamedMap` from a JSON string.
-    ///
-    /// Useful for config loading or API parsing.
-    pub fn from_json_str(s: &str) -> serde_json::Result<Self> {
-        let value: serde_json::Value = serde_json::from_str(s)?;
-        Self::from_json_value(value)
+impl<T, S> IntoIterator for NamedMap<T, S> {
+    type Item = (String, T);
+    type IntoIter = IntoIter<String, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
     }
 }
 
-// === TESTS ===
+impl<'a, T, S> IntoIterator for &'a NamedMap<T, S> {
+    type Item = (&'a String, &'a T);
+    type IntoIter = Iter<'a, String, T>;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[cfg(feature = "serde")]
-    use serde::{Deserialize, Serialize};
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
 
-    #[cfg(feature = "serde")]
-    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-    struct TestItem {
-        value: String,
-        optional: bool,
+impl<'a, T, S> IntoIterator for &'a mut NamedMap<T, S> {
+    type Item = (&'a String, &'a mut T);
+    type IntoIter = IterMut<'a, String, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
     }
+}
 
-    #[cfg(feature = "serde")]
-    impl FromName for TestItem {
-        fn from_name(name: &str) -> Self {
-            Self {
-                value: format!("from_name({})", name),
-                optional: false,
-            }
-        }
+impl<T, S: BuildHasher + Default> FromIterator<(String, T)> for NamedMap<T, S> {
+    fn from_iter<I: IntoIterator<Item = (String, T)>>(iter: I) -> Self {
+        NamedMap(iter.into_iter().collect())
     }
+}
 
-    #[test]
-    fn test_new_is_empty() {
-        let map: NamedMap<()> = NamedMap::new();
-        assert!(map.is_empty());
+impl<T, S: BuildHasher> Extend<(String, T)> for NamedMap<T, S> {
+    /// Inserts every pair; later values overwrite existing keys.
+    fn extend<I: IntoIterator<Item = (String, T)>>(&mut self, iter: I) {
+        self.0.extend(iter);
     }
+}
 
-    #[cfg(feature = "serde")]
-    #[test]
-    fn test_insert_and_get() {
-        let mut map = NamedMap::new();
-        map.insert("key1".to_string(), TestItem {
-            value: "value1".to_string(),
-            optional: true,
-        });
-        assert_eq!(map["key1"].value, "value1");
+// Collect a list of names, with the same semantics as `From<Vec<String>>`.
+//
+// A blanket `S: AsRef<str>` impl would overlap with `FromIterator<(String, T)>`
+// under coherence rules, so the common name types are covered explicitly.
+impl<T: FromName, S: BuildHasher + Default> FromIterator<String> for NamedMap<T, S> {
+    /// Skips empty and whitespace-only names ([`NamePolicy::SkipEmpty`]).
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let mut map = HashMap::default();
+        // `SkipEmpty` never fails.
+        let _ = insert_checked(&mut map, iter, NamePolicy::SkipEmpty);
+        NamedMap(map)
     }
+}
 
-    #[cfg(feature = "serde")]
-    #[test]
-    fn test_deref_transparency() {
-        let mut map = NamedMap::new();
-        map.insert("test".to_string(), TestItem {
-            value: "ok".to_string(),
-            optional: false,
-        });
-        assert_eq!(map.get("test").unwrap().value, "ok");
+impl<'a, T: FromName, S: BuildHasher + Default> FromIterator<&'a str> for NamedMap<T, S> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        iter.into_iter().map(str::to_string).collect()
     }
+}
 
-    #[cfg(all(feature = "serde", feature = "serde_json"))]
-    #[test]
-    fn test_json_roundtrip_array() {
-        let original: NamedMap<TestItem> = NamedMap::from(vec!["a".to_string(), "b".to_string()]);
-        let json_str = original.to_json_string().unwrap();
-        let restored = NamedMap::from_json_str(&json_str).unwrap();
-        assert_eq!(original, restored);
+impl<'a, T: FromName, S: BuildHasher + Default> FromIterator<&'a String> for NamedMap<T, S> {
+    fn from_iter<I: IntoIterator<Item = &'a String>>(iter: I) -> Self {
+        iter.into_iter().cloned().collect()
     }
+}
 
-    #[cfg(all(feature = "serde", feature = "serde_json"))]
-    #[test]
-    fn test_json_roundtrip_object() {
-        let mut original = NamedMap::new();
+impl<T: FromName + PartialEq, S> NamedMap<T, S> {
+    /// Returns the sorted list of names if every value equals its
+    /// `T::from_name(key)` default, and `None` otherwise.
+    ///
+    /// This is the inverse of `From<Vec<String>>`: it lets tools write back
+    /// the compact `["a", "b"]` form when nothing was refined.
+    pub fn to_simple_form(&self) -> Option<Vec<String>> {
+        if self.0.iter().any(|(name, value)| *value != T::from_name(name)) {
+            return None;
+        }
+        let mut names: Vec<String> = self.0.keys().cloned().collect();
+        names.sort();
+        Some(names)
+    }
+}
+
+/// How many names [`NamedMap::insert_names`] added, how many were
+/// already present, and how many it skipped as empty.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NameCounts {
+    /// Names that were absent and got a `T::from_name` entry.
+    pub added: usize,
+    /// Names that were already present (or repeated) and left untouched.
+    pub present: usize,
+    /// Empty and whitespace-only names, skipped ([`NamePolicy::SkipEmpty`]).
+    pub skipped: usize,
+}
+
+impl<T: FromName, S: BuildHasher> NamedMap<T, S> {
+    /// Builds a map from borrowed or owned names (`&["a", "b"]`,
+    /// `Vec<String>`, any iterator of `&str`), each value coming from
+    /// `T::from_name`. Repeated names collapse into one entry, and empty
+    /// and whitespace-only names are skipped ([`NamePolicy::SkipEmpty`]).
+    ///
+    /// ```rust
+    /// # #[derive(Clone)]
+    /// # struct Plugin;
+    /// # impl flexicon::adaptive::FromName for Plugin {
+    /// #     fn from_name(_name: &str) -> Self { Plugin }
+    /// # }
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let map = NamedMap::<Plugin>::from_names(["logger", "http"]);
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    pub fn from_names<I>(names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        S: Default,
+    {
+        let mut map = Self::default();
+        map.insert_names(names);
+        map
+    }
+
+    /// Inserts `T::from_name(name)` for every name not already present,
+    /// allocating each new key once, and counts the names added, already
+    /// present and skipped. Empty and whitespace-only names are skipped
+    /// ([`NamePolicy::SkipEmpty`]).
+    pub fn insert_names<I>(&mut self, names: I) -> NameCounts
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let names = names.into_iter();
+        self.0.reserve(names.size_hint().0);
+        let mut counts = NameCounts::default();
+        for name in names {
+            let Ok(Some(name)) = NamePolicy::SkipEmpty.check(Cow::Borrowed(name.as_ref())) else {
+                counts.skipped += 1;
+                continue;
+            };
+            if self.0.contains_key(name.as_ref()) {
+                counts.present += 1;
+            } else {
+                let value = T::from_name(&name);
+                self.0.insert(name.into_owned(), value);
+                counts.added += 1;
+            }
+        }
+        counts
+    }
+
+    /// Inserts `T::from_name(name)` for every name not already present,
+    /// leaving existing entries untouched and skipping empty and
+    /// whitespace-only names ([`NamePolicy::SkipEmpty`]).
+    ///
+    /// Returns the names that were created, in the order given.
+    ///
+    /// ```rust
+    /// # #[derive(Clone)]
+    /// # struct Plugin;
+    /// # impl flexicon::adaptive::FromName for Plugin {
+    /// #     fn from_name(_name: &str) -> Self { Plugin }
+    /// # }
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let mut map = NamedMap::<Plugin>::from(vec!["logger".to_string()]);
+    /// assert_eq!(map.ensure_names(["logger", "http"]), ["http"]);
+    /// ```
+    pub fn ensure_names<I>(&mut self, names: I) -> Vec<String>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut created = Vec::new();
+        for name in names {
+            let Ok(Some(name)) = NamePolicy::SkipEmpty.check(Cow::Borrowed(name.as_ref())) else {
+                continue;
+            };
+            if !self.0.contains_key(name.as_ref()) {
+                self.0.insert(name.to_string(), T::from_name(&name));
+                created.push(name.into_owned());
+            }
+        }
+        created
+    }
+}
+
+impl<T, S: BuildHasher> NamedMap<T, S> {
+    /// Returns the names absent from the map, in the order given and
+    /// without repeats. The non-mutating counterpart of
+    /// [`ensure_names`](Self::ensure_names), for warning instead of creating.
+    pub fn missing_from<'n, I, N>(&self, names: I) -> Vec<&'n str>
+    where
+        I: IntoIterator<Item = &'n N>,
+        N: AsRef<str> + ?Sized + 'n,
+    {
+        let mut missing = Vec::new();
+        for name in names {
+            let name = name.as_ref();
+            if !self.0.contains_key(name) && !missing.contains(&name) {
+                missing.push(name);
+            }
+        }
+        missing
+    }
+
+    /// Returns `true` if every name in `names` has an entry.
+    pub fn contains_all<I>(&self, names: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        names
+            .into_iter()
+            .all(|name| self.0.contains_key(name.as_ref()))
+    }
+
+    /// Like [`missing_from`](Self::missing_from), but accepts owned names
+    /// and returns owned copies of the missing ones. Nothing is allocated
+    /// when every name is present.
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let map: NamedMap<u16> = [("http".to_string(), 80)].into_iter().collect();
+    /// assert!(map.contains_all(["http"]));
+    /// assert_eq!(map.missing_names(["http", "https"]), ["https"]);
+    /// ```
+    pub fn missing_names<I>(&self, names: I) -> Vec<String>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut missing: Vec<String> = Vec::new();
+        for name in names {
+            let name = name.as_ref();
+            if !self.0.contains_key(name) && !missing.iter().any(|m| m == name) {
+                missing.push(name.to_string());
+            }
+        }
+        missing
+    }
+
+    /// Returns the keys not listed in `allowed`, sorted. Nothing is
+    /// allocated when every key is allowed.
+    ///
+    /// `allowed` is scanned once per key, which suits the short whitelists
+    /// this is meant for.
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let map: NamedMap<u16> = [("http".to_string(), 80), ("telnet".to_string(), 23)]
+    ///     .into_iter()
+    ///     .collect();
+    /// assert_eq!(map.extra_names(&["http", "https"]), ["telnet"]);
+    /// ```
+    pub fn extra_names<'n, I, N>(&self, allowed: I) -> Vec<&str>
+    where
+        I: IntoIterator<Item = &'n N>,
+        I::IntoIter: Clone,
+        N: AsRef<str> + ?Sized + 'n,
+    {
+        let allowed = allowed.into_iter();
+        let mut extra: Vec<&str> = self
+            .0
+            .keys()
+            .map(String::as_str)
+            .filter(|key| !allowed.clone().any(|name| name.as_ref() == *key))
+            .collect();
+        extra.sort_unstable();
+        extra
+    }
+}
+
+impl<T, S: BuildHasher + Clone> NamedMap<T, S> {
+    /// Converts every value with `f(key, value)`, keeping the keys and the
+    /// hasher, e.g. to resolve a `NamedMap<RawConfig>` into a
+    /// `NamedMap<ResolvedConfig>`.
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let mut ports = NamedMap::new();
+    /// ports.insert("http".to_string(), 80u16);
+    ///
+    /// let urls = ports.map_values(|name, port| format!("{}://localhost:{}", name, port));
+    /// assert_eq!(urls["http"], "http://localhost:80");
+    /// ```
+    pub fn map_values<U, F>(self, mut f: F) -> NamedMap<U, S>
+    where
+        F: FnMut(&str, T) -> U,
+    {
+        let mut map = HashMap::with_capacity_and_hasher(self.0.len(), self.0.hasher().clone());
+        for (key, value) in self.0 {
+            let value = f(&key, value);
+            map.insert(key, value);
+        }
+        NamedMap(map)
+    }
+
+    /// Keeps the entries for which `pred(key, value)` returns `true` and
+    /// returns the others, moved rather than dropped, as a new map.
+    ///
+    /// Unlike `HashMap::retain` the removed entries stay available, e.g.
+    /// for logging or re-inserting elsewhere.
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let mut ports: NamedMap<u16> = [("http".to_string(), 80), ("ssh".to_string(), 22)]
+    ///     .into_iter()
+    ///     .collect();
+    /// let privileged = ports.retain_names(|_, port| *port >= 1024);
+    /// assert!(ports.is_empty());
+    /// assert_eq!(privileged.len(), 2);
+    /// ```
+    pub fn retain_names<F>(&mut self, mut pred: F) -> NamedMap<T, S>
+    where
+        F: FnMut(&str, &T) -> bool,
+    {
+        let hasher = self.0.hasher().clone();
+        let kept = HashMap::with_capacity_and_hasher(self.0.len(), hasher.clone());
+        let entries = core::mem::replace(&mut self.0, kept);
+        let mut removed = HashMap::with_hasher(hasher);
+        for (key, value) in entries {
+            if pred(&key, &value) {
+                self.0.insert(key, value);
+            } else {
+                removed.insert(key, value);
+            }
+        }
+        NamedMap(removed)
+    }
+
+    /// Like [`map_values`](Self::map_values), but borrows the map and
+    /// clones the keys.
+    pub fn map_values_ref<U, F>(&self, mut f: F) -> NamedMap<U, S>
+    where
+        F: FnMut(&str, &T) -> U,
+    {
+        let mut map = HashMap::with_capacity_and_hasher(self.0.len(), self.0.hasher().clone());
+        for (key, value) in &self.0 {
+            map.insert(key.clone(), f(key, value));
+        }
+        NamedMap(map)
+    }
+
+    /// Splits the map into the entries for which `pred(key, value)` returns
+    /// `true` and those for which it returns `false`, moving the values.
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let ports: NamedMap<u16> = [("http".to_string(), 80), ("app".to_string(), 8080)]
+    ///     .into_iter()
+    ///     .collect();
+    /// let (privileged, other) = ports.partition(|_, port| *port < 1024);
+    /// assert!(privileged.contains_key("http"));
+    /// assert!(other.contains_key("app"));
+    /// ```
+    pub fn partition<F>(mut self, pred: F) -> (NamedMap<T, S>, NamedMap<T, S>)
+    where
+        F: FnMut(&str, &T) -> bool,
+    {
+        let rejected = self.retain_names(pred);
+        (self, rejected)
+    }
+
+    /// Like [`partition`](Self::partition), but borrows the map: the two
+    /// halves hold references to its values. Use
+    /// [`map_values`](Self::map_values) with `Clone::clone` for owned copies.
+    pub fn partition_ref<F>(&self, mut pred: F) -> (NamedMap<&T, S>, NamedMap<&T, S>)
+    where
+        F: FnMut(&str, &T) -> bool,
+    {
+        let mut matching = HashMap::with_hasher(self.0.hasher().clone());
+        let mut rest = HashMap::with_hasher(self.0.hasher().clone());
+        for (key, value) in &self.0 {
+            if pred(key, value) {
+                matching.insert(key.clone(), value);
+            } else {
+                rest.insert(key.clone(), value);
+            }
+        }
+        (NamedMap(matching), NamedMap(rest))
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+pub(crate) use serde_impl::{NamedMapVisitor, Place};
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::adaptive::seq::{
+        cautious_capacity, deserialize_adaptive, next_named_value, Key, KeySeed, SeqItem,
+    };
+    use crate::adaptive::{DuplicateNameError, DuplicatePolicy};
+    use alloc::borrow::Cow;
+    use serde::{
+        de::{self, Deserializer, MapAccess, SeqAccess, Visitor},
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+
+    /// Where [`NamedMapVisitor`] puts the entries it reads: the plain map,
+    /// or one that keeps something next to each value, such as how the
+    /// entry was written or where.
+    pub(crate) trait Place {
+        type Value;
+        /// What is kept per entry besides its value.
+        type Meta;
+
+        /// The metadata of the entry written in the single-name form.
+        fn name_meta() -> Self::Meta;
+
+        /// Builds the value of an entry written as a bare name.
+        fn from_name<E: de::Error>(name: &str) -> Result<Self::Value, E>;
+
+        /// Reads the next element of the array form. `nullable` turns an
+        /// inline `null` value into a bare name.
+        #[allow(clippy::type_complexity)]
+        fn next_item<'de, A>(
+            seq: &mut A,
+            nullable: bool,
+        ) -> Result<Option<(SeqItem<Self::Value>, Self::Meta)>, A::Error>
+        where
+            A: SeqAccess<'de>,
+            Self::Value: Deserialize<'de>;
+
+        /// Reads the value of the object-form entry `name`. `nullable`
+        /// turns a `null` value into `T::from_name(name)`.
+        fn next_value<'de, A>(
+            access: &mut A,
+            name: &str,
+            nullable: bool,
+        ) -> Result<(Self::Value, Self::Meta), A::Error>
+        where
+            A: MapAccess<'de>,
+            Self::Value: Deserialize<'de>;
+
+        fn clear(&mut self);
+
+        fn reserve(&mut self, additional: usize);
+
+        fn contains_key(&self, name: &str) -> bool;
+
+        #[cfg(feature = "tracing")]
+        fn len(&self) -> usize;
+
+        /// Inserts an entry, the `index`-th of its list, applying `policy`
+        /// if `name` is already present.
+        fn insert(
+            &mut self,
+            policy: DuplicatePolicy,
+            name: String,
+            value: Self::Value,
+            meta: Self::Meta,
+            index: usize,
+        ) -> Result<(), DuplicateNameError>;
+    }
+
+    impl<T: FromName, S: BuildHasher> Place for HashMap<String, T, S> {
+        type Value = T;
+        type Meta = ();
+
+        fn name_meta() {}
+
+        fn from_name<E: de::Error>(name: &str) -> Result<T, E> {
+            Ok(T::from_name(name))
+        }
+
+        fn next_item<'de, A>(
+            seq: &mut A,
+            nullable: bool,
+        ) -> Result<Option<(SeqItem<T>, ())>, A::Error>
+        where
+            A: SeqAccess<'de>,
+            T: Deserialize<'de>,
+        {
+            let item = if nullable {
+                seq.next_element::<SeqItem<Option<T>>>()?
+                    .map(SeqItem::into_nullable_item)
+            } else {
+                seq.next_element::<SeqItem<T>>()?
+            };
+            Ok(item.map(|item| (item, ())))
+        }
+
+        fn next_value<'de, A>(
+            access: &mut A,
+            name: &str,
+            nullable: bool,
+        ) -> Result<(T, ()), A::Error>
+        where
+            A: MapAccess<'de>,
+            T: Deserialize<'de>,
+        {
+            let value = if nullable {
+                next_named_value::<_, Option<T>>(access, name)?
+                    .unwrap_or_else(|| T::from_name(name))
+            } else {
+                next_named_value(access, name)?
+            };
+            Ok((value, ()))
+        }
+
+        fn clear(&mut self) {
+            HashMap::clear(self);
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            HashMap::reserve(self, additional);
+        }
+
+        fn contains_key(&self, name: &str) -> bool {
+            HashMap::contains_key(self, name)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn len(&self) -> usize {
+            HashMap::len(self)
+        }
+
+        fn insert(
+            &mut self,
+            policy: DuplicatePolicy,
+            name: String,
+            value: T,
+            _meta: (),
+            index: usize,
+        ) -> Result<(), DuplicateNameError> {
+            policy.insert(self, name, value, index)
+        }
+    }
+
+    /// Visitor that handles both array-of-strings and object formats.
+    ///
+    /// Fills `place` (cleared first) rather than returning a new map, so
+    /// that `deserialize_in_place` can reuse its allocation.
+    #[derive(Debug)]
+    pub(crate) struct NamedMapVisitor<'a, P> {
+        pub(crate) place: &'a mut P,
+        pub(crate) policy: DuplicatePolicy,
+        /// How empty and whitespace-only names are handled.
+        pub(crate) names: NamePolicy,
+        /// Whether `null` values stand for `T::from_name(key)`.
+        pub(crate) nullable: bool,
+        /// Whether the input can be driven with `deserialize_any`.
+        pub(crate) self_describing: bool,
+    }
+
+    impl<'de, P> Visitor<'de> for NamedMapVisitor<'_, P>
+    where
+        P: Place,
+        P::Value: Deserialize<'de>,
+    {
+        type Value = ();
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a map (e.g., {{ \"a\": {{...}} }}), a sequence of names and single-key maps (e.g., [\"a\", {{ \"b\": {{...}} }}]), or a single name (e.g., \"a\")")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            self.visit_string(v.to_string())
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::trace_span!("named_map.deserialize", form = "name").entered();
+            self.place.clear();
+            let name = match self.names.check(Cow::Owned(v)) {
+                Ok(Some(name)) => name.into_owned(),
+                Ok(None) => return Ok(()),
+                Err(name) => {
+                    return Err(de::Error::invalid_value(de::Unexpected::Str(&name), &"a non-empty name"))
+                }
+            };
+            let value = P::from_name(&name)?;
+            // `KeepLast` never fails.
+            let _ = self
+                .place
+                .insert(DuplicatePolicy::KeepLast, name, value, P::name_meta(), 0);
+            #[cfg(feature = "tracing")]
+            tracing::trace!(entries = 1, "deserialized");
+            Ok(())
+        }
+
+        /// RON (with `implicit_some`, or an explicit `Some(...)`) may present
+        /// the map wrapped in an option; the wrapper carries no meaning here.
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::trace_span!("named_map.deserialize", form = "seq").entered();
+            let map = self.place;
+            map.clear();
+            map.reserve(cautious_capacity::<P::Value>(seq.size_hint()));
+            let mut index = 0;
+            while let Some((item, meta)) = P::next_item(&mut seq, self.nullable)? {
+                if let Some(item) = item
+                    .check_name(self.names, index)
+                    .map_err(de::Error::custom)?
+                {
+                    let (name, value) = item.into_entry_with(P::from_name)?;
+                    map.insert(self.policy, name, value, meta, index)
+                        .map_err(de::Error::custom)?;
+                }
+                index += 1;
+            }
+            #[cfg(feature = "tracing")]
+            tracing::trace!(entries = map.len(), "deserialized");
+            Ok(())
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::trace_span!("named_map.deserialize", form = "map").entered();
+            // Driven by hand (rather than via `HashMap`'s own impl) so that a
+            // repeated key is reported instead of silently keeping the last value.
+            self.place.clear();
+            self.place
+                .reserve(cautious_capacity::<P::Value>(access.size_hint()));
+            let seed = KeySeed {
+                self_describing: self.self_describing,
+            };
+            while let Some(Key(name)) = access.next_key_seed(seed)? {
+                let name = match self.names.check(name) {
+                    Ok(Some(name)) => name,
+                    Ok(None) => {
+                        access.next_value::<de::IgnoredAny>()?;
+                        continue;
+                    }
+                    Err(name) => {
+                        return Err(de::Error::custom(format_args!("empty key {:?}", name)))
+                    }
+                };
+                if self.place.contains_key(&name) {
+                    return Err(de::Error::custom(format_args!("duplicate key {:?}", name)));
+                }
+                let (value, meta) = P::next_value(&mut access, &name, self.nullable)?;
+                // `KeepLast` never fails.
+                let _ =
+                    self.place
+                        .insert(DuplicatePolicy::KeepLast, name.into_owned(), value, meta, 0);
+            }
+            #[cfg(feature = "tracing")]
+            tracing::trace!(entries = self.place.len(), "deserialized");
+            Ok(())
+        }
+    }
+
+    impl<T, H> Serialize for NamedMap<T, H>
+    where
+        T: Serialize,
+    {
+        /// Serializes as a JSON/TOML/YAML object (never as an array).
+        /// This ensures canonical, lossless output.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            // Like `HashMap`'s own impl, which `hashbrown` only has behind a feature.
+            serializer.collect_map(&self.0)
+        }
+    }
+
+    impl<'de, T, S> Deserialize<'de> for NamedMap<T, S>
+    where
+        T: Deserialize<'de> + FromName + Clone,
+        S: BuildHasher + Default,
+    {
+        /// Deserializes from either:
+        /// - An object (detailed form); repeated keys are rejected
+        /// - An array of strings (simple form); repeated names are rejected
+        /// - A single non-empty string, as a one-entry map
+        ///
+        /// Non-human-readable formats (bincode, postcard, ...) only accept the
+        /// object form, matching what `Serialize` emits.
+        ///
+        /// See [`NamedMap::deserialize_with_policy`] for other duplicate handling.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Self::deserialize_with_policy(deserializer, DuplicatePolicy::Error)
+        }
+
+        /// Clears `place` and refills it, reusing its allocation.
+        fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let self_describing = deserializer.is_human_readable();
+            deserialize_adaptive(
+                deserializer,
+                NamedMapVisitor {
+                    place: &mut place.0,
+                    policy: DuplicatePolicy::Error,
+                    names: NamePolicy::RejectEmpty,
+                    nullable: false,
+                    self_describing,
+                },
+            )
+        }
+    }
+
+    impl<T, S> NamedMap<T, S>
+    where
+        T: FromName + Clone,
+        S: BuildHasher + Default,
+    {
+        /// Deserializes like the `Deserialize` impl, but handles names repeated
+        /// in the array form according to `policy`.
+        ///
+        /// Repeated keys in the object form are always rejected.
+        pub fn deserialize_with_policy<'de, D>(
+            deserializer: D,
+            policy: DuplicatePolicy,
+        ) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de>,
+        {
+            let self_describing = deserializer.is_human_readable();
+            let mut map = HashMap::default();
+            deserialize_adaptive(
+                deserializer,
+                NamedMapVisitor {
+                    place: &mut map,
+                    policy,
+                    names: NamePolicy::RejectEmpty,
+                    nullable: false,
+                    self_describing,
+                },
+            )?;
+            Ok(NamedMap(map))
+        }
+
+        /// Deserializes like the `Deserialize` impl, but treats a `null` value
+        /// in the object form (`{ "logger": null }`) as `T::from_name(key)`.
+        ///
+        /// Use as `#[serde(deserialize_with = "NamedMap::deserialize_nullable")]`.
+        /// Formats without null (such as TOML) behave exactly as usual.
+        pub fn deserialize_nullable<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de>,
+        {
+            // Binary formats carry exactly what `Serialize` wrote: plain `T` values.
+            let nullable = deserializer.is_human_readable();
+            let mut map = HashMap::default();
+            deserialize_adaptive(
+                deserializer,
+                NamedMapVisitor {
+                    place: &mut map,
+                    policy: DuplicatePolicy::Error,
+                    names: NamePolicy::RejectEmpty,
+                    nullable,
+                    self_describing: nullable,
+                },
+            )?;
+            Ok(NamedMap(map))
+        }
+
+        /// Like the `Deserialize` impl, but always accepts every form, even from
+        /// a non-human-readable (yet self-describing) format such as MessagePack.
+        #[cfg(any(feature = "rmp", feature = "cbor"))]
+        pub(crate) fn deserialize_self_describing<'de, D>(
+            deserializer: D,
+        ) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de>,
+        {
+            let mut map = HashMap::default();
+            deserializer.deserialize_any(NamedMapVisitor {
+                place: &mut map,
+                policy: DuplicatePolicy::Error,
+                names: NamePolicy::RejectEmpty,
+                nullable: false,
+                self_describing: true,
+            })?;
+            Ok(NamedMap(map))
+        }
+
+        /// [`DuplicatePolicy::KeepFirst`] shorthand for
+        /// `#[serde(deserialize_with = "NamedMap::deserialize_keep_first")]`.
+        pub fn deserialize_keep_first<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de>,
+        {
+            Self::deserialize_with_policy(deserializer, DuplicatePolicy::KeepFirst)
+        }
+
+        /// [`DuplicatePolicy::KeepLast`] shorthand for
+        /// `#[serde(deserialize_with = "NamedMap::deserialize_keep_last")]`.
+        pub fn deserialize_keep_last<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de>,
+        {
+            Self::deserialize_with_policy(deserializer, DuplicatePolicy::KeepLast)
+        }
+
+        /// Deserializes like the `Deserialize` impl, but handles empty and
+        /// whitespace-only names (array form) and keys (object form)
+        /// according to `names`.
+        pub fn deserialize_with_name_policy<'de, D>(
+            deserializer: D,
+            names: NamePolicy,
+        ) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de>,
+        {
+            let self_describing = deserializer.is_human_readable();
+            let mut map = HashMap::default();
+            deserialize_adaptive(
+                deserializer,
+                NamedMapVisitor {
+                    place: &mut map,
+                    policy: DuplicatePolicy::Error,
+                    names,
+                    nullable: false,
+                    self_describing,
+                },
+            )?;
+            Ok(NamedMap(map))
+        }
+
+        /// [`NamePolicy::TrimAndSkipEmpty`] shorthand for
+        /// `#[serde(deserialize_with = "NamedMap::deserialize_trim_names")]`.
+        pub fn deserialize_trim_names<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de>,
+        {
+            Self::deserialize_with_name_policy(deserializer, NamePolicy::TrimAndSkipEmpty)
+        }
+    }
+}
+
+// === JSON-SPECIFIC CONVENIENCE METHODS ===
+
+#[cfg(feature = "serde_json")]
+impl<T, S> NamedMap<T, S>
+where
+    T: serde::Serialize,
+{
+    /// Serialize this map to a `serde_json::Value`.
+    ///
+    /// Equivalent to `serde_json::to_value(&map)`, but with explicit intent.
+    pub fn to_json_value(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(&self.0)
+    }
+
+    /// Serialize this map to a compact JSON string.
+    pub fn to_json_string(&self) -> serde_json::Result<String> {
+        serde_json::to_string(&self.0)
+    }
+
+    /// Serialize this map to an indented, human-readable JSON string.
+    pub fn to_json_string_pretty(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&self.0)
+    }
+
+    /// Serialize this map as compact JSON into `writer` (a file, socket, ...).
+    ///
+    /// The writer is not buffered; wrap it in a `BufWriter` if needed.
+    pub fn to_json_writer<W: std::io::Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, &self.0)
+    }
+
+    /// Like [`to_json_writer`](Self::to_json_writer), with indented output.
+    pub fn to_json_writer_pretty<W: std::io::Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer_pretty(writer, &self.0)
+    }
+
+    /// Serialize this map as an array of objects sorted by name, each
+    /// value with its name injected as a `"name"` field:
+    /// `{"logger": {"version": "1"}}` becomes `[{"name": "logger", "version": "1"}]`.
+    ///
+    /// Fails if a value does not serialize to a JSON object, or already has
+    /// a `name` field other than its key.
+    pub fn to_named_array(&self) -> serde_json::Result<serde_json::Value> {
+        use serde::ser::Error as _;
+        use serde_json::Value;
+
+        let mut entries = Vec::with_capacity(self.len());
+        for (name, value) in self.iter_sorted() {
+            let Value::Object(fields) = serde_json::to_value(value)? else {
+                return Err(serde_json::Error::custom(format_args!(
+                    "value of {:?} is not a JSON object", name
+                )));
+            };
+            if let Some(existing) = fields.get("name").filter(|existing| *existing != name.as_str()) {
+                return Err(serde_json::Error::custom(format_args!(
+                    "value of {:?} already has a conflicting name {}", name, existing
+                )));
+            }
+            let mut object = serde_json::Map::with_capacity(fields.len() + 1);
+            object.insert("name".to_string(), Value::String(name.clone()));
+            object.extend(fields.into_iter().filter(|(field, _)| field != "name"));
+            entries.push(Value::Object(object));
+        }
+        Ok(Value::Array(entries))
+    }
+
+    /// Like [`to_named_array`](Self::to_named_array), as a compact JSON string.
+    pub fn to_named_array_string(&self) -> serde_json::Result<String> {
+        serde_json::to_string(&self.to_named_array()?)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T, S> NamedMap<T, S>
+where
+    T: serde::Serialize + FromName + PartialEq,
+{
+    /// Serialize this map to a `serde_json::Value`, preferring the simple form.
+    ///
+    /// Emits a sorted array of names when [`to_simple_form`](Self::to_simple_form)
+    /// succeeds, and the detailed object otherwise.
+    pub fn to_json_value_adaptive(&self) -> serde_json::Result<serde_json::Value> {
+        match self.to_simple_form() {
+            Some(names) => serde_json::to_value(names),
+            None => self.to_json_value(),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T, S> NamedMap<T, S>
+where
+    T: for<'de> serde::Deserialize<'de> + FromName + Clone,
+    S: BuildHasher + Default,
+{
+    /// Parse a `NamedMap` from a `serde_json::Value`.
+    ///
+    /// Supports the same dual formats as the general `Deserialize` impl:
+    /// - Object: `{ "a": {...} }`
+    /// - Array: `["a", "b"]`, optionally mixed with single-key objects
+    ///   (`["a", { "b": {...} }]`)
+    /// - String: `"a"`, as a one-entry map
+    ///
+    /// Unlike the strict `Deserialize` impl, `null` entries
+    /// (`{ "a": null }`) are accepted and become `T::from_name(key)`.
+    pub fn from_json_value(value: serde_json::Value) -> serde_json::Result<Self> {
+        Self::deserialize_nullable(value)
+    }
+
+    /// Parse a `NamedMap` from a JSON string.
+    ///
+    /// Useful for config loading or API parsing. The text is parsed directly
+    /// (not through `serde_json::Value`), so repeated object keys are caught.
+    pub fn from_json_str(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
+
+    /// Parse a `NamedMap` from a JSON stream, accepting both forms.
+    ///
+    /// Reads incrementally rather than into a `String` first; wrap unbuffered
+    /// sources (such as a `File`) in a `BufReader`.
+    pub fn from_json_reader<R: std::io::Read>(reader: R) -> serde_json::Result<Self> {
+        serde_json::from_reader(reader)
+    }
+
+    /// Like [`from_json_str`](Self::from_json_str), but the error carries
+    /// the path of the failing value (e.g. `http.version`).
+    #[cfg(feature = "path-to-error")]
+    pub fn from_json_str_with_path(s: &str) -> Result<Self, crate::de::PathError> {
+        crate::de::with_path(&mut serde_json::Deserializer::from_str(s))
+    }
+}
+
+/// Why [`NamedMap`]'s `FromStr` impl rejected a string.
+#[cfg(feature = "serde_json")]
+#[derive(Debug)]
+pub enum ParseNamedMapError {
+    /// The string started with `{` or `[` but is not a valid map.
+    Json(serde_json::Error),
+    /// The comma-separated list repeats a name.
+    Names(super::DuplicateNameError),
+}
+
+#[cfg(feature = "serde_json")]
+impl fmt::Display for ParseNamedMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseNamedMapError::Json(err) => write!(f, "invalid JSON: {}", err),
+            ParseNamedMapError::Names(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl std::error::Error for ParseNamedMapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseNamedMapError::Json(err) => Some(err),
+            ParseNamedMapError::Names(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> core::str::FromStr for NamedMap<T>
+where
+    T: for<'de> serde::Deserialize<'de> + FromName + Clone,
+{
+    type Err = ParseNamedMapError;
+
+    /// Parses JSON in any adaptive form, falling back to a comma-separated
+    /// list of names (see [`from_comma_separated`](NamedMap::from_comma_separated)).
+    ///
+    /// A string starting with `{` or `[` is only ever parsed as JSON, so a
+    /// typo there reports the JSON error instead of becoming a strange name.
+    ///
+    /// ```rust
+    /// # #[derive(Clone, serde::Deserialize)]
+    /// # struct Plugin { enabled: bool }
+    /// # impl flexicon::adaptive::FromName for Plugin {
+    /// #     fn from_name(_name: &str) -> Self { Self { enabled: true } }
+    /// # }
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let map: NamedMap<Plugin> = "logger, http".parse().unwrap();
+    /// assert_eq!(map.len(), 2);
+    /// let map: NamedMap<Plugin> = r#"{"http": {"enabled": false}}"#.parse().unwrap();
+    /// assert!(!map["http"].enabled);
+    /// assert!(r#"{"http": "#.parse::<NamedMap<Plugin>>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim_start().starts_with(['{', '[']) {
+            return Self::from_json_str(s).map_err(ParseNamedMapError::Json);
+        }
+        match Self::from_json_str(s) {
+            Ok(map) => Ok(map),
+            Err(_) => Self::from_comma_separated(s).map_err(ParseNamedMapError::Names),
+        }
+    }
+}
+
+// === JSON5 PARSING ===
+
+#[cfg(feature = "json5")]
+impl<T, S> NamedMap<T, S>
+where
+    T: for<'de> serde::Deserialize<'de> + FromName + Clone,
+    S: BuildHasher + Default,
+{
+    /// Parse a `NamedMap` from JSON5: JSON plus comments, trailing commas,
+    /// unquoted keys and single-quoted strings.
+    ///
+    /// Accepts the same forms as [`from_json_str`](Self::from_json_str);
+    /// serialization stays plain JSON.
+    pub fn from_json5_str(s: &str) -> Result<Self, json5::Error> {
+        json5::from_str(s)
+    }
+}
+
+// === MESSAGEPACK CONVENIENCE METHODS ===
+
+#[cfg(feature = "rmp")]
+impl<T, S> NamedMap<T, S>
+where
+    T: serde::Serialize,
+{
+    /// Serialize this map to MessagePack, with structs in values packed as
+    /// arrays (`rmp_serde`'s compact mode).
+    pub fn to_msgpack_vec(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec(&self.0)
+    }
+
+    /// Serialize this map to MessagePack, with structs in values packed as
+    /// maps keyed by field name (more robust to field reordering).
+    pub fn to_msgpack_vec_named(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(&self.0)
+    }
+}
+
+#[cfg(feature = "rmp")]
+impl<T, S> NamedMap<T, S>
+where
+    T: for<'de> serde::Deserialize<'de> + FromName + Clone,
+    S: BuildHasher + Default,
+{
+    /// Parse a `NamedMap` from MessagePack written in either mode.
+    ///
+    /// MessagePack is self-describing, so a packed array of names (or a
+    /// mixed array) is accepted as well as a map.
+    pub fn from_msgpack_slice(bytes: &[u8]) -> Result<Self, rmp_serde::decode::Error> {
+        Self::deserialize_self_describing(&mut rmp_serde::Deserializer::from_read_ref(bytes))
+    }
+}
+
+// === CBOR CONVENIENCE METHODS ===
+
+/// Adapter letting `ciborium` (whose deserializer is private) drive
+/// [`NamedMap::deserialize_self_describing`].
+#[cfg(feature = "cbor")]
+struct SelfDescribing<T, S>(NamedMap<T, S>);
+
+#[cfg(feature = "cbor")]
+impl<'de, T, S> serde::Deserialize<'de> for SelfDescribing<T, S>
+where
+    T: serde::Deserialize<'de> + FromName + Clone,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        NamedMap::deserialize_self_describing(deserializer).map(SelfDescribing)
+    }
+}
+
+/// Entries in CBOR deterministic order (RFC 8949 §4.2.1): for text keys,
+/// shorter names first, then bytewise.
+#[cfg(feature = "cbor")]
+struct DeterministicEntries<'a, T>(Vec<(&'a String, &'a T)>);
+
+#[cfg(feature = "cbor")]
+impl<T: serde::Serialize> serde::Serialize for DeterministicEntries<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.0.iter().copied())
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl<T, S> NamedMap<T, S>
+where
+    T: serde::Serialize,
+{
+    /// Serialize this map as CBOR into `writer`.
+    pub fn to_cbor_writer<W: std::io::Write>(
+        &self,
+        writer: W,
+    ) -> Result<(), ciborium::ser::Error<std::io::Error>> {
+        ciborium::ser::into_writer(&self.0, writer)
+    }
+
+    /// Like [`to_cbor_writer`](Self::to_cbor_writer), with entries in CBOR
+    /// deterministic key order, so equal maps encode to identical bytes.
+    ///
+    /// Only the entry order is fixed; maps nested inside values keep their
+    /// own serialization order.
+    pub fn to_cbor_writer_deterministic<W: std::io::Write>(
+        &self,
+        writer: W,
+    ) -> Result<(), ciborium::ser::Error<std::io::Error>> {
+        let mut entries: Vec<_> = self.0.iter().collect();
+        entries.sort_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        ciborium::ser::into_writer(&DeterministicEntries(entries), writer)
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl<T, S> NamedMap<T, S>
+where
+    T: for<'de> serde::Deserialize<'de> + FromName + Clone,
+    S: BuildHasher + Default,
+{
+    /// Parse a `NamedMap` from a CBOR stream.
+    ///
+    /// CBOR is self-describing, so an array of names (or a mixed array) is
+    /// accepted as well as a map. Keys must be text strings; byte-string
+    /// keys are rejected.
+    pub fn from_cbor_reader<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, ciborium::de::Error<std::io::Error>> {
+        ciborium::de::from_reader::<SelfDescribing<T, S>, _>(reader).map(|map| map.0)
+    }
+
+    /// Parse a `NamedMap` from CBOR bytes (see [`from_cbor_reader`](Self::from_cbor_reader)).
+    pub fn from_cbor_slice(bytes: &[u8]) -> Result<Self, ciborium::de::Error<std::io::Error>> {
+        Self::from_cbor_reader(bytes)
+    }
+}
+
+// === TOML-SPECIFIC CONVENIENCE METHODS ===
+
+#[cfg(feature = "toml")]
+impl<T, S> NamedMap<T, S>
+where
+    T: serde::Serialize,
+{
+    /// Entries sorted by name, so that TOML output is deterministic
+    /// (TOML files are usually committed to version control).
+    fn sorted_entries(&self) -> std::collections::BTreeMap<&str, &T> {
+        self.0.iter().map(|(name, value)| (name.as_str(), value)).collect()
+    }
+
+    /// Serialize this map to a TOML document, one table per entry,
+    /// with entries sorted by name.
+    pub fn to_toml_string(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string(&self.sorted_entries())
+    }
+
+    /// Like [`to_toml_string`](Self::to_toml_string), with arrays spread
+    /// over multiple lines.
+    pub fn to_toml_string_pretty(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(&self.sorted_entries())
+    }
+}
+
+#[cfg(feature = "toml")]
+impl<T, S> NamedMap<T, S>
+where
+    T: for<'de> serde::Deserialize<'de> + FromName + Clone,
+    S: BuildHasher + Default,
+{
+    /// Parse a `NamedMap` from a `toml::Value`, in either form:
+    /// - Table: `{ a = {...} }`
+    /// - Array: `["a", "b"]`, optionally mixed with single-key tables
+    /// - String: `"a"`, as a one-entry map
+    pub fn from_toml_value(value: toml::Value) -> Result<Self, toml::de::Error> {
+        serde::Deserialize::deserialize(value)
+    }
+
+    /// Parse a `NamedMap` from a TOML document whose top-level tables are the
+    /// entries (`[logger]`, `[http]`, ...).
+    ///
+    /// A TOML document is always a table; for the array form, parse the
+    /// enclosing document and use [`from_toml_value`](Self::from_toml_value)
+    /// on the field, or embed the map in a `Deserialize` struct.
+    pub fn from_toml_str(s: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(s)
+    }
+}
+
+// === YAML-SPECIFIC CONVENIENCE METHODS ===
+
+#[cfg(feature = "yaml")]
+impl<T, S> NamedMap<T, S>
+where
+    T: serde::Serialize,
+{
+    /// Serialize this map to a YAML mapping.
+    pub fn to_yaml_string(&self) -> serde_yaml::Result<String> {
+        serde_yaml::to_string(&self.0)
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl<T, S> NamedMap<T, S>
+where
+    T: for<'de> serde::Deserialize<'de> + FromName + Clone,
+    S: BuildHasher + Default,
+{
+    /// Parse a `NamedMap` from a `serde_yaml::Value`, in either form:
+    /// - Mapping: `{ a: {...} }`
+    /// - Sequence: `[a, b]`, optionally mixed with single-key mappings
+    /// - Scalar: `a`, as a one-entry map
+    ///
+    /// A `null` (an empty document or `~`) is an empty map. Non-string
+    /// mapping keys are rejected with an error, as are repeated keys.
+    pub fn from_yaml_value(value: serde_yaml::Value) -> serde_yaml::Result<Self> {
+        if value.is_null() {
+            return Ok(Self::default());
+        }
+        serde::Deserialize::deserialize(value)
+    }
+
+    /// Parse a `NamedMap` from a single YAML document (see
+    /// [`from_yaml_value`](Self::from_yaml_value)); multiple documents are
+    /// rejected.
+    pub fn from_yaml_str(s: &str) -> serde_yaml::Result<Self> {
+        Self::from_yaml_value(serde_yaml::from_str(s)?)
+    }
+}
+
+// === RON-SPECIFIC CONVENIENCE METHODS ===
+
+#[cfg(feature = "ron")]
+impl<T, S> NamedMap<T, S>
+where
+    T: serde::Serialize,
+{
+    /// Serialize this map to a compact RON map.
+    pub fn to_ron_string(&self) -> ron::Result<String> {
+        ron::to_string(&self.0)
+    }
+
+    /// Serialize this map to an indented RON map.
+    pub fn to_ron_string_pretty(&self) -> ron::Result<String> {
+        ron::ser::to_string_pretty(&self.0, ron::ser::PrettyConfig::default())
+    }
+}
+
+#[cfg(feature = "ron")]
+impl<T, S> NamedMap<T, S>
+where
+    T: for<'de> serde::Deserialize<'de> + FromName + Clone,
+    S: BuildHasher + Default,
+{
+    /// Parse a `NamedMap` from RON, in either form:
+    /// - Map: `{ "a": (...) }` (values may carry their struct name, `Item(...)`)
+    /// - List: `["a", "b"]`, optionally mixed with single-key maps
+    /// - String: `"a"`, as a one-entry map
+    ///
+    /// Struct-like maps (`(a: (...))`) are read as maps, and a redundant
+    /// `Some(...)` around the whole map is ignored. `Option` fields inside
+    /// values follow RON's usual rules (`Some(..)` unless `implicit_some`).
+    pub fn from_ron_str(s: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(s)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+
+    #[cfg(feature = "serde")]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct TestItem {
+        value: String,
+        optional: bool,
+    }
+
+    #[cfg(feature = "serde")]
+    impl FromName for TestItem {
+        fn from_name(name: &str) -> Self {
+            Self {
+                value: format!("from_name({})", name),
+                optional: false,
+            }
+        }
+    }
+
+    #[test]
+    fn test_new_is_empty() {
+        let map: NamedMap<()> = NamedMap::new();
+        assert!(map.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_insert_and_get() {
+        let mut map = NamedMap::new();
+        map.insert("key1".to_string(), TestItem {
+            value: "value1".to_string(),
+            optional: true,
+        });
+        assert_eq!(map["key1"].value, "value1");
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut map: NamedMap<u32> = NamedMap::with_capacity(100);
+        assert!(map.capacity() >= 100);
+        map.insert("a".to_string(), 1);
+        map.shrink_to_fit();
+        assert!(map.capacity() < 100);
+        map.reserve(50);
+        assert!(map.capacity() >= 51);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_deserialize_large_document() {
+        let document: serde_json::Map<String, serde_json::Value> = (0..1000)
+            .map(|i| {
+                let value = serde_json::json!({"value": i.to_string(), "optional": false});
+                (format!("device{}", i), value)
+            })
+            .collect();
+        let map: NamedMap<TestItem> = serde_json::from_value(document.into()).unwrap();
+        assert_eq!(map.len(), 1000);
+        assert!(map.capacity() >= map.len());
+
+        // The binary form carries the exact length up front.
+        let bytes = bincode::serialize(&map).unwrap();
+        let decoded: NamedMap<TestItem> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded, map);
+        assert!(decoded.capacity() >= 1000);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_hostile_length_prefix() {
+        // A map claiming 2^40 entries, then nothing: the reservation is
+        // capped instead of attempting a multi-terabyte allocation.
+        let bytes = (1u64 << 40).to_le_bytes();
+        assert!(bincode::deserialize::<NamedMap<TestItem>>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_insert_returns_previous() {
+        let mut map: NamedMap<u32> = NamedMap::new();
+        assert_eq!(map.insert("a".to_string(), 1), None);
+        assert_eq!(map.insert("a".to_string(), 2), Some(1));
+        assert_eq!(map["a"], 2);
+    }
+
+    #[test]
+    fn test_try_insert() {
+        let mut map: NamedMap<u32> = NamedMap::new();
+        *map.try_insert("a".to_string(), 1).unwrap() += 10;
+
+        let err = map.try_insert("a".to_string(), 2).unwrap_err();
+        assert_eq!(err, OccupiedError { key: "a".to_string(), value: 2 });
+        assert_eq!(err.to_string(), "duplicate key \"a\"");
+        assert_eq!(map["a"], 11);
+    }
+
+    #[test]
+    fn test_custom_hasher() {
+        let mut map = NamedMap::<u32, fxhash::FxBuildHasher>::default();
+        map.insert("a".to_string(), 1);
+        map.extend([("b".to_string(), 2)]);
+        let collected: NamedMap<u32, fxhash::FxBuildHasher> =
+            [("a".to_string(), 1), ("b".to_string(), 2)].into_iter().collect();
+        assert_eq!(map, collected);
+
+        let map = NamedMap::<u32, _>::with_capacity_and_hasher(8, fxhash::FxBuildHasher::default());
+        assert!(map.capacity() >= 8);
+        let inner: HashMap<String, u32, fxhash::FxBuildHasher> = map.into_inner();
+        assert!(inner.is_empty());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_custom_hasher_serde_roundtrip() {
+        type FxNamedMap<T> = NamedMap<T, fxhash::FxBuildHasher>;
+
+        let names: FxNamedMap<TestItem> = serde_json::from_str(r#"["a", "b"]"#).unwrap();
+        assert_eq!(names, FxNamedMap::from(vec!["a".to_string(), "b".to_string()]));
+
+        let mut map = FxNamedMap::<TestItem>::from_json_str(
+            r#"{"a": {"value": "x", "optional": true}, "b": {"value": "y", "optional": false}}"#,
+        )
+        .unwrap();
+        let restored = FxNamedMap::from_json_value(map.to_json_value().unwrap()).unwrap();
+        assert_eq!(restored, map);
+
+        let bytes = bincode::serialize(&map).unwrap();
+        assert_eq!(bincode::deserialize::<FxNamedMap<TestItem>>(&bytes).unwrap(), map);
+
+        let mut deserializer = serde_json::Deserializer::from_str(r#""c""#);
+        FxNamedMap::deserialize_in_place(&mut deserializer, &mut map).unwrap();
+        assert_eq!(map, FxNamedMap::from(vec!["c".to_string()]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deref_transparency() {
+        let mut map = NamedMap::new();
+        map.insert("test".to_string(), TestItem {
+            value: "ok".to_string(),
+            optional: false,
+        });
+        assert_eq!(map.get("test").unwrap().value, "ok");
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_json_roundtrip_array() {
+        let original: NamedMap<TestItem> = NamedMap::from(vec!["a".to_string(), "b".to_string()]);
+        let json_str = original.to_json_string().unwrap();
+        let restored = NamedMap::from_json_str(&json_str).unwrap();
+        assert_eq!(original, restored);
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_json_roundtrip_object() {
+        let mut original = NamedMap::new();
         original.insert("handler".to_string(), TestItem {
             value: "http".to_string(),
             optional: true,
         });
-        let json_str = original.to_json_string().unwrap();
-        let restored = NamedMap::from_json_str(&json_str).unwrap();
-        assert_eq!(original, restored);
+        let json_str = original.to_json_string().unwrap();
+        let restored = NamedMap::from_json_str(&json_str).unwrap();
+        assert_eq!(original, restored);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_toml_compatibility() {
+        // This test ensures the main serde path does NOT depend on serde_json.
+        // It verifies compatibility with TOML, commonly used in Arcella and walmq manifests.
+        let toml_str = r#"
+            logger = { value = "file", optional = true }
+            metrics = { value = "prom", optional = false }
+        "#;
+        let _map: NamedMap<TestItem> = toml::from_str(toml_str).unwrap();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_toml_mixed_array() {
+        #[derive(Deserialize)]
+        struct Manifest {
+            interfaces: NamedMap<TestItem>,
+        }
+
+        let toml_str = r#"
+            interfaces = ["logger", { http = { value = "0.2", optional = true } }]
+        "#;
+        let manifest: Manifest = toml::from_str(toml_str).unwrap();
+        assert_eq!(manifest.interfaces.len(), 2);
+        assert_eq!(manifest.interfaces["logger"], TestItem::from_name("logger"));
+        assert_eq!(manifest.interfaces["http"].value, "0.2");
+        assert!(manifest.interfaces["http"].optional);
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_json_mixed_array() {
+        let map: NamedMap<TestItem> = serde_json::from_str(
+            r#"["logger", {"http": {"value": "0.2", "optional": true}}]"#,
+        )
+        .unwrap();
+        assert_eq!(map["logger"], TestItem::from_name("logger"));
+        assert_eq!(map["http"].value, "0.2");
+
+        let restored = NamedMap::from_json_str(&map.to_json_string().unwrap()).unwrap();
+        assert_eq!(map, restored);
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_mixed_array_rejects_multi_key_object() {
+        let err = serde_json::from_str::<NamedMap<TestItem>>(
+            r#"[{"a": {"value": "x", "optional": false}, "b": {"value": "y", "optional": false}}]"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("single-key map"), "{}", err);
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_mixed_array_rejects_duplicate_names() {
+        let err = serde_json::from_str::<NamedMap<TestItem>>(
+            r#"["a", {"a": {"value": "x", "optional": false}}]"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("duplicate name \"a\""), "{}", err);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_simple_form() {
+        let mut map: NamedMap<TestItem> = NamedMap::from(vec!["b".to_string(), "a".to_string()]);
+        assert_eq!(map.to_simple_form(), Some(vec!["a".to_string(), "b".to_string()]));
+
+        map.get_mut("a").unwrap().optional = true;
+        assert_eq!(map.to_simple_form(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_names_and_insert_names() {
+        let from_slice = NamedMap::<TestItem>::from_names(&["a", "b"]);
+        let from_strings = NamedMap::<TestItem>::from_names(vec!["a".to_string(), "b".to_string()]);
+        let from_adapter = NamedMap::<TestItem>::from_names("a,b,a".split(','));
+        assert_eq!(from_slice, from_strings);
+        assert_eq!(from_slice, from_adapter);
+        assert_eq!(from_slice, NamedMap::from(vec!["a".to_string(), "b".to_string()]));
+
+        let mut map = from_slice;
+        map.get_mut("a").unwrap().optional = true;
+        let counts = map.insert_names(["a", "c", "", "d", "c", " "].iter().copied());
+        assert_eq!(
+            counts,
+            NameCounts {
+                added: 2,
+                present: 2,
+                skipped: 2
+            }
+        );
+        assert_eq!(map.len(), 4);
+        assert!(map["a"].optional);
+        assert_eq!(map["d"], TestItem::from_name("d"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ensure_names() {
+        let required = ["logger", "http"];
+
+        let mut empty: NamedMap<TestItem> = NamedMap::new();
+        assert_eq!(empty.missing_from(&required), ["logger", "http"]);
+        assert_eq!(empty.ensure_names(required), ["logger", "http"]);
+        assert_eq!(empty["http"], TestItem::from_name("http"));
+
+        let mut full: NamedMap<TestItem> = NamedMap::from(vec!["logger".to_string(), "http".to_string()]);
+        assert!(full.missing_from(&required).is_empty());
+        assert!(full.ensure_names(required).is_empty());
+
+        let mut partial: NamedMap<TestItem> = NamedMap::new();
+        partial.insert("http".to_string(), TestItem { value: "custom".to_string(), optional: true });
+        let names = vec!["logger".to_string(), "http".to_string(), "logger".to_string()];
+        assert_eq!(partial.missing_from(&names), ["logger"]);
+        assert_eq!(partial.ensure_names(&names), ["logger"]);
+        assert_eq!(partial.len(), 2);
+        assert!(partial.ensure_names(["", "  "]).is_empty());
+        assert_eq!(partial.len(), 2);
+        assert_eq!(partial["http"].value, "custom");
+    }
+
+    #[test]
+    fn test_contains_all_and_missing_names() {
+        let map: NamedMap<u16> = [("http", 80), ("https", 443)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect();
+        let none: [&str; 0] = [];
+
+        assert!(map.contains_all(none));
+        assert!(map.missing_names(none).is_empty());
+        assert!(map.contains_all(["https", "http"]));
+        assert!(map.missing_names(vec!["http".to_string()]).is_empty());
+        assert!(!map.contains_all(["http", "ssh"]));
+        assert_eq!(map.missing_names(["ssh", "http", "dns", "ssh"]), ["ssh", "dns"]);
+
+        assert_eq!(map.extra_names(&none), ["http", "https"]);
+        assert!(map.extra_names(&["https", "http", "ssh"]).is_empty());
+        let allowed = vec!["http".to_string()];
+        assert_eq!(map.extra_names(&allowed), ["https"]);
+        assert!(NamedMap::<u16>::new().extra_names(&none).is_empty());
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_to_json_value_adaptive() {
+        let mut map: NamedMap<TestItem> = NamedMap::from(vec!["b".to_string(), "a".to_string()]);
+        assert_eq!(map.to_json_value_adaptive().unwrap(), serde_json::json!(["a", "b"]));
+
+        map.get_mut("a").unwrap().optional = true;
+        let value = map.to_json_value_adaptive().unwrap();
+        assert!(value.is_object());
+        assert_eq!(NamedMap::from_json_value(value).unwrap(), map);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_collect_names_from_pipeline() {
+        let raw = "logger, , http,metrics";
+        let map: NamedMap<TestItem> = raw
+            .split(',')
+            .filter_map(|s| Some(s.trim()).filter(|s| !s.is_empty()))
+            .collect();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["http"], TestItem::from_name("http"));
+
+        let owned: NamedMap<TestItem> = vec!["a".to_string()].into_iter().collect();
+        assert_eq!(owned, NamedMap::from(vec!["a".to_string()]));
+    }
+
+    #[test]
+    fn test_collect_pairs_and_extend() {
+        let mut map: NamedMap<u32> = [("a", 1), ("b", 2), ("c", 3)]
+            .into_iter()
+            .filter_map(|(k, v)| if v == 2 { None } else { Some((k.to_string(), v)) })
+            .collect();
+        assert_eq!(map.len(), 2);
+        assert!(!map.contains_key("b"));
+
+        map.extend(vec![("c".to_string(), 30), ("d".to_string(), 4)]);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["c"], 30);
+        assert_eq!(map["d"], 4);
+    }
+
+    #[test]
+    fn test_map_values_changes_type() {
+        let ports: NamedMap<u16> = [("http".to_string(), 80), ("https".to_string(), 443)]
+            .into_iter()
+            .collect();
+        let secure = ports.map_values_ref(|name, port| name.ends_with('s') && *port == 443);
+        assert!(secure["https"]);
+        assert!(!secure["http"]);
+
+        let labels: NamedMap<String> = ports.map_values(|name, port| format!("{}:{}", name, port));
+        assert_eq!(labels["http"], "http:80");
+        assert_eq!(labels.len(), 2);
+    }
+
+    #[test]
+    fn test_retain_names() {
+        let sample: NamedMap<u16> = [("http", 80), ("https", 443), ("admin", 9000)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect();
+
+        let mut map = sample.clone();
+        assert!(map.retain_names(|_, _| true).is_empty());
+        assert_eq!(map, sample);
+
+        let mut map = sample.clone();
+        assert_eq!(map.retain_names(|_, _| false), sample);
+        assert!(map.is_empty());
+
+        let mut map = sample.clone();
+        let removed = map.retain_names(|name, port| name.starts_with("http") && *port < 443);
+        assert_eq!(map.keys().collect::<Vec<_>>(), ["http"]);
+        let mut removed: Vec<(String, u16)> = removed.into_iter().collect();
+        removed.sort();
+        assert_eq!(removed, [("admin".to_string(), 9000), ("https".to_string(), 443)]);
+    }
+
+    #[test]
+    fn test_partition() {
+        let sample: NamedMap<u16> = [("http", 80), ("https", 443), ("admin", 9000), ("app", 8080)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect();
+        let privileged = |_: &str, port: &u16| *port < 1024;
+
+        let (low, high) = sample.partition_ref(privileged);
+        assert_eq!(low.len() + high.len(), sample.len());
+        for (name, port) in sample.iter() {
+            assert_ne!(low.contains_key(name), high.contains_key(name));
+            let half = if privileged(name, port) { &low } else { &high };
+            assert_eq!(half[name.as_str()], port);
+        }
+
+        let (low, high) = sample.clone().partition(privileged);
+        assert_eq!(low.len() + high.len(), sample.len());
+        let mut keys: Vec<_> = low.keys().chain(high.keys()).cloned().collect();
+        keys.sort();
+        assert_eq!(keys, ["admin", "app", "http", "https"]);
+        assert_eq!(low, sample.partition_ref(privileged).0.map_values(|_, port| *port));
+        assert!(high.values().all(|port| *port >= 1024));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_map_values_after_deserialize() {
+        let map: NamedMap<TestItem> =
+            serde_json::from_str(r#"["a", {"b": {"value": "custom", "optional": true}}]"#).unwrap();
+        let resolved = map.map_values(|name, item| {
+            if item.optional {
+                item.value
+            } else {
+                format!("{}={}", name, item.value)
+            }
+        });
+        assert_eq!(resolved["a"], format!("a={}", TestItem::from_name("a").value));
+        assert_eq!(resolved["b"], "custom");
+    }
+
+    #[test]
+    fn test_sorted_iteration_is_stable() {
+        let names = ["delta", "alpha", "charlie", "bravo", "echo"];
+        let forward: NamedMap<usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.to_string(), i))
+            .collect();
+        let mut backward: NamedMap<usize> = NamedMap::with_capacity(64);
+        for (i, name) in names.iter().enumerate().rev() {
+            backward.insert(name.to_string(), i);
+        }
+        assert_eq!(forward, backward);
+
+        let expected = ["alpha", "bravo", "charlie", "delta", "echo"];
+        for map in [&forward, &backward] {
+            assert_eq!(map.keys_sorted().collect::<Vec<_>>(), expected);
+            assert_eq!(
+                map.iter_sorted().map(|(k, _)| k).collect::<Vec<_>>(),
+                expected
+            );
+            assert_eq!(
+                map.values_sorted_by_key().copied().collect::<Vec<_>>(),
+                [1, 3, 2, 0, 4]
+            );
+        }
+        assert_eq!(NamedMap::<usize>::new().iter_sorted().count(), 0);
+    }
+
+    #[test]
+    fn test_names() {
+        let empty: NamedMap<u16> = NamedMap::new();
+        assert!(empty.names().is_empty());
+        assert!(empty.names_unsorted().is_empty());
+        assert!(empty.into_names().is_empty());
+
+        let map: NamedMap<u16> = [("ssh", 22), ("http", 80), ("dns", 53)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect();
+        let mut unsorted = map.names_unsorted();
+        unsorted.sort();
+        assert_eq!(unsorted, map.names());
+        let names: Vec<String> = map.names().into_iter().map(String::from).collect();
+        assert_eq!(map.into_names(), names);
+        assert_eq!(names, ["dns", "http", "ssh"]);
+    }
+
+    #[test]
+    fn test_display_summary() {
+        let mut map: NamedMap<u16> = NamedMap::new();
+        assert_eq!(map.to_string(), "NamedMap{0 entries}");
+        assert_eq!(map.display_names().to_string(), "");
+
+        map.insert("logger".to_string(), 0);
+        assert_eq!(map.to_string(), "NamedMap{1 entry: logger}");
+        map.insert("http".to_string(), 80);
+        assert_eq!(map.to_string(), "NamedMap{2 entries: http, logger}");
+        assert_eq!(map.display_names().to_string(), "http, logger");
+
+        let mut map: NamedMap<u16> = NamedMap::new();
+        for i in 0..10 {
+            map.insert(format!("if{}", i), 0);
+        }
+        assert_eq!(
+            map.to_string(),
+            "NamedMap{10 entries: if0, if1, if2, if3, if4, if5, if6, if7, if8, if9}"
+        );
+        map.insert("if10".to_string(), 0);
+        map.insert("lo".to_string(), 0);
+        assert_eq!(
+            map.to_string(),
+            "NamedMap{12 entries: if0, if1, if10, if2, if3, if4, if5, if6, if7, if8, … and 2 more}"
+        );
+        assert!(map.display_names().to_string().ends_with("if9, lo"));
+    }
+
+    #[test]
+    fn test_hash_ignores_insertion_order() {
+        let hasher = DefaultHasher::default();
+        let forward: NamedMap<u16> = [("http", 80), ("https", 443), ("ssh", 22)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect();
+        let mut backward: NamedMap<u16> = NamedMap::with_capacity(64);
+        for (name, port) in [("ssh", 22), ("https", 443), ("http", 80)] {
+            backward.insert(name.to_string(), port);
+        }
+        assert_eq!(forward, backward);
+        assert_eq!(hasher.hash_one(&forward), hasher.hash_one(&backward));
+
+        let mut cache = HashMap::new();
+        cache.insert(forward.clone(), "built");
+        assert_eq!(cache.get(&backward), Some(&"built"));
+
+        let mut other_value = forward.clone();
+        other_value.insert("ssh".to_string(), 2222);
+        let mut other_key = forward.clone();
+        let port = other_key.remove("ssh").unwrap();
+        other_key.insert("sftp".to_string(), port);
+        let mut fewer = forward.clone();
+        fewer.remove("ssh");
+        for (a, b) in [
+            (&forward, &other_value),
+            (&forward, &other_key),
+            (&forward, &fewer),
+            (&fewer, &NamedMap::new()),
+        ] {
+            assert_ne!(hasher.hash_one(a), hasher.hash_one(b));
+        }
+    }
+
+    #[test]
+    fn test_eq_plain_maps() {
+        let map: NamedMap<u16> = [("http", 80), ("ssh", 22)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect();
+
+        let same = HashMap::from([("ssh".to_string(), 22), ("http".to_string(), 80)]);
+        assert_eq!(map, same);
+        assert_eq!(same, map);
+        let sorted = BTreeMap::from([("http".to_string(), 80), ("ssh".to_string(), 22)]);
+        assert_eq!(map, sorted);
+        assert_eq!(sorted, map);
+
+        for other in [
+            HashMap::from([("http".to_string(), 80)]),
+            HashMap::from([("http".to_string(), 80), ("ssh".to_string(), 2222)]),
+            HashMap::from([("http".to_string(), 80), ("sftp".to_string(), 22)]),
+            HashMap::from([
+                ("http".to_string(), 80),
+                ("ssh".to_string(), 22),
+                ("dns".to_string(), 53),
+            ]),
+        ] {
+            assert_ne!(map, other);
+            assert_ne!(other, map);
+            let other: BTreeMap<String, u16> = other.into_iter().collect();
+            assert_ne!(map, other);
+            assert_ne!(other, map);
+        }
+
+        // Only `T: PartialEq` is needed.
+        let floats: NamedMap<f64> = [("ratio".to_string(), 0.5)].into_iter().collect();
+        assert_eq!(floats, HashMap::from([("ratio".to_string(), 0.5)]));
+        assert_eq!(floats, floats.clone());
+    }
+
+    #[test]
+    fn test_plain_map_conversions() {
+        let sorted = BTreeMap::from([
+            ("http".to_string(), 80u16),
+            ("https".to_string(), 443),
+            ("ssh".to_string(), 22),
+        ]);
+        let map = NamedMap::<u16>::from(sorted);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["https"], 443);
+
+        let sorted = BTreeMap::from(map.clone());
+        assert_eq!(sorted.len(), 3);
+        assert_eq!(sorted.keys().next().map(String::as_str), Some("http"));
+        assert_eq!(sorted["ssh"], 22);
+
+        let plain = HashMap::from(map);
+        assert_eq!(plain.len(), 3);
+        assert_eq!(plain["http"], 80);
+        let map = NamedMap::from(plain);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["ssh"], 22);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_from_str() {
+        let map: NamedMap<TestItem> = r#"{"a": {"value": "x", "optional": true}}"#.parse().unwrap();
+        assert_eq!(map["a"].value, "x");
+
+        let map: NamedMap<TestItem> = r#"["a", {"b": {"value": "y", "optional": false}}]"#
+            .parse()
+            .unwrap();
+        assert_eq!(map["a"], TestItem::from_name("a"));
+        assert_eq!(map["b"].value, "y");
+
+        let map: NamedMap<TestItem> = "a, b,c".parse().unwrap();
+        assert_eq!(map.names(), ["a", "b", "c"]);
+        let map: NamedMap<TestItem> = r#""a""#.parse().unwrap();
+        assert_eq!(map.names(), ["a"]);
+
+        let err = "a, b, a".parse::<NamedMap<TestItem>>().unwrap_err();
+        assert!(matches!(err, ParseNamedMapError::Names(_)), "{:?}", err);
+
+        for broken in [r#"{"a": "#, r#"  ["a", "#, "[a, b]"] {
+            let err = broken.parse::<NamedMap<TestItem>>().unwrap_err();
+            assert!(matches!(err, ParseNamedMapError::Json(_)), "{:?}", err);
+            assert!(err.to_string().starts_with("invalid JSON: "), "{}", err);
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_to_named_array() {
+        let mut map = NamedMap::new();
+        map.insert("logger".to_string(), TestItem { value: "1".to_string(), optional: false });
+        map.insert("auth".to_string(), TestItem { value: "2".to_string(), optional: true });
+        map.insert("http".to_string(), TestItem { value: "3".to_string(), optional: false });
+        assert_eq!(
+            map.to_named_array().unwrap(),
+            serde_json::json!([
+                {"name": "auth", "value": "2", "optional": true},
+                {"name": "http", "value": "3", "optional": false},
+                {"name": "logger", "value": "1", "optional": false},
+            ])
+        );
+        let string = map.to_named_array_string().unwrap();
+        assert!(string.starts_with(r#"[{"name":"auth","#), "{}", string);
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&string).unwrap(), map.to_named_array().unwrap());
+        assert_eq!(NamedMap::<TestItem>::new().to_named_array().unwrap(), serde_json::json!([]));
+
+        let mut values: NamedMap<serde_json::Value> = NamedMap::new();
+        values.insert("a".to_string(), serde_json::json!({"name": "a", "port": 1}));
+        assert_eq!(values.to_named_array().unwrap(), serde_json::json!([{"name": "a", "port": 1}]));
+
+        values.insert("b".to_string(), serde_json::json!({"name": "other"}));
+        let err = values.to_named_array().unwrap_err();
+        assert_eq!(err.to_string(), r#"value of "b" already has a conflicting name "other""#);
+
+        values.insert("b".to_string(), serde_json::json!(80));
+        let err = values.to_named_array_string().unwrap_err();
+        assert_eq!(err.to_string(), "value of \"b\" is not a JSON object");
+    }
+
+    #[test]
+    fn test_into_iter_owned() {
+        let map: NamedMap<u32> = [("a".to_string(), 1), ("b".to_string(), 2)].into_iter().collect();
+        let mut pairs: Vec<(String, u32)> = map.into_iter().collect();
+        pairs.sort();
+        assert_eq!(pairs, [("a".to_string(), 1), ("b".to_string(), 2)]);
+    }
+
+    #[test]
+    fn test_into_iter_by_ref() {
+        let mut map: NamedMap<u32> = [("a".to_string(), 1), ("b".to_string(), 2)].into_iter().collect();
+        for (_, value) in &mut map {
+            *value *= 10;
+        }
+        let mut total = 0;
+        for (_, value) in &map {
+            total += value;
+        }
+        assert_eq!(total, 30);
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_object_rejects_duplicate_keys() {
+        let err = serde_json::from_str::<NamedMap<TestItem>>(
+            r#"{"logger": {"value": "a", "optional": false}, "logger": {"value": "b", "optional": true}}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("duplicate key \"logger\""), "{}", err);
+
+        let err = NamedMap::<TestItem>::from_json_str(
+            r#"{"x": {"value": "a", "optional": false}, "x": {"value": "b", "optional": true}}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("duplicate key \"x\""), "{}", err);
     }
 
     #[cfg(feature = "serde")]
     #[test]
-    fn test_toml_compatibility() {
-        // This test ensures the main serde path does NOT depend on serde_json.
-        // It verifies compatibility with TOML, commonly used in Arcella and walmq manifests.
-        let toml_str = r#"
-            logger = { value = "file", optional = true }
-            metrics = { value = "prom", optional = false }
-        "#;
-        let _map: NamedMap<TestItem> = toml::from_str(toml_str).unwrap();
+    fn test_object_duplicate_keys_toml() {
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Manifest {
+            interfaces: NamedMap<TestItem>,
+        }
+
+        // TOML forbids repeated keys itself, so the parser reports them
+        // before the map ever sees the second entry.
+        let err = toml::from_str::<Manifest>(
+            r#"
+            [interfaces.logger]
+            value = "a"
+            optional = false
+
+            [interfaces.logger]
+            value = "b"
+            optional = true
+            "#,
+        )
+        .unwrap_err();
+        assert!(err.message().contains("duplicate key"), "{}", err);
+
+        let err = toml::from_str::<Manifest>(
+            r#"interfaces = { x = { value = "a", optional = false }, x = { value = "b", optional = true } }"#,
+        )
+        .unwrap_err();
+        assert!(err.message().contains("duplicate key"), "{}", err);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_object_duplicate_keys_yaml() {
+        const INPUT: &str = "logger:\n  value: a\n  optional: false\nlogger:\n  value: b\n  optional: true\n";
+
+        // Deserializing straight from the document streams both entries to
+        // the map, which rejects the second one.
+        let err = serde_yaml::from_str::<NamedMap<TestItem>>(INPUT).unwrap_err();
+        assert!(err.to_string().contains("duplicate key \"logger\""), "{}", err);
+
+        // Going through `serde_yaml::Value` hits serde_yaml's own check first.
+        let err = NamedMap::<TestItem>::from_yaml_str(INPUT).unwrap_err();
+        assert!(err.to_string().contains("duplicate entry with key \"logger\""), "{}", err);
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_null_values() {
+        #[derive(Debug, Deserialize)]
+        struct Manifest {
+            #[serde(deserialize_with = "NamedMap::deserialize_nullable")]
+            interfaces: NamedMap<TestItem>,
+        }
+
+        let input = r#"{"interfaces": {"logger": null, "http": {"value": "0.2", "optional": true}}}"#;
+        let manifest: Manifest = serde_json::from_str(input).unwrap();
+        assert_eq!(manifest.interfaces["logger"], TestItem::from_name("logger"));
+        assert_eq!(manifest.interfaces["http"].value, "0.2");
+
+        let value = serde_json::json!({"logger": null, "http": {"value": "0.2", "optional": true}});
+        assert_eq!(NamedMap::from_json_value(value).unwrap(), manifest.interfaces);
+
+        // The plain `Deserialize` impl stays strict.
+        assert!(serde_json::from_str::<NamedMap<TestItem>>(r#"{"logger": null}"#).is_err());
+        assert!(serde_json::from_str::<NamedMap<TestItem>>(r#"[{"logger": null}]"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_null_values_toml_unaffected() {
+        #[derive(Debug, Deserialize)]
+        struct Manifest {
+            #[serde(deserialize_with = "NamedMap::deserialize_nullable")]
+            interfaces: NamedMap<TestItem>,
+        }
+
+        let manifest: Manifest = toml::from_str(
+            r#"interfaces = { logger = { value = "file", optional = true } }"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.interfaces["logger"].value, "file");
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_json_single_string() {
+        let map: NamedMap<TestItem> = serde_json::from_str(r#""logger""#).unwrap();
+        assert_eq!(map, NamedMap::from(vec!["logger".to_string()]));
+
+        let map = NamedMap::<TestItem>::from_json_value(serde_json::json!("logger")).unwrap();
+        assert_eq!(map["logger"], TestItem::from_name("logger"));
+
+        let err = serde_json::from_str::<NamedMap<TestItem>>(r#""""#).unwrap_err();
+        assert!(err.to_string().contains("a non-empty name"), "{}", err);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_toml_single_string() {
+        #[derive(Deserialize)]
+        struct Manifest {
+            interfaces: NamedMap<TestItem>,
+        }
+
+        let manifest: Manifest = toml::from_str(r#"interfaces = "logger""#).unwrap();
+        assert_eq!(manifest.interfaces.len(), 1);
+        assert_eq!(manifest.interfaces["logger"], TestItem::from_name("logger"));
+
+        assert!(toml::from_str::<Manifest>(r#"interfaces = """#).is_err());
     }
 
     #[test]
@@ -399,4 +2710,433 @@ mod tests {
         let map: NamedMap<()> = NamedMap::default();
         assert!(map.is_empty());
     }
+
+    #[cfg(feature = "serde")]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Snapshot {
+        interfaces: NamedMap<TestItem>,
+        #[serde(deserialize_with = "NamedMap::deserialize_nullable")]
+        nullable: NamedMap<TestItem>,
+    }
+
+    #[cfg(feature = "serde")]
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            interfaces: NamedMap::from(vec!["logger".to_string(), "http".to_string()]),
+            nullable: NamedMap::from(vec!["metrics".to_string()]),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_bincode_roundtrip() {
+        let original = snapshot();
+        let bytes = bincode::serialize(&original).unwrap();
+        let restored: Snapshot = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(original, restored);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_postcard_roundtrip() {
+        let original = snapshot();
+        let bytes = postcard::to_allocvec(&original).unwrap();
+        let restored: Snapshot = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(original, restored);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_human_readable_forms_unchanged() {
+        let snapshot: Snapshot = toml::from_str(
+            r#"
+            interfaces = ["logger", { http = { value = "v", optional = true } }]
+            nullable = "metrics"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(snapshot.interfaces["logger"], TestItem::from_name("logger"));
+        assert!(snapshot.interfaces["http"].optional);
+        assert_eq!(snapshot.nullable["metrics"], TestItem::from_name("metrics"));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_values_borrow_from_input() {
+        use std::borrow::Cow;
+
+        #[derive(Debug, Clone, Deserialize)]
+        struct Borrowed<'a> {
+            #[serde(borrow)]
+            label: Cow<'a, str>,
+        }
+
+        impl FromName for Borrowed<'_> {
+            fn from_name(name: &str) -> Self {
+                Self {
+                    label: Cow::Owned(name.to_string()),
+                }
+            }
+        }
+
+        const INPUT: &str = r#"{"logger": {"label": "stdout"}, "http": {"label": "axum"}}"#;
+        let map: NamedMap<Borrowed<'static>> = serde_json::from_str(INPUT).unwrap();
+        assert!(matches!(map["logger"].label, Cow::Borrowed("stdout")));
+
+        let map: NamedMap<Borrowed<'_>> =
+            serde_json::from_str(r#"["metrics", {"http": {"label": "axum"}}]"#).unwrap();
+        assert!(matches!(map["http"].label, Cow::Borrowed("axum")));
+        assert!(matches!(map["metrics"].label, Cow::Owned(_)));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_deserialize_in_place_reuses_map() {
+        let mut map: NamedMap<TestItem> =
+            (0..64).map(|i| format!("old{}", i)).collect::<Vec<_>>().into();
+        let capacity = map.capacity();
+
+        let mut de = serde_json::Deserializer::from_str(
+            r#"["a", {"b": {"value": "v", "optional": true}}]"#,
+        );
+        NamedMap::deserialize_in_place(&mut de, &mut map).unwrap();
+        assert_eq!(map.len(), 2);
+        assert!(!map.contains_key("old0"));
+        assert!(map["b"].optional);
+        assert!(map.capacity() >= capacity);
+
+        let mut de =
+            serde_json::Deserializer::from_str(r#"{"c": {"value": "v", "optional": false}}"#);
+        NamedMap::deserialize_in_place(&mut de, &mut map).unwrap();
+        assert_eq!(map.keys().collect::<Vec<_>>(), ["c"]);
+        assert!(map.capacity() >= capacity);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_error_names_entry_toml() {
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Manifest {
+            interfaces: NamedMap<TestItem>,
+        }
+
+        let err = toml::from_str::<Manifest>(
+            r#"
+            [interfaces.logger]
+            value = "stdout"
+            optional = false
+
+            [interfaces.http]
+            value = "axum"
+            "#,
+        )
+        .unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("error in entry \"http\""), "{}", msg);
+        assert!(msg.contains("missing field `optional`"), "{}", msg);
+
+        let err =
+            toml::from_str::<Manifest>(r#"interfaces = ["logger", { http = {} }]"#).unwrap_err();
+        assert!(err.to_string().contains("error in entry \"http\""), "{}", err);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_value_error_names_entry_json() {
+        let err = NamedMap::<TestItem>::from_json_str(
+            r#"{"logger": {"value": "stdout", "optional": false}, "http": {"value": "axum"}}"#,
+        )
+        .unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("error in entry \"http\""), "{}", msg);
+        assert!(msg.contains("missing field `optional`"), "{}", msg);
+        assert!(!msg.contains("\"logger\""), "{}", msg);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_error_messages() {
+        use serde_json::json;
+
+        let message = |value| {
+            NamedMap::<TestItem>::from_json_value(value)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            message(json!({ "http": { "value": "axum" } })),
+            "error in entry \"http\": missing field `optional`"
+        );
+        assert_eq!(
+            message(json!({ "": { "value": "axum", "optional": false } })),
+            "empty key \"\""
+        );
+        assert_eq!(message(json!(["a", "a"])), "duplicate name \"a\" at index 1");
+        assert_eq!(message(json!(["a", " "])), "empty name at index 1");
+        assert_eq!(
+            message(json!([{ "a": null, "b": null }])),
+            "inline entry \"a\" must be a single-key map, found additional key \"b\""
+        );
+        assert_eq!(
+            message(json!("")),
+            "invalid value: string \"\", expected a non-empty name"
+        );
+
+        let err = serde_json::from_str::<NamedMap<TestItem>>(
+            r#"{"x": {"value": "a", "optional": false}, "x": {"value": "b", "optional": true}}"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "duplicate key \"x\" at line 1 column 44");
+    }
+
+    #[cfg(feature = "path-to-error")]
+    #[test]
+    fn test_from_json_str_with_path() {
+        let err = NamedMap::<TestItem>::from_json_str_with_path(
+            r#"{"logger": {"value": "stdout", "optional": false}, "http": {"value": "axum", "optional": "yes"}}"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.path, "http.optional");
+        assert!(err.message.contains("invalid type"), "{}", err);
+        assert!(err.to_string().starts_with("http.optional: "), "{}", err);
+
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Config {
+            plugins: NamedMap<TestItem>,
+        }
+
+        let mut de =
+            serde_json::Deserializer::from_str(r#"{"plugins": ["a", {"b": {"value": 1}}]}"#);
+        let err = crate::de::with_path::<Config, _>(&mut de).unwrap_err();
+        assert_eq!(err.path, "plugins[1].b.value");
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_writer_and_reader_roundtrip() {
+        use std::io::Cursor;
+
+        let mut original: NamedMap<TestItem> = NamedMap::from(vec!["a".to_string()]);
+        original.insert("b".to_string(), TestItem {
+            value: "v".to_string(),
+            optional: true,
+        });
+
+        let mut buf = Vec::new();
+        original.to_json_writer(&mut buf).unwrap();
+        assert_eq!(NamedMap::from_json_reader(Cursor::new(&buf)).unwrap(), original);
+
+        let mut pretty = Vec::new();
+        original.to_json_writer_pretty(&mut pretty).unwrap();
+        assert_eq!(pretty, original.to_json_string_pretty().unwrap().into_bytes());
+        assert!(pretty.contains(&b'\n') && !pretty.ends_with(b"\n"));
+        assert_eq!(NamedMap::from_json_reader(Cursor::new(&pretty)).unwrap(), original);
+
+        let simple: NamedMap<TestItem> =
+            NamedMap::from_json_reader(Cursor::new(br#"["x", "y"]"#)).unwrap();
+        assert_eq!(simple.len(), 2);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_helpers() {
+        let doc: toml::Table = toml::from_str(
+            r#"
+            simple = ["b", "a"]
+            detailed = { b = { value = "v", optional = true }, a = { value = "w", optional = false } }
+            "#,
+        )
+        .unwrap();
+
+        let simple = NamedMap::<TestItem>::from_toml_value(doc["simple"].clone()).unwrap();
+        assert_eq!(simple["a"], TestItem::from_name("a"));
+
+        let detailed = NamedMap::<TestItem>::from_toml_value(doc["detailed"].clone()).unwrap();
+        assert!(detailed["b"].optional);
+
+        let out = detailed.to_toml_string().unwrap();
+        assert!(out.find("[a]").unwrap() < out.find("[b]").unwrap(), "{}", out);
+        assert_eq!(NamedMap::from_toml_str(&out).unwrap(), detailed);
+        assert_eq!(
+            NamedMap::from_toml_str(&detailed.to_toml_string_pretty().unwrap()).unwrap(),
+            detailed
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_helpers() {
+        let simple = NamedMap::<TestItem>::from_yaml_str("- logger\n- http\n").unwrap();
+        assert_eq!(simple["http"], TestItem::from_name("http"));
+
+        let detailed = NamedMap::<TestItem>::from_yaml_str(
+            "logger:\n  value: stdout\n  optional: true\nhttp:\n  value: axum\n  optional: false\n",
+        )
+        .unwrap();
+        assert!(detailed["logger"].optional);
+
+        let out = detailed.to_yaml_string().unwrap();
+        assert_eq!(NamedMap::from_yaml_str(&out).unwrap(), detailed);
+
+        assert!(NamedMap::<TestItem>::from_yaml_str("").unwrap().is_empty());
+        assert!(NamedMap::<TestItem>::from_yaml_str("~").unwrap().is_empty());
+
+        let err = NamedMap::<TestItem>::from_yaml_str("1: { value: x, optional: true }\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("expected a name"), "{}", err);
+
+        assert!(NamedMap::<TestItem>::from_yaml_str("- a\n---\n- b\n").is_err());
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn test_ron_helpers() {
+        let simple = NamedMap::<TestItem>::from_ron_str(r#"["logger", "http"]"#).unwrap();
+        assert_eq!(simple["http"], TestItem::from_name("http"));
+
+        let detailed = NamedMap::<TestItem>::from_ron_str(
+            r#"{
+                "logger": (value: "stdout", optional: true),
+                "http": TestItem(value: "axum", optional: false),
+            }"#,
+        )
+        .unwrap();
+        assert!(detailed["logger"].optional);
+        assert_eq!(detailed["http"].value, "axum");
+
+        let mixed =
+            NamedMap::<TestItem>::from_ron_str(r#"["a", {"b": (value: "v", optional: true)}]"#)
+                .unwrap();
+        assert!(mixed["b"].optional);
+
+        let unnamed =
+            NamedMap::<TestItem>::from_ron_str(r#"(logger: (value: "x", optional: true))"#)
+                .unwrap();
+        assert!(unnamed["logger"].optional);
+        let wrapped = NamedMap::<TestItem>::from_ron_str(r#"Some(["a"])"#).unwrap();
+        assert_eq!(wrapped["a"], TestItem::from_name("a"));
+
+        for out in [
+            detailed.to_ron_string().unwrap(),
+            detailed.to_ron_string_pretty().unwrap(),
+        ] {
+            assert_eq!(NamedMap::from_ron_str(&out).unwrap(), detailed);
+        }
+    }
+
+    #[cfg(feature = "json5")]
+    #[test]
+    fn test_json5_helpers() {
+        let simple = NamedMap::<TestItem>::from_json5_str(
+            r#"
+            // enabled plugins
+            [
+                "logger",
+                'http', /* single quotes work too */
+            ]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(simple["http"], TestItem::from_name("http"));
+
+        let detailed = NamedMap::<TestItem>::from_json5_str(
+            r#"{
+                logger: { value: "stdout", optional: true },
+                http: { value: "axum", optional: false, },
+            }"#,
+        )
+        .unwrap();
+        assert!(detailed["logger"].optional);
+
+        let err = NamedMap::<TestItem>::from_json5_str(r#"{ http: { value: "axum" } }"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("error in entry \"http\""), "{}", err);
+    }
+
+    #[cfg(feature = "rmp")]
+    #[test]
+    fn test_msgpack_helpers() {
+        let mut original: NamedMap<TestItem> = NamedMap::from(vec!["a".to_string()]);
+        original.insert("b".to_string(), TestItem {
+            value: "v".to_string(),
+            optional: true,
+        });
+
+        for bytes in [
+            original.to_msgpack_vec().unwrap(),
+            original.to_msgpack_vec_named().unwrap(),
+        ] {
+            assert_eq!(NamedMap::from_msgpack_slice(&bytes).unwrap(), original);
+        }
+
+        // A mixed array, with the inline entry's struct packed compactly.
+        let entry: HashMap<_, _> = [("b", ("v", true))].into_iter().collect();
+        let packed = rmp_serde::to_vec(&("a", entry)).unwrap();
+        let mixed = NamedMap::<TestItem>::from_msgpack_slice(&packed).unwrap();
+        assert_eq!(mixed["a"], TestItem::from_name("a"));
+        assert!(mixed["b"].optional);
+    }
+
+    #[cfg(all(feature = "rmp", feature = "serde_json"))]
+    #[test]
+    fn test_msgpack_matches_json() {
+        let json: NamedMap<TestItem> =
+            NamedMap::from_json_str(r#"["a", {"b": {"value": "v", "optional": true}}]"#).unwrap();
+        let bytes = json.to_msgpack_vec_named().unwrap();
+        assert_eq!(NamedMap::from_msgpack_slice(&bytes).unwrap(), json);
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn test_cbor_helpers() {
+        let mut original: NamedMap<TestItem> =
+            NamedMap::from(vec!["aa".to_string(), "b".to_string()]);
+        original.insert("c".to_string(), TestItem {
+            value: "v".to_string(),
+            optional: true,
+        });
+
+        let mut bytes = Vec::new();
+        original.to_cbor_writer(&mut bytes).unwrap();
+        assert_eq!(NamedMap::from_cbor_slice(&bytes).unwrap(), original);
+        assert_eq!(NamedMap::from_cbor_reader(bytes.as_slice()).unwrap(), original);
+
+        let mut names = Vec::new();
+        ciborium::ser::into_writer(&["a", "b"], &mut names).unwrap();
+        assert_eq!(NamedMap::<TestItem>::from_cbor_slice(&names).unwrap().len(), 2);
+
+        let mut byte_keys = Vec::new();
+        let value = ciborium::Value::Map(vec![(
+            ciborium::Value::Bytes(b"a".to_vec()),
+            ciborium::Value::Null,
+        )]);
+        ciborium::ser::into_writer(&value, &mut byte_keys).unwrap();
+        let err = NamedMap::<TestItem>::from_cbor_slice(&byte_keys).unwrap_err();
+        assert!(err.to_string().contains("byte string"), "{}", err);
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn test_cbor_deterministic_encoding() {
+        let encode = |names: &[&str]| {
+            let map: NamedMap<TestItem> = names.iter().map(|name| name.to_string()).collect();
+            let mut bytes = Vec::new();
+            map.to_cbor_writer_deterministic(&mut bytes).unwrap();
+            bytes
+        };
+
+        let bytes = encode(&["bb", "a", "c", "aa"]);
+        assert_eq!(bytes, encode(&["aa", "c", "a", "bb"]));
+
+        let value: ciborium::Value = ciborium::de::from_reader(bytes.as_slice()).unwrap();
+        let keys: Vec<_> = value
+            .as_map()
+            .unwrap()
+            .iter()
+            .map(|(key, _)| key.as_text().unwrap().to_string())
+            .collect();
+        assert_eq!(keys, ["a", "c", "aa", "bb"]);
+    }
 }
@@ -0,0 +1,540 @@
+// flexicon/src/adaptive/namedmultimap.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use super::map::HashMap;
+use super::{FromName, NamedMap};
+
+/// A variant of [`NamedMap`] in which a name may carry several values,
+/// backed by `HashMap<String, Vec<T>>`.
+///
+/// Where `NamedMap` rejects (or, with a [`DuplicatePolicy`], collapses) a
+/// repeated name, `NamedMultiMap` keeps every value, in input order:
+///
+/// - an array of names and single-key maps appends one value per item;
+/// - in an object, each value may be a single `T` or an array of `T`.
+///
+/// Serialization always emits the object-of-arrays form. Every name holds at
+/// least one value.
+///
+/// [`DuplicatePolicy`]: super::DuplicatePolicy
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # #[derive(Clone, Serialize, Deserialize)]
+/// # struct Listener { port: u16 }
+/// # impl flexicon::adaptive::FromName for Listener {
+/// #     fn from_name(_name: &str) -> Self { Self { port: 80 } }
+/// # }
+/// use flexicon::adaptive::NamedMultiMap;
+///
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() -> Result<(), serde_json::Error> {
+/// let listeners: NamedMultiMap<Listener> =
+///     serde_json::from_str(r#"["http", {"http": {"port": 8080}}, "admin"]"#)?;
+/// assert_eq!(listeners.get_all("http").len(), 2);
+/// assert_eq!(listeners.len_names(), 2);
+/// assert_eq!(listeners.len_entries(), 3);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedMultiMap<T>(HashMap<String, Vec<T>>);
+
+impl<T> NamedMultiMap<T> {
+    /// Creates an empty `NamedMultiMap`.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Appends `value` to the values of `name`.
+    ///
+    /// Unlike [`NamedMap::insert`], nothing is ever replaced.
+    pub fn insert(&mut self, name: String, value: T) {
+        self.0.entry(name).or_default().push(value);
+    }
+
+    /// Returns every value of `name` in insertion order, or an empty slice.
+    pub fn get_all(&self, name: &str) -> &[T] {
+        self.0.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the number of distinct names.
+    pub fn len_names(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the number of values, across all names.
+    pub fn len_entries(&self) -> usize {
+        self.0.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Consumes the map and returns the inner `HashMap`.
+    pub fn into_inner(self) -> HashMap<String, Vec<T>> {
+        self.0
+    }
+
+    /// Returns a reference to the inner map.
+    pub fn as_inner(&self) -> &HashMap<String, Vec<T>> {
+        &self.0
+    }
+}
+
+impl<T> Default for NamedMultiMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Read-only: mutating through `HashMap` could leave a name with no values,
+// or replace values that `insert` would append to.
+impl<T> Deref for NamedMultiMap<T> {
+    type Target = HashMap<String, Vec<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<NamedMultiMap<T>> for NamedMap<Vec<T>> {
+    fn from(map: NamedMultiMap<T>) -> Self {
+        NamedMap::from(map.0)
+    }
+}
+
+// Names with an empty list are dropped.
+impl<T> From<NamedMap<Vec<T>>> for NamedMultiMap<T> {
+    fn from(map: NamedMap<Vec<T>>) -> Self {
+        NamedMultiMap(
+            map.into_iter()
+                .filter(|(_, values)| !values.is_empty())
+                .collect(),
+        )
+    }
+}
+
+impl<T> FromIterator<(String, T)> for NamedMultiMap<T> {
+    /// Collects pairs, appending the values of repeated names.
+    fn from_iter<I: IntoIterator<Item = (String, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<T> Extend<(String, T)> for NamedMultiMap<T> {
+    /// Appends every pair.
+    fn extend<I: IntoIterator<Item = (String, T)>>(&mut self, iter: I) {
+        for (name, value) in iter {
+            self.insert(name, value);
+        }
+    }
+}
+
+// Allow construction from a simple list of names; each occurrence of a name
+// adds a `T::from_name` value.
+impl<T: FromName> From<Vec<String>> for NamedMultiMap<T> {
+    fn from(list: Vec<String>) -> Self {
+        list.into_iter()
+            .map(|name| {
+                let value = T::from_name(&name);
+                (name, value)
+            })
+            .collect()
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::adaptive::seq::{
+        cautious_capacity, deserialize_adaptive, next_named_value, Key, KeySeed, SeqItem,
+    };
+    use alloc::string::ToString;
+    use alloc::vec;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::{
+        de::{
+            self, value::MapAccessDeserializer, Deserializer, IntoDeserializer, MapAccess,
+            SeqAccess, Visitor,
+        },
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+
+    /// A value in the object form: a single `T` or an array of them.
+    struct OneOrMany<T>(Vec<T>);
+
+    struct OneOrManyVisitor<T> {
+        _phantom: PhantomData<T>,
+    }
+
+    impl<T> OneOrManyVisitor<T> {
+        fn one<'de, D>(deserializer: D) -> Result<OneOrMany<T>, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de>,
+        {
+            T::deserialize(deserializer).map(|value| OneOrMany(vec![value]))
+        }
+    }
+
+    impl<'de, T> Visitor<'de> for OneOrManyVisitor<T>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = OneOrMany<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a value or an array of values")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut values = Vec::with_capacity(cautious_capacity::<T>(seq.size_hint()));
+            while let Some(value) = seq.next_element()? {
+                values.push(value);
+            }
+            Ok(OneOrMany(values))
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            Self::one(MapAccessDeserializer::new(map))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Self::one(v.into_deserializer())
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Self::one(v.into_deserializer())
+        }
+
+        fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Self::one(v.into_deserializer())
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Self::one(v.into_deserializer())
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Self::one(v.into_deserializer())
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Self::one(v.into_deserializer())
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for OneOrMany<T>
+    where
+        T: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(OneOrManyVisitor {
+                _phantom: PhantomData,
+            })
+        }
+    }
+
+    /// Visitor that appends every value it meets.
+    struct NamedMultiMapVisitor<T> {
+        /// Whether the input can be driven with `deserialize_any`; otherwise
+        /// values are read as the arrays `Serialize` wrote.
+        self_describing: bool,
+        _phantom: PhantomData<T>,
+    }
+
+    impl<'de, T> Visitor<'de> for NamedMultiMapVisitor<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        type Value = NamedMultiMap<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a map of values or arrays of values (e.g., {{ \"a\": [{{...}}, {{...}}] }}), a sequence of names and single-key maps (e.g., [\"a\", {{ \"a\": {{...}} }}]), or a single name (e.g., \"a\")")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            self.visit_string(v.to_string())
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if v.is_empty() {
+                return Err(de::Error::invalid_value(
+                    de::Unexpected::Str(&v),
+                    &"a non-empty name",
+                ));
+            }
+            Ok(NamedMultiMap::from(vec![v]))
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut map = NamedMultiMap::new();
+            while let Some(item) = seq.next_element::<SeqItem<T>>()? {
+                let (name, value) = item.into_entry();
+                map.insert(name, value);
+            }
+            Ok(map)
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut map = NamedMultiMap::new();
+            let seed = KeySeed {
+                self_describing: self.self_describing,
+            };
+            // A repeated key appends too, like a repeated name in the array form.
+            while let Some(Key(name)) = access.next_key_seed(seed)? {
+                let values = if self.self_describing {
+                    next_named_value::<_, OneOrMany<T>>(&mut access, &name)?.0
+                } else {
+                    next_named_value::<_, Vec<T>>(&mut access, &name)?
+                };
+                if !values.is_empty() {
+                    map.0.entry(name.into_owned()).or_default().extend(values);
+                }
+            }
+            Ok(map)
+        }
+    }
+
+    impl<T> Serialize for NamedMultiMap<T>
+    where
+        T: Serialize,
+    {
+        /// Serializes as an object of arrays, even for single values.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_map(&self.0)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for NamedMultiMap<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        /// Deserializes from either:
+        /// - An object whose values are a `T` or an array of `T`
+        /// - An array of names and single-key maps, appending in order
+        /// - A single non-empty string, as a one-entry map
+        ///
+        /// Non-human-readable formats only accept the object-of-arrays form,
+        /// matching what `Serialize` emits.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let self_describing = deserializer.is_human_readable();
+            deserialize_adaptive(
+                deserializer,
+                NamedMultiMapVisitor {
+                    self_describing,
+                    _phantom: PhantomData,
+                },
+            )
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    struct Listener {
+        port: u16,
+    }
+
+    impl FromName for Listener {
+        fn from_name(_name: &str) -> Self {
+            Self { port: 80 }
+        }
+    }
+
+    fn listener(port: u16) -> Listener {
+        Listener { port }
+    }
+
+    #[test]
+    fn test_insert_appends_where_named_map_overwrites() {
+        let mut single = NamedMap::new();
+        single.insert("http".to_string(), listener(80));
+        assert_eq!(
+            single.insert("http".to_string(), listener(8080)),
+            Some(listener(80))
+        );
+        assert_eq!(single["http"], listener(8080));
+
+        let mut multi = NamedMultiMap::new();
+        multi.insert("http".to_string(), listener(80));
+        multi.insert("http".to_string(), listener(8080));
+        multi.insert("admin".to_string(), listener(9000));
+        assert_eq!(multi.get_all("http"), [listener(80), listener(8080)]);
+        assert_eq!(multi.get_all("missing"), []);
+        assert_eq!(multi.len_names(), 2);
+        assert_eq!(multi.len_entries(), 3);
+    }
+
+    #[test]
+    fn test_named_map_conversions() {
+        let multi: NamedMultiMap<Listener> =
+            NamedMultiMap::from(vec!["http".to_string(), "http".to_string()]);
+        let grouped: NamedMap<Vec<Listener>> = multi.clone().into();
+        assert_eq!(grouped["http"], [listener(80), listener(80)]);
+
+        let mut grouped = grouped;
+        grouped.insert("empty".to_string(), Vec::new());
+        let back = NamedMultiMap::from(grouped);
+        assert_eq!(back, multi);
+        assert!(!back.contains_key("empty"));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_array_form_appends_where_named_map_rejects() {
+        let json = r#"["http", {"http": {"port": 8080}}, "admin"]"#;
+
+        let err = serde_json::from_str::<NamedMap<Listener>>(json).unwrap_err();
+        assert!(
+            err.to_string().contains("duplicate name \"http\" at index 1"),
+            "{}",
+            err
+        );
+
+        let multi: NamedMultiMap<Listener> = serde_json::from_str(json).unwrap();
+        assert_eq!(multi.get_all("http"), [listener(80), listener(8080)]);
+        assert_eq!(multi.get_all("admin"), [listener(80)]);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_object_form_one_or_many() {
+        let multi: NamedMultiMap<Listener> = serde_json::from_str(
+            r#"{"http": [{"port": 80}, {"port": 8080}], "admin": {"port": 9000}, "none": []}"#,
+        )
+        .unwrap();
+        assert_eq!(multi.get_all("http"), [listener(80), listener(8080)]);
+        assert_eq!(multi.get_all("admin"), [listener(9000)]);
+        assert_eq!(multi.len_names(), 2);
+
+        // Scalars work as single values too.
+        #[derive(Debug, Clone, PartialEq, Deserialize)]
+        #[serde(transparent)]
+        struct Port(u16);
+
+        impl FromName for Port {
+            fn from_name(_name: &str) -> Self {
+                Port(80)
+            }
+        }
+
+        let ports: NamedMultiMap<Port> =
+            serde_json::from_str(r#"{"http": 80, "https": [443, 8443]}"#).unwrap();
+        assert_eq!(ports.get_all("http"), [Port(80)]);
+        assert_eq!(ports.get_all("https"), [Port(443), Port(8443)]);
+
+        let err = serde_json::from_str::<NamedMultiMap<Listener>>(r#"{"http": [{"port": "x"}]}"#)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("error in entry \"http\""),
+            "{}",
+            err
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_serializes_object_of_arrays() {
+        let mut multi = NamedMultiMap::new();
+        multi.insert("http".to_string(), listener(80));
+        let json = serde_json::to_value(&multi).unwrap();
+        assert_eq!(json, serde_json::json!({"http": [{"port": 80}]}));
+        assert_eq!(
+            serde_json::from_value::<NamedMultiMap<Listener>>(json).unwrap(),
+            multi
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_binary_roundtrip() {
+        let multi: NamedMultiMap<Listener> = [
+            ("http".to_string(), listener(80)),
+            ("http".to_string(), listener(8080)),
+        ]
+        .into_iter()
+        .collect();
+        let bytes = bincode::serialize(&multi).unwrap();
+        assert_eq!(
+            bincode::deserialize::<NamedMultiMap<Listener>>(&bytes).unwrap(),
+            multi
+        );
+    }
+}
@@ -0,0 +1,280 @@
+// flexicon/src/adaptive/namepolicy.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::hash::BuildHasher;
+
+use super::map::HashMap;
+use super::{DuplicatePolicy, FromName, NamedMap};
+
+/// What to do with empty and whitespace-only names, such as the `""` and
+/// `"  "` in `["logger", "", "  "]`.
+///
+/// Names with interior spaces (`"my logger"`) are never affected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NamePolicy {
+    /// Reject the input, reporting the position of the empty name.
+    #[default]
+    RejectEmpty,
+    /// Trim surrounding whitespace from every name and skip names that end
+    /// up empty.
+    TrimAndSkipEmpty,
+    /// Skip empty and whitespace-only names, keeping every other name as
+    /// written. The infallible conversions (`From<Vec<String>>`,
+    /// `FromIterator<String>`, [`NamedMap::from_names`]) apply this policy.
+    SkipEmpty,
+}
+
+/// An empty or whitespace-only name found by [`NamePolicy::RejectEmpty`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmptyNameError {
+    /// Zero-based position of the name in its source list.
+    pub index: usize,
+}
+
+impl fmt::Display for EmptyNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "empty name at index {}", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EmptyNameError {}
+
+impl NamePolicy {
+    /// Applies the policy to a name or key: `Ok(None)` means skip it and
+    /// `Err` hands back the rejected name. Unchanged names are passed
+    /// through without reallocating.
+    pub(crate) fn check<'a>(
+        self,
+        name: Cow<'a, str>,
+    ) -> Result<Option<Cow<'a, str>>, Cow<'a, str>> {
+        let trimmed = name.trim();
+        match self {
+            _ if !trimmed.is_empty() && trimmed.len() == name.len() => Ok(Some(name)),
+            NamePolicy::RejectEmpty if trimmed.is_empty() => Err(name),
+            NamePolicy::RejectEmpty => Ok(Some(name)),
+            NamePolicy::TrimAndSkipEmpty if trimmed.is_empty() => Ok(None),
+            NamePolicy::TrimAndSkipEmpty => Ok(Some(Cow::Owned(trimmed.to_string()))),
+            NamePolicy::SkipEmpty if trimmed.is_empty() => Ok(None),
+            NamePolicy::SkipEmpty => Ok(Some(name)),
+        }
+    }
+
+    /// Like [`check`](Self::check), for the name at `index` of a list.
+    pub(crate) fn check_name(
+        self,
+        name: String,
+        index: usize,
+    ) -> Result<Option<String>, EmptyNameError> {
+        match self.check(Cow::Owned(name)) {
+            Ok(name) => Ok(name.map(Cow::into_owned)),
+            Err(_) => Err(EmptyNameError { index }),
+        }
+    }
+}
+
+impl<T: FromName, S: BuildHasher + Default> NamedMap<T, S> {
+    /// Builds a map from a list of names, handling empty and
+    /// whitespace-only names with `policy`. Repeated names collapse into
+    /// one entry.
+    ///
+    /// The checked counterpart of `From<Vec<String>>`, which skips empty
+    /// names ([`NamePolicy::SkipEmpty`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::{NamedMap, NamePolicy};
+    /// # #[derive(Debug, Clone)]
+    /// # struct Plugin;
+    /// # impl flexicon::adaptive::FromName for Plugin {
+    /// #     fn from_name(_name: &str) -> Self { Plugin }
+    /// # }
+    ///
+    /// let names = vec!["logger".to_string(), " ".to_string()];
+    /// let err = NamedMap::<Plugin>::from_names_with_name_policy(names.clone(), NamePolicy::RejectEmpty)
+    ///     .unwrap_err();
+    /// assert_eq!(err.to_string(), "empty name at index 1");
+    ///
+    /// let map = NamedMap::<Plugin>::from_names_with_name_policy(names, NamePolicy::TrimAndSkipEmpty)
+    ///     .unwrap();
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn from_names_with_name_policy<I>(
+        names: I,
+        policy: NamePolicy,
+    ) -> Result<Self, EmptyNameError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut map = HashMap::default();
+        insert_checked(&mut map, names, policy)?;
+        Ok(NamedMap::from(map))
+    }
+}
+
+/// Inserts `T::from_name(name)` for every name `policy` lets through, with
+/// [`DuplicatePolicy::KeepLast`] for repeated names.
+pub(crate) fn insert_checked<T, S, I>(
+    map: &mut HashMap<String, T, S>,
+    names: I,
+    policy: NamePolicy,
+) -> Result<(), EmptyNameError>
+where
+    T: FromName,
+    S: BuildHasher,
+    I: IntoIterator<Item = String>,
+{
+    for (index, name) in names.into_iter().enumerate() {
+        if let Some(name) = policy.check_name(name, index)? {
+            let value = T::from_name(&name);
+            // `KeepLast` never fails.
+            let _ = DuplicatePolicy::KeepLast.insert(map, name, value, index);
+        }
+    }
+    Ok(())
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Item(String);
+
+    impl FromName for Item {
+        fn from_name(name: &str) -> Self {
+            Item(name.to_string())
+        }
+    }
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_reject_empty_by_default() {
+        for list in [&["logger", ""][..], &["logger", "  "], &["\t"]] {
+            let index = list.len() - 1;
+            let err =
+                NamedMap::<Item>::from_names_with_name_policy(names(list), NamePolicy::default())
+                    .unwrap_err();
+            assert_eq!(err, EmptyNameError { index });
+        }
+    }
+
+    #[test]
+    fn test_conversions_skip_empty_names() {
+        let expected = NamedMap::<Item>::from_names_with_name_policy(
+            names(&["a", " b"]),
+            NamePolicy::RejectEmpty,
+        )
+        .unwrap();
+        let list = ["a", "", " b", "  "];
+        assert_eq!(NamedMap::<Item>::from(names(&list)), expected);
+        assert_eq!(list.iter().copied().collect::<NamedMap<Item>>(), expected);
+        assert_eq!(NamedMap::<Item>::from_names(list), expected);
+        assert_eq!(
+            NamedMap::<Item>::from_names_with_name_policy(names(&list), NamePolicy::SkipEmpty),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_trim_and_skip_empty() {
+        let map = NamedMap::<Item>::from_names_with_name_policy(
+            names(&[" logger ", "", "  ", "http"]),
+            NamePolicy::TrimAndSkipEmpty,
+        )
+        .unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["logger"], Item::from_name("logger"));
+        assert!(map.contains_key("http"));
+    }
+
+    #[test]
+    fn test_interior_spaces_pass() {
+        for policy in [
+            NamePolicy::RejectEmpty,
+            NamePolicy::TrimAndSkipEmpty,
+            NamePolicy::SkipEmpty,
+        ] {
+            let map = NamedMap::<Item>::from_names_with_name_policy(names(&["my logger"]), policy)
+                .unwrap();
+            assert_eq!(map["my logger"], Item::from_name("my logger"));
+        }
+        let map = NamedMap::<Item>::from(names(&["my logger"]));
+        assert!(map.contains_key("my logger"));
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod serde {
+        use super::*;
+        use ::serde::Deserialize;
+
+        #[derive(Debug, Clone, PartialEq, Deserialize)]
+        struct Value {
+            v: u32,
+        }
+
+        impl FromName for Value {
+            fn from_name(_name: &str) -> Self {
+                Value { v: 0 }
+            }
+        }
+
+        #[derive(Deserialize)]
+        struct Lenient {
+            #[serde(deserialize_with = "NamedMap::deserialize_trim_names")]
+            map: NamedMap<Value>,
+        }
+
+        #[test]
+        fn test_deserialize_rejects_empty_names() {
+            for (input, message) in [
+                (r#"["logger", "", "  "]"#, "empty name at index 1"),
+                (r#"["logger", {"  ": {"v": 1}}]"#, "empty name at index 1"),
+                (r#"{"logger": {"v": 1}, " ": {"v": 2}}"#, r#"empty key " ""#),
+                (r#"" ""#, "a non-empty name"),
+            ] {
+                let err = serde_json::from_str::<NamedMap<Value>>(input).unwrap_err();
+                assert!(err.to_string().contains(message), "{}: {}", input, err);
+            }
+        }
+
+        #[test]
+        fn test_deserialize_trim_and_skip_empty() {
+            let lenient: Lenient =
+                serde_json::from_str(r#"{"map": ["logger", "", "  ", {" http ": {"v": 8}}]}"#)
+                    .unwrap();
+            assert_eq!(lenient.map.len(), 2);
+            assert_eq!(lenient.map["logger"], Value { v: 0 });
+            assert_eq!(lenient.map["http"], Value { v: 8 });
+
+            let lenient: Lenient =
+                serde_json::from_str(r#"{"map": {" logger": {"v": 1}, "": {"v": 2}}}"#).unwrap();
+            assert_eq!(lenient.map.len(), 1);
+            assert_eq!(lenient.map["logger"], Value { v: 1 });
+        }
+
+        #[test]
+        fn test_deserialize_interior_spaces_pass() {
+            let map: NamedMap<Value> =
+                serde_json::from_str(r#"["my logger", {"my http": {"v": 8}}]"#).unwrap();
+            assert_eq!(map.len(), 2);
+            let map: NamedMap<Value> = serde_json::from_str(r#"{"my logger": {"v": 1}}"#).unwrap();
+            assert_eq!(map["my logger"], Value { v: 1 });
+        }
+    }
+}
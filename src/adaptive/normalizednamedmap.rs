@@ -0,0 +1,376 @@
+// flexicon/src/adaptive/normalizednamedmap.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::ops::Deref;
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+use super::NamedMap;
+
+/// Returns `name` in Unicode Normalization Form C, borrowing when it
+/// already is.
+fn normalize(name: &str) -> Cow<'_, str> {
+    if is_nfc(name) {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(name.nfc().collect())
+    }
+}
+
+/// A [`NamedMap`] whose names are NFC-normalized.
+///
+/// `"café"` typed as one precomposed `é` and as `e` plus a combining accent
+/// look identical but are different strings; this map stores both under the
+/// composed (NFC) form, and lookups normalize the queried name the same way.
+///
+/// Deserialization rejects two input names that only become equal after
+/// normalization, showing the bytes of both so the difference is visible.
+///
+/// Requires the `unicode` feature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedNamedMap<T>(NamedMap<T>);
+
+impl<T> NormalizedNamedMap<T> {
+    /// Creates an empty `NormalizedNamedMap`.
+    pub fn new() -> Self {
+        Self(NamedMap::new())
+    }
+
+    /// Inserts a key-value pair under the NFC form of `key`.
+    pub fn insert(&mut self, key: String, value: T) -> Option<T> {
+        let key = match normalize(&key) {
+            Cow::Borrowed(_) => key,
+            Cow::Owned(normalized) => normalized,
+        };
+        self.0.insert(key, value)
+    }
+
+    /// Returns the value of `name`, in any normalization form.
+    pub fn get(&self, name: &str) -> Option<&T> {
+        self.0.get(normalize(name).as_ref())
+    }
+
+    /// Returns a mutable reference to the value of `name`, in any
+    /// normalization form.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut T> {
+        self.0.get_mut(normalize(name).as_ref())
+    }
+
+    /// Returns `true` if the map has an entry for `name`, in any
+    /// normalization form.
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.0.contains_key(normalize(name).as_ref())
+    }
+
+    /// Removes the entry for `name`, in any normalization form.
+    pub fn remove(&mut self, name: &str) -> Option<T> {
+        self.0.remove(normalize(name).as_ref())
+    }
+
+    /// Returns the normalized map.
+    pub fn into_inner(self) -> NamedMap<T> {
+        self.0
+    }
+}
+
+impl<T> Default for NormalizedNamedMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Read-only: mutable access could insert names that are not normalized.
+impl<T> Deref for NormalizedNamedMap<T> {
+    type Target = NamedMap<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<NormalizedNamedMap<T>> for NamedMap<T> {
+    fn from(map: NormalizedNamedMap<T>) -> Self {
+        map.0
+    }
+}
+
+impl<T> FromIterator<(String, T)> for NormalizedNamedMap<T> {
+    /// Collects pairs; a later name equal after normalization replaces the
+    /// earlier value.
+    fn from_iter<I: IntoIterator<Item = (String, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (name, value) in iter {
+            map.insert(name, value);
+        }
+        map
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::adaptive::map::{Entry, HashMap};
+    use crate::adaptive::seq::{
+        cautious_capacity, deserialize_adaptive, next_named_value, Key, KeySeed, SeqItem,
+    };
+    use crate::adaptive::FromName;
+    use alloc::string::ToString;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::{
+        de::{self, Deserializer, MapAccess, SeqAccess, Visitor},
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+
+    /// Displays a name's UTF-8 bytes as an escaped byte string, e.g.
+    /// `b"cafe\xcc\x81"`.
+    struct Bytes<'a>(&'a str);
+
+    impl fmt::Display for Bytes<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("b\"")?;
+            for byte in self.0.bytes() {
+                fmt::Display::fmt(&core::ascii::escape_default(byte), f)?;
+            }
+            f.write_str("\"")
+        }
+    }
+
+    /// Collects normalized entries while remembering how each name was
+    /// spelled in the input, to report merges.
+    struct Builder<T> {
+        entries: HashMap<String, (String, T)>,
+    }
+
+    impl<T> Builder<T> {
+        fn with_capacity(capacity: usize) -> Self {
+            Self {
+                entries: HashMap::with_capacity(capacity),
+            }
+        }
+
+        fn insert<E: de::Error>(&mut self, name: String, value: T) -> Result<(), E> {
+            match self.entries.entry(normalize(&name).into_owned()) {
+                Entry::Occupied(entry) if entry.get().0 == name => {
+                    Err(E::custom(format_args!("duplicate name {:?}", name)))
+                }
+                Entry::Occupied(entry) => Err(E::custom(format_args!(
+                    "names {:?} ({}) and {:?} ({}) are the same after NFC normalization",
+                    entry.get().0,
+                    Bytes(&entry.get().0),
+                    name,
+                    Bytes(&name)
+                ))),
+                Entry::Vacant(entry) => {
+                    entry.insert((name, value));
+                    Ok(())
+                }
+            }
+        }
+
+        fn finish(self) -> NormalizedNamedMap<T> {
+            NormalizedNamedMap(
+                self.entries
+                    .into_iter()
+                    .map(|(name, (_, value))| (name, value))
+                    .collect(),
+            )
+        }
+    }
+
+    struct NormalizedNamedMapVisitor<T> {
+        /// Whether the input can be driven with `deserialize_any`.
+        self_describing: bool,
+        _phantom: PhantomData<T>,
+    }
+
+    impl<'de, T> Visitor<'de> for NormalizedNamedMapVisitor<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        type Value = NormalizedNamedMap<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a map (e.g., {{ \"a\": {{...}} }}), a sequence of names and single-key maps (e.g., [\"a\", {{ \"b\": {{...}} }}]), or a single name (e.g., \"a\")")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            self.visit_string(v.to_string())
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if v.is_empty() {
+                return Err(de::Error::invalid_value(
+                    de::Unexpected::Str(&v),
+                    &"a non-empty name",
+                ));
+            }
+            let value = T::from_name(&v);
+            let mut map = NormalizedNamedMap::new();
+            map.insert(v, value);
+            Ok(map)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut builder =
+                Builder::with_capacity(cautious_capacity::<(String, T)>(seq.size_hint()));
+            while let Some(item) = seq.next_element::<SeqItem<T>>()? {
+                let (name, value) = item.into_entry();
+                builder.insert(name, value)?;
+            }
+            Ok(builder.finish())
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut builder =
+                Builder::with_capacity(cautious_capacity::<(String, T)>(access.size_hint()));
+            let seed = KeySeed {
+                self_describing: self.self_describing,
+            };
+            while let Some(Key(name)) = access.next_key_seed(seed)? {
+                let value = next_named_value(&mut access, &name)?;
+                builder.insert(name.into_owned(), value)?;
+            }
+            Ok(builder.finish())
+        }
+    }
+
+    impl<T> Serialize for NormalizedNamedMap<T>
+    where
+        T: Serialize,
+    {
+        /// Serializes as an object keyed by the normalized names.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_map(self.0.iter())
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for NormalizedNamedMap<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        /// Deserializes from the same forms as [`NamedMap`], normalizing
+        /// every name; names that merge under normalization are rejected.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let self_describing = deserializer.is_human_readable();
+            deserialize_adaptive(
+                deserializer,
+                NormalizedNamedMapVisitor {
+                    self_describing,
+                    _phantom: PhantomData,
+                },
+            )
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "serde")]
+    use crate::adaptive::FromName;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+
+    const COMPOSED: &str = "caf\u{e9}";
+    const DECOMPOSED: &str = "cafe\u{301}";
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    struct Shop {
+        open: bool,
+    }
+
+    #[cfg(feature = "serde")]
+    impl FromName for Shop {
+        fn from_name(_name: &str) -> Self {
+            Self { open: true }
+        }
+    }
+
+    #[test]
+    fn test_insert_and_lookup_normalize() {
+        let mut map = NormalizedNamedMap::new();
+        assert_eq!(
+            map.insert(DECOMPOSED.to_string(), Shop { open: true }),
+            None
+        );
+        assert!(map.0.contains_key(COMPOSED));
+        assert_eq!(map.get(COMPOSED), map.get(DECOMPOSED));
+        assert_eq!(
+            map.insert(COMPOSED.to_string(), Shop { open: false }),
+            Some(Shop { open: true })
+        );
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.remove(DECOMPOSED), Some(Shop { open: false }));
+        assert!(map.is_empty());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_normalizes_both_forms() {
+        let json = format!(r#"{{"{}": {{"open": false}}}}"#, DECOMPOSED);
+        let map: NormalizedNamedMap<Shop> = serde_json::from_str(&json).unwrap();
+        assert_eq!(map[COMPOSED], Shop { open: false });
+        assert_eq!(
+            serde_json::to_value(&map).unwrap(),
+            serde_json::json!({ COMPOSED: {"open": false} })
+        );
+
+        let json = format!(r#"["{}", "tea"]"#, DECOMPOSED);
+        let map: NormalizedNamedMap<Shop> = serde_json::from_str(&json).unwrap();
+        assert!(map.contains_key(COMPOSED));
+        assert!(map.contains_key(DECOMPOSED));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_merge_shows_both_byte_sequences() {
+        let expected = format!(
+            r#"names {:?} (b"caf\xc3\xa9") and {:?} (b"cafe\xcc\x81") are the same after NFC normalization"#,
+            COMPOSED, DECOMPOSED
+        );
+
+        let json = format!(
+            r#"{{"{}": {{"open": true}}, "{}": {{"open": false}}}}"#,
+            COMPOSED, DECOMPOSED
+        );
+        let err = serde_json::from_str::<NormalizedNamedMap<Shop>>(&json).unwrap_err();
+        assert!(err.to_string().contains(&expected), "{}", err);
+
+        let json = format!(r#"["{}", "{}"]"#, COMPOSED, DECOMPOSED);
+        let err = serde_json::from_str::<NormalizedNamedMap<Shop>>(&json).unwrap_err();
+        assert!(err.to_string().contains(&expected), "{}", err);
+    }
+}
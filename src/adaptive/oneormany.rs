@@ -0,0 +1,390 @@
+// flexicon/src/adaptive/oneormany.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fields written as either a single value or a list of values.
+
+use alloc::vec::{self, Vec};
+use core::ops::{Deref, DerefMut};
+use core::slice;
+
+/// A list that also accepts a single bare value: `tags = "a"` reads the
+/// same as `tags = ["a"]`.
+///
+/// With the `serde` feature it deserializes from either form and always
+/// serializes as a sequence. A sequence is always read as the list, so a
+/// `T` that is itself a sequence must be written inside one. Formats that
+/// are not human-readable (bincode, postcard, ...) only accept the
+/// sequence `Serialize` writes.
+///
+/// The list dereferences to `Vec<T>` (and so to `[T]`).
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// use flexicon::adaptive::OneOrMany;
+///
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() {
+/// let one: OneOrMany<String> = serde_json::from_str(r#""a""#).unwrap();
+/// let many: OneOrMany<String> = serde_json::from_str(r#"["a", "b"]"#).unwrap();
+/// assert_eq!(one.len(), 1);
+/// assert_eq!(many[1], "b");
+/// assert_eq!(serde_json::to_string(&one).unwrap(), r#"["a"]"#);
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OneOrMany<T>(Vec<T>);
+
+impl<T> OneOrMany<T> {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Returns the values as a `Vec`.
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Iterates over the values in order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.0.iter()
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for OneOrMany<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for OneOrMany<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<Vec<T>> for OneOrMany<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self(values)
+    }
+}
+
+impl<T> From<OneOrMany<T>> for Vec<T> {
+    fn from(values: OneOrMany<T>) -> Self {
+        values.0
+    }
+}
+
+impl<T> FromIterator<T> for OneOrMany<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<T> for OneOrMany<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for OneOrMany<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a OneOrMany<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut OneOrMany<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use alloc::string::String;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::{
+        de::{
+            self,
+            value::{EnumAccessDeserializer, MapAccessDeserializer, SeqAccessDeserializer},
+            Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+        },
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+
+    /// Reads a sequence as the list and anything else as its only value.
+    struct OneOrManyVisitor<T> {
+        _phantom: PhantomData<T>,
+    }
+
+    impl<T> OneOrManyVisitor<T> {
+        fn one<'de, D>(deserializer: D) -> Result<OneOrMany<T>, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de>,
+        {
+            Ok(OneOrMany(alloc::vec![T::deserialize(deserializer)?]))
+        }
+    }
+
+    macro_rules! visit_scalar {
+        ($($method:ident($ty:ty)),*) => {
+            $(
+                fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    Self::one(v.into_deserializer())
+                }
+            )*
+        };
+    }
+
+    impl<'de, T> Visitor<'de> for OneOrManyVisitor<T>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = OneOrMany<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a value or a sequence of values")
+        }
+
+        visit_scalar!(
+            visit_bool(bool),
+            visit_i64(i64),
+            visit_i128(i128),
+            visit_u64(u64),
+            visit_u128(u128),
+            visit_f64(f64),
+            visit_char(char),
+            visit_str(&str),
+            visit_string(String),
+            visit_bytes(&[u8])
+        );
+
+        fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Self::one(de::value::BorrowedStrDeserializer::new(v))
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Self::one(().into_deserializer())
+        }
+
+        /// RON may present the value wrapped in an option; the wrapper
+        /// carries no meaning here.
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            Vec::deserialize(SeqAccessDeserializer::new(seq)).map(OneOrMany)
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            Self::one(MapAccessDeserializer::new(map))
+        }
+
+        fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+        where
+            A: EnumAccess<'de>,
+        {
+            Self::one(EnumAccessDeserializer::new(data))
+        }
+    }
+
+    impl<T: Serialize> Serialize for OneOrMany<T> {
+        /// Serializes as a sequence, even with a single value.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(&self.0)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for OneOrMany<T>
+    where
+        T: Deserialize<'de>,
+    {
+        /// Deserializes from a sequence of values or a single bare value.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let visitor = OneOrManyVisitor {
+                _phantom: PhantomData,
+            };
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(visitor)
+            } else {
+                deserializer.deserialize_seq(visitor)
+            }
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+
+    #[test]
+    fn test_collection_api() {
+        let mut tags: OneOrMany<String> = ["a", "b"].iter().map(|tag| tag.to_string()).collect();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags.iter().next().map(String::as_str), Some("a"));
+        tags.push("c".to_string());
+        tags.extend(["d".to_string()]);
+        assert_eq!(tags.first().map(String::as_str), Some("a"));
+        for tag in &mut tags {
+            tag.make_ascii_uppercase();
+        }
+        assert_eq!(tags.clone().into_vec(), ["A", "B", "C", "D"]);
+        assert_eq!(Vec::from(tags), ["A", "B", "C", "D"]);
+        assert!(OneOrMany::<u8>::default().is_empty());
+        assert_eq!(OneOrMany::from(vec![1, 2]).into_iter().sum::<i32>(), 3);
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod serde_tests {
+        use super::*;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Service {
+            tags: OneOrMany<String>,
+            #[serde(default)]
+            ports: OneOrMany<u16>,
+        }
+
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct Endpoint {
+            host: String,
+        }
+
+        #[test]
+        fn test_json_forms() {
+            let one: Service = serde_json::from_str(r#"{"tags": "web", "ports": 80}"#).unwrap();
+            assert_eq!(one.tags.into_vec(), ["web"]);
+            assert_eq!(one.ports.into_vec(), [80]);
+
+            let many: Service =
+                serde_json::from_str(r#"{"tags": ["web", "api"], "ports": [80, 443]}"#).unwrap();
+            assert_eq!(many.tags.into_vec(), ["web", "api"]);
+            assert_eq!(many.ports.into_vec(), [80, 443]);
+
+            let empty: Service = serde_json::from_str(r#"{"tags": []}"#).unwrap();
+            assert!(empty.tags.is_empty());
+            assert!(empty.ports.is_empty());
+
+            let endpoints: OneOrMany<Endpoint> =
+                serde_json::from_str(r#"{"host": "a.example"}"#).unwrap();
+            assert_eq!(endpoints[0].host, "a.example");
+
+            let err = serde_json::from_str::<Service>(r#"{"tags": 7}"#).unwrap_err();
+            assert!(err.to_string().contains("expected a string"), "{}", err);
+        }
+
+        #[test]
+        fn test_json_single_value_round_trip() {
+            let service: Service = serde_json::from_str(r#"{"tags": "web"}"#).unwrap();
+            let json = serde_json::to_string(&service).unwrap();
+            assert_eq!(json, r#"{"tags":["web"],"ports":[]}"#);
+            assert_eq!(serde_json::from_str::<Service>(&json).unwrap(), service);
+
+            let bytes = bincode::serialize(&service).unwrap();
+            assert_eq!(bincode::deserialize::<Service>(&bytes).unwrap(), service);
+        }
+
+        #[test]
+        fn test_toml_forms() {
+            let one: Service = toml::from_str("tags = \"web\"\nports = 80\n").unwrap();
+            assert_eq!(one.tags.into_vec(), ["web"]);
+            assert_eq!(one.ports.into_vec(), [80]);
+
+            let many: Service =
+                toml::from_str("tags = [\"web\", \"api\"]\nports = [80, 443]\n").unwrap();
+            assert_eq!(many.tags.len(), 2);
+            assert_eq!(many.ports[1], 443);
+
+            let empty: Service = toml::from_str("tags = []\n").unwrap();
+            assert!(empty.tags.is_empty());
+
+            #[derive(Debug, Deserialize)]
+            struct Upstream {
+                endpoints: OneOrMany<Endpoint>,
+            }
+            let table: Upstream = toml::from_str("[endpoints]\nhost = \"a.example\"\n").unwrap();
+            assert_eq!(table.endpoints.len(), 1);
+            let array: Upstream = toml::from_str(
+                "[[endpoints]]\nhost = \"a.example\"\n[[endpoints]]\nhost = \"b.example\"\n",
+            )
+            .unwrap();
+            assert_eq!(array.endpoints[1].host, "b.example");
+        }
+
+        #[test]
+        fn test_toml_single_value_round_trip() {
+            let service: Service = toml::from_str("tags = \"web\"\n").unwrap();
+            let text = toml::to_string(&service).unwrap();
+            assert_eq!(text, "tags = [\"web\"]\nports = []\n");
+            assert_eq!(toml::from_str::<Service>(&text).unwrap(), service);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+// flexicon/src/adaptive/openapi.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+use utoipa::openapi::schema::{AnyOfBuilder, ArrayBuilder, ObjectBuilder, Schema, Type};
+use utoipa::openapi::{Ref, RefOr};
+use utoipa::{PartialSchema, ToSchema};
+
+use super::NamedMap;
+
+/// The same shape as the `JsonSchema` impl: an `anyOf` of the detailed
+/// object (values reference `T`'s component), the (mixed) array of names
+/// and single-key objects, and a single name.
+impl<T: ToSchema> PartialSchema for NamedMap<T> {
+    fn schema() -> RefOr<Schema> {
+        let name = || {
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .min_length(Some(1))
+        };
+        let entries = || {
+            ObjectBuilder::new()
+                .schema_type(Type::Object)
+                .additional_properties(Some(Ref::from_schema_name(T::name())))
+        };
+        AnyOfBuilder::new()
+            .item(entries())
+            .item(
+                ArrayBuilder::new().items(
+                    AnyOfBuilder::new()
+                        .item(name())
+                        .item(entries().min_properties(Some(1)).max_properties(Some(1))),
+                ),
+            )
+            .item(name())
+            .into()
+    }
+}
+
+impl<T: ToSchema> ToSchema for NamedMap<T> {
+    /// `NamedMap_<T>`, so different instantiations get distinct components.
+    fn name() -> Cow<'static, str> {
+        format!("NamedMap_{}", T::name()).into()
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        schemas.push((T::name().into_owned(), T::schema()));
+        T::schemas(schemas);
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use utoipa::openapi::{ComponentsBuilder, OpenApiBuilder};
+
+    #[derive(ToSchema)]
+    #[allow(dead_code)]
+    struct Interface {
+        version: String,
+    }
+
+    #[derive(ToSchema)]
+    #[allow(dead_code)]
+    struct Plugin {
+        enabled: bool,
+    }
+
+    #[test]
+    fn test_openapi_snapshot() {
+        let mut schemas = Vec::new();
+        NamedMap::<Interface>::schemas(&mut schemas);
+        let doc = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema_from::<NamedMap<Interface>>()
+                    .schemas_from_iter(schemas)
+                    .build(),
+            ))
+            .build();
+        let value = serde_json::to_value(&doc).unwrap();
+
+        assert_eq!(
+            value["components"]["schemas"]["NamedMap_Interface"],
+            json!({
+                "anyOf": [
+                    {
+                        "type": "object",
+                        "additionalProperties": { "$ref": "#/components/schemas/Interface" }
+                    },
+                    {
+                        "type": "array",
+                        "items": {
+                            "anyOf": [
+                                { "type": "string", "minLength": 1 },
+                                {
+                                    "type": "object",
+                                    "additionalProperties": {
+                                        "$ref": "#/components/schemas/Interface"
+                                    },
+                                    "minProperties": 1,
+                                    "maxProperties": 1
+                                }
+                            ]
+                        }
+                    },
+                    { "type": "string", "minLength": 1 }
+                ]
+            })
+        );
+        assert!(value["components"]["schemas"]["Interface"].is_object());
+    }
+
+    #[test]
+    fn test_component_names_are_distinct() {
+        assert_eq!(NamedMap::<Interface>::name(), "NamedMap_Interface");
+        assert_ne!(NamedMap::<Interface>::name(), NamedMap::<Plugin>::name());
+    }
+}
@@ -0,0 +1,476 @@
+// flexicon/src/adaptive/orderednamedmap.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use indexmap::map::Entry;
+use indexmap::IndexMap;
+use std::borrow::Cow;
+use std::ops::{Deref, DerefMut};
+
+use super::{DuplicateNameError, DuplicatePolicy, FromName, NamePolicy, NamedMap};
+
+/// An insertion-ordered variant of [`NamedMap`], backed by `IndexMap<String, T>`.
+///
+/// Accepts the same dual formats as `NamedMap`, but remembers the order in
+/// which entries were declared. Use it when entries are processed in
+/// sequence (middleware chains, interface bring-up order, etc.).
+///
+/// Iteration and serialization follow insertion order.
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # #[derive(Clone, Serialize, Deserialize)]
+/// # struct Layer { enabled: bool }
+/// # impl flexicon::adaptive::FromName for Layer {
+/// #     fn from_name(_name: &str) -> Self { Self { enabled: true } }
+/// # }
+/// use flexicon::adaptive::OrderedNamedMap;
+///
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() -> Result<(), serde_json::Error> {
+/// let chain: OrderedNamedMap<Layer> = serde_json::from_str(r#"["auth", "cors", "gzip"]"#)?;
+/// let names: Vec<&str> = chain.keys().map(String::as_str).collect();
+/// assert_eq!(names, ["auth", "cors", "gzip"]);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderedNamedMap<T>(IndexMap<String, T>);
+
+impl<T> OrderedNamedMap<T> {
+    /// Creates an empty `OrderedNamedMap`.
+    pub fn new() -> Self {
+        Self(IndexMap::new())
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// A new key is appended at the end; an existing key keeps its position.
+    pub fn insert(&mut self, key: String, value: T) {
+        self.0.insert(key, value);
+    }
+
+    /// Returns `true` if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Consumes the map and returns the inner `IndexMap`.
+    pub fn into_inner(self) -> IndexMap<String, T> {
+        self.0
+    }
+
+    /// Returns a reference to the inner map.
+    pub fn as_inner(&self) -> &IndexMap<String, T> {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the inner map.
+    ///
+    /// ⚠️ **Warning**: Direct mutation bypasses any future validation or invariants
+    /// that `OrderedNamedMap` might enforce.
+    /// Prefer using `insert` or higher-level APIs when possible.
+    pub fn as_inner_mut(&mut self) -> &mut IndexMap<String, T> {
+        &mut self.0
+    }
+}
+
+impl<T> Default for OrderedNamedMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<IndexMap<String, T>> for OrderedNamedMap<T> {
+    fn from(map: IndexMap<String, T>) -> Self {
+        OrderedNamedMap(map)
+    }
+}
+
+// Conversions to and from the unordered map. Going from `NamedMap` the
+// resulting order is the (arbitrary) iteration order of the `HashMap`.
+impl<T> From<NamedMap<T>> for OrderedNamedMap<T> {
+    fn from(map: NamedMap<T>) -> Self {
+        OrderedNamedMap(map.into_iter().collect())
+    }
+}
+
+impl<T> From<OrderedNamedMap<T>> for NamedMap<T> {
+    fn from(map: OrderedNamedMap<T>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<T> From<IndexMap<String, T>> for NamedMap<T> {
+    fn from(map: IndexMap<String, T>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<T> From<NamedMap<T>> for IndexMap<String, T> {
+    fn from(map: NamedMap<T>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<T> Deref for OrderedNamedMap<T> {
+    type Target = IndexMap<String, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for OrderedNamedMap<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> IntoIterator for OrderedNamedMap<T> {
+    type Item = (String, T);
+    type IntoIter = indexmap::map::IntoIter<String, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a OrderedNamedMap<T> {
+    type Item = (&'a String, &'a T);
+    type IntoIter = indexmap::map::Iter<'a, String, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut OrderedNamedMap<T> {
+    type Item = (&'a String, &'a mut T);
+    type IntoIter = indexmap::map::IterMut<'a, String, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+/// Inserts `name` → `value`, the `index`-th entry of its list, applying
+/// `policy` if `name` is already present. A replaced entry keeps its position.
+fn insert_with_policy<T>(
+    map: &mut IndexMap<String, T>,
+    policy: DuplicatePolicy,
+    name: String,
+    value: T,
+    index: usize,
+) -> Result<(), DuplicateNameError> {
+    match map.entry(name) {
+        Entry::Vacant(entry) => {
+            entry.insert(value);
+        }
+        Entry::Occupied(mut entry) => {
+            if policy.replaces(entry.key(), index)? {
+                entry.insert(value);
+            }
+        }
+    }
+    Ok(())
+}
+
+// Allow construction from a simple list of names, keeping the list order.
+// Lenient like `NamedMap`'s: empty names are skipped, and a repeated name
+// keeps the position of its first occurrence.
+impl<T: FromName + Clone> From<Vec<String>> for OrderedNamedMap<T> {
+    fn from(list: Vec<String>) -> Self {
+        let mut map = IndexMap::with_capacity(list.len());
+        for (index, name) in list.into_iter().enumerate() {
+            let Ok(Some(name)) = NamePolicy::SkipEmpty.check(Cow::Owned(name)) else {
+                continue;
+            };
+            let value = T::from_name(&name);
+            // `KeepLast` never fails.
+            let _ = insert_with_policy(
+                &mut map,
+                DuplicatePolicy::KeepLast,
+                name.into_owned(),
+                value,
+                index,
+            );
+        }
+        OrderedNamedMap(map)
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::adaptive::namedmap::{NamedMapVisitor, Place};
+    use crate::adaptive::seq::{deserialize_adaptive, next_named_value, SeqItem};
+    use serde::{
+        de::{self, DeserializeOwned, Deserializer, MapAccess, SeqAccess},
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+
+    // Keeps entries in the order `NamedMapVisitor` reads them.
+    impl<T: FromName> Place for IndexMap<String, T> {
+        type Value = T;
+        type Meta = ();
+
+        fn name_meta() {}
+
+        fn from_name<E: de::Error>(name: &str) -> Result<T, E> {
+            Ok(T::from_name(name))
+        }
+
+        fn next_item<'de, A>(
+            seq: &mut A,
+            _nullable: bool,
+        ) -> Result<Option<(SeqItem<T>, ())>, A::Error>
+        where
+            A: SeqAccess<'de>,
+            T: Deserialize<'de>,
+        {
+            Ok(seq.next_element::<SeqItem<T>>()?.map(|item| (item, ())))
+        }
+
+        fn next_value<'de, A>(
+            access: &mut A,
+            name: &str,
+            _nullable: bool,
+        ) -> Result<(T, ()), A::Error>
+        where
+            A: MapAccess<'de>,
+            T: Deserialize<'de>,
+        {
+            Ok((next_named_value(access, name)?, ()))
+        }
+
+        fn clear(&mut self) {
+            IndexMap::clear(self);
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            IndexMap::reserve(self, additional);
+        }
+
+        fn contains_key(&self, name: &str) -> bool {
+            IndexMap::contains_key(self, name)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn len(&self) -> usize {
+            IndexMap::len(self)
+        }
+
+        fn insert(
+            &mut self,
+            policy: DuplicatePolicy,
+            name: String,
+            value: T,
+            _meta: (),
+            index: usize,
+        ) -> Result<(), DuplicateNameError> {
+            insert_with_policy(self, policy, name, value, index)
+        }
+    }
+
+    impl<T> Serialize for OrderedNamedMap<T>
+    where
+        T: Serialize,
+    {
+        /// Serializes as an object whose keys follow insertion order.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_map(&self.0)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for OrderedNamedMap<T>
+    where
+        T: DeserializeOwned + FromName + Clone,
+    {
+        /// Deserializes from the same forms as `NamedMap`, with the same
+        /// checks, keeping entries in the order they were written.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let self_describing = deserializer.is_human_readable();
+            let mut map = IndexMap::new();
+            deserialize_adaptive(
+                deserializer,
+                NamedMapVisitor {
+                    place: &mut map,
+                    policy: DuplicatePolicy::Error,
+                    names: NamePolicy::RejectEmpty,
+                    nullable: false,
+                    self_describing,
+                },
+            )?;
+            Ok(OrderedNamedMap(map))
+        }
+    }
+}
+
+// === JSON-SPECIFIC CONVENIENCE METHODS ===
+
+#[cfg(feature = "serde_json")]
+impl<T> OrderedNamedMap<T>
+where
+    T: serde::Serialize,
+{
+    /// Serialize this map to a `serde_json::Value`.
+    ///
+    /// Note that `serde_json::Value` only keeps key order when `serde_json`'s
+    /// `preserve_order` feature is enabled; use `to_json_string` otherwise.
+    pub fn to_json_value(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(self)
+    }
+
+    /// Serialize this map to a compact JSON string, in insertion order.
+    pub fn to_json_string(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> OrderedNamedMap<T>
+where
+    T: for<'de> serde::Deserialize<'de> + FromName + Clone,
+{
+    /// Parse an `OrderedNamedMap` from a `serde_json::Value`.
+    ///
+    /// Supports the same formats as the general `Deserialize` impl.
+    pub fn from_json_value(value: serde_json::Value) -> serde_json::Result<Self> {
+        serde_json::from_value(value)
+    }
+
+    /// Parse an `OrderedNamedMap` from a JSON string.
+    ///
+    /// Unlike `from_json_value`, this parses the text directly, so object key
+    /// order is preserved regardless of `serde_json` features.
+    pub fn from_json_str(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    struct TestItem {
+        value: String,
+    }
+
+    impl FromName for TestItem {
+        fn from_name(name: &str) -> Self {
+            Self {
+                value: format!("from_name({})", name),
+            }
+        }
+    }
+
+    fn names<T>(map: &OrderedNamedMap<T>) -> Vec<&str> {
+        map.keys().map(String::as_str).collect()
+    }
+
+    #[test]
+    fn test_from_vec_keeps_order() {
+        let map: OrderedNamedMap<TestItem> =
+            OrderedNamedMap::from(["c", "", "a", "b", "c"].map(String::from).to_vec());
+        assert_eq!(names(&map), ["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_named_map_conversions() {
+        let ordered: OrderedNamedMap<TestItem> =
+            OrderedNamedMap::from(vec!["x".to_string(), "y".to_string()]);
+        let unordered: NamedMap<TestItem> = ordered.clone().into();
+        assert_eq!(unordered.len(), 2);
+        assert_eq!(unordered["x"], TestItem::from_name("x"));
+
+        let back: OrderedNamedMap<TestItem> = unordered.into();
+        assert_eq!(back.len(), 2);
+        assert_eq!(back["y"], ordered["y"]);
+    }
+
+    #[test]
+    fn test_index_map_conversions() {
+        let mut index = IndexMap::new();
+        index.insert("b".to_string(), TestItem::from_name("b"));
+        index.insert("a".to_string(), TestItem::from_name("a"));
+        let map = NamedMap::from(index);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["a"], TestItem::from_name("a"));
+
+        let index = IndexMap::from(map);
+        assert_eq!(index.len(), 2);
+        assert_eq!(index["b"], TestItem::from_name("b"));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_array_order_roundtrip() {
+        let map = OrderedNamedMap::<TestItem>::from_json_str(r#"["zeta", "alpha", "mid"]"#).unwrap();
+        assert_eq!(names(&map), ["zeta", "alpha", "mid"]);
+
+        let json = map.to_json_string().unwrap();
+        assert!(json.find("zeta").unwrap() < json.find("alpha").unwrap());
+        let restored = OrderedNamedMap::<TestItem>::from_json_str(&json).unwrap();
+        assert_eq!(names(&restored), ["zeta", "alpha", "mid"]);
+        assert_eq!(map, restored);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_toml_array_order_roundtrip() {
+        #[derive(Serialize, Deserialize)]
+        struct Manifest {
+            chain: OrderedNamedMap<TestItem>,
+        }
+
+        let manifest: Manifest = toml::from_str(r#"chain = ["zeta", "alpha", "mid"]"#).unwrap();
+        assert_eq!(names(&manifest.chain), ["zeta", "alpha", "mid"]);
+
+        let toml_str = toml::to_string(&manifest).unwrap();
+        let restored: Manifest = toml::from_str(&toml_str).unwrap();
+        assert_eq!(names(&restored.chain), ["zeta", "alpha", "mid"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_toml_checked_like_named_map() {
+        #[derive(Debug, Deserialize)]
+        struct Manifest {
+            chain: OrderedNamedMap<TestItem>,
+        }
+
+        let manifest: Manifest = toml::from_str(r#"chain = "auth""#).unwrap();
+        assert_eq!(names(&manifest.chain), ["auth"]);
+
+        let err = toml::from_str::<Manifest>(r#"chain = ["auth", "cors", "auth"]"#).unwrap_err();
+        assert_eq!(err.message(), "duplicate name \"auth\" at index 2");
+
+        let err = toml::from_str::<Manifest>(r#"chain = ["auth", ""]"#).unwrap_err();
+        assert_eq!(err.message(), "empty name at index 1");
+    }
+}
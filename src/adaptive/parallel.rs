@@ -0,0 +1,168 @@
+// flexicon/src/adaptive/parallel.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parallel iteration with `rayon`, delegating to the inner `HashMap`.
+
+use core::hash::BuildHasher;
+use rayon::collections::hash_map;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+use super::NamedMap;
+
+impl<T: Send, S> IntoParallelIterator for NamedMap<T, S> {
+    type Iter = hash_map::IntoIter<String, T>;
+    type Item = (String, T);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_inner().into_par_iter()
+    }
+}
+
+impl<'a, T: Sync, S> IntoParallelIterator for &'a NamedMap<T, S> {
+    type Iter = hash_map::Iter<'a, String, T>;
+    type Item = (&'a String, &'a T);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_inner().into_par_iter()
+    }
+}
+
+impl<'a, T: Send, S> IntoParallelIterator for &'a mut NamedMap<T, S> {
+    type Iter = hash_map::IterMut<'a, String, T>;
+    type Item = (&'a String, &'a mut T);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_inner_mut().into_par_iter()
+    }
+}
+
+impl<T: Send, S: BuildHasher + Default + Send> FromParallelIterator<(String, T)>
+    for NamedMap<T, S>
+{
+    /// Collects in parallel; a repeated name keeps one of its values,
+    /// unspecified which.
+    fn from_par_iter<I>(iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = (String, T)>,
+    {
+        NamedMap::from(HashMap::from_par_iter(iter))
+    }
+}
+
+impl<T: Send, S: BuildHasher + Send> ParallelExtend<(String, T)> for NamedMap<T, S> {
+    fn par_extend<I>(&mut self, iter: I)
+    where
+        I: IntoParallelIterator<Item = (String, T)>,
+    {
+        self.as_inner_mut().par_extend(iter);
+    }
+}
+
+impl<T: Send, S: BuildHasher + Clone + Send> NamedMap<T, S> {
+    /// Like [`map_values`](Self::map_values), calling `f` on the entries in
+    /// parallel.
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let ports: NamedMap<u16> = [("http".to_string(), 80), ("ssh".to_string(), 22)]
+    ///     .into_iter()
+    ///     .collect();
+    /// let urls = ports.par_map_values(|name, port| format!("{}://localhost:{}", name, port));
+    /// assert_eq!(urls["ssh"], "ssh://localhost:22");
+    /// ```
+    pub fn par_map_values<U, F>(self, f: F) -> NamedMap<U, S>
+    where
+        U: Send,
+        F: Fn(&str, T) -> U + Sync + Send,
+    {
+        let inner = self.into_inner();
+        let mut map = HashMap::with_capacity_and_hasher(inner.len(), inner.hasher().clone());
+        map.par_extend(inner.into_par_iter().map(|(key, value)| {
+            let value = f(&key, value);
+            (key, value)
+        }));
+        NamedMap::from(map)
+    }
+
+    /// Like [`retain_names`](Self::retain_names), calling `pred` on the
+    /// entries in parallel. The removed entries are returned as a new map.
+    pub fn par_retain_names<F>(&mut self, pred: F) -> NamedMap<T, S>
+    where
+        F: Fn(&str, &T) -> bool + Sync + Send,
+    {
+        let hasher = self.as_inner().hasher().clone();
+        let kept = HashMap::with_capacity_and_hasher(self.len(), hasher.clone());
+        let entries = core::mem::replace(self.as_inner_mut(), kept);
+        let (kept, removed): (Vec<_>, Vec<_>) = entries
+            .into_par_iter()
+            .partition(|(key, value)| pred(key, value));
+        self.as_inner_mut().extend(kept);
+        let mut map = HashMap::with_capacity_and_hasher(removed.len(), hasher);
+        map.extend(removed);
+        NamedMap::from(map)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ports(size: u32) -> NamedMap<u32> {
+        (0..size).map(|i| (format!("port{}", i), i)).collect()
+    }
+
+    #[test]
+    fn test_mutate_values_in_parallel() {
+        let mut map = ports(1000);
+        map.par_iter_mut().for_each(|(_, port)| *port += 10_000);
+        assert!(map
+            .iter()
+            .all(|(name, port)| *name == format!("port{}", port - 10_000)));
+
+        let total: u32 = map.par_iter().map(|(_, port)| *port).sum();
+        assert_eq!(total, (10_000..11_000).sum::<u32>());
+    }
+
+    #[test]
+    fn test_collect_pairs_in_parallel() {
+        let map: NamedMap<u32> = (0..1000u32)
+            .into_par_iter()
+            .map(|i| (format!("port{}", i), i))
+            .collect();
+        assert_eq!(map, ports(1000));
+
+        let doubled: NamedMap<u32> = map
+            .into_par_iter()
+            .map(|(name, port)| (name, port * 2))
+            .collect();
+        assert_eq!(doubled["port21"], 42);
+    }
+
+    #[test]
+    fn test_par_map_values_and_retain_names() {
+        let map = ports(100).par_map_values(|name, port| format!("{}={}", name, port));
+        assert_eq!(map.len(), 100);
+        assert_eq!(map["port7"], "port7=7");
+
+        let mut map = ports(100);
+        let removed = map.par_retain_names(|_, port| port % 2 == 0);
+        assert_eq!(map.len(), 50);
+        assert_eq!(removed.len(), 50);
+        assert!(map.values().all(|port| port % 2 == 0));
+        assert!(removed.contains_key("port99"));
+
+        let mut extended = ports(2);
+        extended.par_extend(vec![("port9".to_string(), 9)]);
+        assert_eq!(extended.len(), 3);
+    }
+}
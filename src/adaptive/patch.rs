@@ -0,0 +1,366 @@
+// flexicon/src/adaptive/patch.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! JSON Merge Patch (RFC 7386) applied entry by entry, and JSON Patch
+//! (RFC 6902) behind the `json-patch` feature.
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+use super::{FromName, NamePolicy, NamedMap};
+
+/// Why a patch could not be applied to a [`NamedMap`].
+#[derive(Debug)]
+pub enum PatchError {
+    /// The merge patch as a whole was not a JSON object.
+    NotAnObject,
+    /// A key of the merge patch is empty or whitespace-only.
+    EmptyKey {
+        /// The rejected key.
+        key: String,
+    },
+    /// An entry could not be converted to or from JSON.
+    InvalidEntry {
+        /// The entry's name.
+        key: String,
+        /// The underlying serde error.
+        source: serde_json::Error,
+    },
+    /// A JSON Patch operation failed (including a `test` that did not match).
+    #[cfg(feature = "json-patch")]
+    Operation(json_patch::PatchError),
+    /// The patched document is no longer a valid map.
+    #[cfg(feature = "json-patch")]
+    InvalidMap(serde_json::Error),
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchError::NotAnObject => f.write_str("merge patch must be a JSON object"),
+            PatchError::EmptyKey { key } => write!(f, "empty key {:?}", key),
+            PatchError::InvalidEntry { key, source } => {
+                write!(f, "error in entry {:?}: {}", key, source)
+            }
+            #[cfg(feature = "json-patch")]
+            PatchError::Operation(err) => write!(f, "patch operation {}", err),
+            #[cfg(feature = "json-patch")]
+            PatchError::InvalidMap(err) => write!(f, "patched map is invalid: {}", err),
+        }
+    }
+}
+
+impl Error for PatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PatchError::NotAnObject | PatchError::EmptyKey { .. } => None,
+            PatchError::InvalidEntry { source, .. } => Some(source),
+            #[cfg(feature = "json-patch")]
+            PatchError::Operation(err) => Some(err),
+            #[cfg(feature = "json-patch")]
+            PatchError::InvalidMap(err) => Some(err),
+        }
+    }
+}
+
+/// The `MergePatch(Target, Patch)` function from RFC 7386, section 2.
+fn merge_patch(target: &mut Value, patch: Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch;
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let Value::Object(target) = target else {
+        unreachable!("replaced by an object above");
+    };
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(&key);
+        } else {
+            merge_patch(target.entry(key).or_insert(Value::Null), value);
+        }
+    }
+}
+
+impl<T> NamedMap<T>
+where
+    T: Serialize + DeserializeOwned + FromName,
+{
+    /// Applies a JSON Merge Patch (RFC 7386) whose keys are entry names.
+    ///
+    /// - `null` removes the entry;
+    /// - an object deep-merges into the entry's JSON form, which is then
+    ///   deserialized back; a missing entry starts from `T::from_name`;
+    /// - any other value replaces the entry outright.
+    ///
+    /// Empty and whitespace-only keys are rejected
+    /// ([`NamePolicy::RejectEmpty`]). The patch is applied atomically: on error the map is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flexicon::adaptive::{FromName, NamedMap};
+    /// # use serde::{Deserialize, Serialize};
+    /// # #[derive(Clone, Serialize, Deserialize)]
+    /// # struct Plugin { enabled: bool }
+    /// # impl FromName for Plugin {
+    /// #     fn from_name(_name: &str) -> Self { Plugin { enabled: true } }
+    /// # }
+    /// let mut map = NamedMap::<Plugin>::from(vec!["logger".to_string(), "http".to_string()]);
+    /// map.apply_merge_patch(serde_json::json!({
+    ///     "logger": {"enabled": false},
+    ///     "http": null,
+    /// }))
+    /// .unwrap();
+    /// assert!(!map["logger"].enabled);
+    /// assert!(!map.contains_key("http"));
+    /// ```
+    pub fn apply_merge_patch(&mut self, patch: Value) -> Result<(), PatchError> {
+        let Value::Object(patch) = patch else {
+            return Err(PatchError::NotAnObject);
+        };
+
+        let mut updates = Vec::with_capacity(patch.len());
+        for (key, entry_patch) in patch {
+            let key = match NamePolicy::RejectEmpty.check(Cow::Owned(key)) {
+                Ok(Some(key)) => key.into_owned(),
+                Ok(None) => continue,
+                Err(key) => {
+                    return Err(PatchError::EmptyKey {
+                        key: key.into_owned(),
+                    })
+                }
+            };
+            if entry_patch.is_null() {
+                updates.push((key, None));
+                continue;
+            }
+            let invalid = |source| PatchError::InvalidEntry {
+                key: key.clone(),
+                source,
+            };
+            let mut target = match self.get(&key) {
+                Some(value) => serde_json::to_value(value),
+                None => serde_json::to_value(T::from_name(&key)),
+            }
+            .map_err(invalid)?;
+            merge_patch(&mut target, entry_patch);
+            let value = serde_json::from_value(target).map_err(invalid)?;
+            updates.push((key, Some(value)));
+        }
+
+        for (key, value) in updates {
+            match value {
+                Some(value) => self.insert(key, value),
+                None => self.remove(&key),
+            };
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "json-patch")]
+impl<T> NamedMap<T>
+where
+    T: Serialize + DeserializeOwned + FromName + Clone,
+{
+    /// Applies a JSON Patch (RFC 6902) to the map's JSON form, where paths
+    /// address `/entryName/field/...`.
+    ///
+    /// The operations run against a copy of the map as a `serde_json::Value`,
+    /// which is deserialized back and committed only once every operation
+    /// (`test` included) has succeeded; on error the map is unchanged.
+    pub fn apply_json_patch(&mut self, patch: &json_patch::Patch) -> Result<(), PatchError> {
+        let mut document = self.to_json_value().map_err(PatchError::InvalidMap)?;
+        json_patch::patch(&mut document, patch).map_err(PatchError::Operation)?;
+        *self = Self::from_json_value(document).map_err(PatchError::InvalidMap)?;
+        Ok(())
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Limits {
+        cpu: u32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        memory: Option<u32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Interface {
+        version: String,
+        limits: Limits,
+    }
+
+    impl FromName for Interface {
+        fn from_name(_name: &str) -> Self {
+            Interface {
+                version: "latest".to_string(),
+                limits: Limits {
+                    cpu: 1,
+                    memory: Some(64),
+                },
+            }
+        }
+    }
+
+    fn sample() -> NamedMap<Interface> {
+        NamedMap::from(vec!["logger".to_string(), "http".to_string()])
+    }
+
+    #[test]
+    fn test_null_removes_entry() {
+        let mut map = sample();
+        map.apply_merge_patch(json!({"http": null, "absent": null}))
+            .unwrap();
+        assert_eq!(map.len(), 1);
+        assert!(map.contains_key("logger"));
+    }
+
+    #[test]
+    fn test_nested_field_update() {
+        let mut map = sample();
+        map.apply_merge_patch(json!({"logger": {"limits": {"cpu": 4, "memory": null}}}))
+            .unwrap();
+        let logger = &map["logger"];
+        assert_eq!(logger.version, "latest");
+        assert_eq!(
+            logger.limits,
+            Limits {
+                cpu: 4,
+                memory: None
+            }
+        );
+        assert_eq!(map["http"], Interface::from_name("http"));
+    }
+
+    #[test]
+    fn test_new_entry_starts_from_name() {
+        let mut map = sample();
+        map.apply_merge_patch(json!({"metrics": {"version": "2"}}))
+            .unwrap();
+        assert_eq!(map["metrics"].version, "2");
+        assert_eq!(map["metrics"].limits.cpu, 1);
+    }
+
+    #[test]
+    fn test_invalid_patches() {
+        let mut map = sample();
+        let err = map.apply_merge_patch(json!(["logger"])).unwrap_err();
+        assert!(matches!(err, PatchError::NotAnObject));
+        assert_eq!(err.to_string(), "merge patch must be a JSON object");
+
+        // A non-object entry patch replaces the entry, which must then be a
+        // valid `T`; nothing is applied when it is not.
+        let err = map
+            .apply_merge_patch(json!({"http": null, "logger": "oops"}))
+            .unwrap_err();
+        assert!(
+            err.to_string().starts_with("error in entry \"logger\""),
+            "{}",
+            err
+        );
+        assert_eq!(map, sample());
+
+        let err = map
+            .apply_merge_patch(json!({"http": null, " ": {"version": "2"}}))
+            .unwrap_err();
+        assert!(matches!(&err, PatchError::EmptyKey { key } if key == " "));
+        assert_eq!(err.to_string(), "empty key \" \"");
+        assert_eq!(map, sample());
+    }
+
+    #[cfg(feature = "json-patch")]
+    fn json_patch(operations: serde_json::Value) -> json_patch::Patch {
+        serde_json::from_value(operations).unwrap()
+    }
+
+    #[cfg(feature = "json-patch")]
+    #[test]
+    fn test_json_patch_operations() {
+        let mut map = sample();
+        map.apply_json_patch(&json_patch(json!([
+            {"op": "test", "path": "/logger/version", "value": "latest"},
+            {"op": "replace", "path": "/logger/version", "value": "2"},
+            {"op": "add", "path": "/metrics", "value": {"version": "1", "limits": {"cpu": 2}}},
+            {"op": "copy", "from": "/metrics/limits", "path": "/http/limits"},
+            {"op": "move", "from": "/logger", "path": "/tracing"},
+            {"op": "remove", "path": "/tracing/limits/memory"},
+        ])))
+        .unwrap();
+
+        assert_eq!(map.len(), 3);
+        assert!(!map.contains_key("logger"));
+        assert_eq!(map["tracing"].version, "2");
+        assert_eq!(map["tracing"].limits.memory, None);
+        assert_eq!(map["metrics"].limits.cpu, 2);
+        assert_eq!(
+            map["http"].limits,
+            Limits {
+                cpu: 2,
+                memory: None
+            }
+        );
+    }
+
+    #[cfg(feature = "json-patch")]
+    #[test]
+    fn test_json_patch_out_of_order_path() {
+        let mut map = sample();
+        // `/metrics` is only added by the second operation.
+        let err = map
+            .apply_json_patch(&json_patch(json!([
+                {"op": "replace", "path": "/metrics/version", "value": "1"},
+                {"op": "add", "path": "/metrics", "value": {"version": "1", "limits": {"cpu": 1}}},
+            ])))
+            .unwrap_err();
+        assert!(
+            matches!(&err, PatchError::Operation(err) if err.operation == 0),
+            "{}",
+            err
+        );
+        assert_eq!(map, sample());
+    }
+
+    #[cfg(feature = "json-patch")]
+    #[test]
+    fn test_json_patch_is_atomic() {
+        let mut map = sample();
+        let err = map
+            .apply_json_patch(&json_patch(json!([
+                {"op": "remove", "path": "/http"},
+                {"op": "replace", "path": "/logger/version", "value": "2"},
+                {"op": "test", "path": "/logger/version", "value": "3"},
+            ])))
+            .unwrap_err();
+        assert!(err.to_string().contains("value did not match"), "{}", err);
+        assert_eq!(map, sample());
+
+        // Operations that succeed but leave an invalid entry are rejected too.
+        let err = map
+            .apply_json_patch(&json_patch(json!([
+                {"op": "remove", "path": "/logger/limits"},
+            ])))
+            .unwrap_err();
+        assert!(matches!(err, PatchError::InvalidMap(_)), "{}", err);
+        assert_eq!(map, sample());
+    }
+}
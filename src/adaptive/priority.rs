@@ -0,0 +1,160 @@
+// flexicon/src/adaptive/priority.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Ordering entries by a priority they carry.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use super::NamedMap;
+
+/// A value with a priority; higher comes first.
+pub trait Prioritized {
+    /// The entry's priority.
+    fn priority(&self) -> i64;
+}
+
+/// Highest priority first, then names in lexicographic order.
+fn by_priority<T: Prioritized>(a: (&String, &T), b: (&String, &T)) -> Ordering {
+    b.1.priority()
+        .cmp(&a.1.priority())
+        .then_with(|| a.0.cmp(b.0))
+}
+
+impl<T: Prioritized, S> NamedMap<T, S> {
+    /// Iterates from the highest to the lowest priority; entries of equal
+    /// priority come in the lexicographic order of their names.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::{NamedMap, Prioritized};
+    ///
+    /// struct Route {
+    ///     priority: i32,
+    /// }
+    ///
+    /// impl Prioritized for Route {
+    ///     fn priority(&self) -> i64 {
+    ///         self.priority.into()
+    ///     }
+    /// }
+    ///
+    /// let mut map = NamedMap::new();
+    /// map.insert("fallback".to_string(), Route { priority: -10 });
+    /// map.insert("static".to_string(), Route { priority: 5 });
+    /// map.insert("api".to_string(), Route { priority: 5 });
+    ///
+    /// let order: Vec<&String> = map.iter_by_priority().map(|(name, _)| name).collect();
+    /// assert_eq!(order, ["api", "static", "fallback"]);
+    /// ```
+    pub fn iter_by_priority(&self) -> impl Iterator<Item = (&String, &T)> {
+        let mut entries: Vec<(&String, &T)> = self.iter().collect();
+        entries.sort_unstable_by(|a, b| by_priority(*a, *b));
+        entries.into_iter()
+    }
+
+    /// Consumes the map into its entries, ordered as by
+    /// [`iter_by_priority`](Self::iter_by_priority).
+    pub fn into_vec_by_priority(self) -> Vec<(String, T)> {
+        let mut entries: Vec<(String, T)> = self.into_iter().collect();
+        entries.sort_unstable_by(|a, b| by_priority((&a.0, &a.1), (&b.0, &b.1)));
+        entries
+    }
+
+    /// Returns the entry with the highest priority, the first name among
+    /// equals; `None` if the map is empty.
+    pub fn max_priority(&self) -> Option<(&String, &T)> {
+        self.iter().min_by(|a, b| by_priority(*a, *b))
+    }
+
+    /// Returns the entry with the lowest priority, the first name among
+    /// equals; `None` if the map is empty.
+    pub fn min_priority(&self) -> Option<(&String, &T)> {
+        self.iter().min_by(|a, b| {
+            a.1.priority()
+                .cmp(&b.1.priority())
+                .then_with(|| a.0.cmp(b.0))
+        })
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[derive(Debug, PartialEq)]
+    struct Route(i32);
+
+    impl Prioritized for Route {
+        fn priority(&self) -> i64 {
+            self.0.into()
+        }
+    }
+
+    fn routes(entries: &[(&str, i32)]) -> NamedMap<Route> {
+        let mut map = NamedMap::new();
+        for (name, priority) in entries {
+            map.insert(name.to_string(), Route(*priority));
+        }
+        map
+    }
+
+    #[test]
+    fn test_highest_priority_first() {
+        let map = routes(&[("low", -5), ("high", 100), ("mid", 0)]);
+        let order: Vec<&String> = map.iter_by_priority().map(|(name, _)| name).collect();
+        assert_eq!(order, ["high", "mid", "low"]);
+
+        let entries = map.into_vec_by_priority();
+        assert_eq!(
+            entries,
+            [
+                ("high".to_string(), Route(100)),
+                ("mid".to_string(), Route(0)),
+                ("low".to_string(), Route(-5)),
+            ]
+        );
+        assert_eq!(NamedMap::<Route>::new().iter_by_priority().count(), 0);
+    }
+
+    #[test]
+    fn test_equal_priorities_come_in_name_order() {
+        let names = ["delta", "alpha", "echo", "charlie", "bravo"];
+        for rotation in 0..names.len() {
+            let mut map = NamedMap::new();
+            for name in names.iter().cycle().skip(rotation).take(names.len()) {
+                map.insert(name.to_string(), Route(if *name == "echo" { 1 } else { 0 }));
+            }
+            let order: Vec<&String> = map.iter_by_priority().map(|(name, _)| name).collect();
+            assert_eq!(order, ["echo", "alpha", "bravo", "charlie", "delta"]);
+            let order: Vec<String> = map
+                .into_vec_by_priority()
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect();
+            assert_eq!(order, ["echo", "alpha", "bravo", "charlie", "delta"]);
+        }
+    }
+
+    #[test]
+    fn test_max_and_min_priority() {
+        let map = routes(&[("b", 3), ("a", 3), ("d", -1), ("c", -1), ("e", 0)]);
+        assert_eq!(map.max_priority(), Some((&"a".to_string(), &Route(3))));
+        assert_eq!(map.min_priority(), Some((&"c".to_string(), &Route(-1))));
+
+        let empty = NamedMap::<Route>::new();
+        assert_eq!(empty.max_priority(), None);
+        assert_eq!(empty.min_priority(), None);
+    }
+}
@@ -0,0 +1,209 @@
+// flexicon/src/adaptive/projection.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Borrowed views over a subset of entries.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::BuildHasher;
+
+use super::NamedMap;
+
+/// Names requested from a map that does not contain them, as reported by
+/// [`NamedMap::project_strict`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingNamesError {
+    /// The missing names, in the order requested, each listed once.
+    pub names: Vec<String>,
+}
+
+impl fmt::Display for MissingNamesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.names.len() == 1 {
+            "missing name "
+        } else {
+            "missing names "
+        })?;
+        for (i, name) in self.names.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{:?}", name)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MissingNamesError {}
+
+/// The entries of a [`NamedMap`] selected by
+/// [`project`](NamedMap::project), borrowed rather than cloned.
+///
+/// Entries keep the order in which they were requested. With the `serde`
+/// feature the view serializes in the object form, like the map itself.
+#[derive(Debug, Clone)]
+pub struct ProjectedView<'a, T> {
+    entries: Vec<(&'a String, &'a T)>,
+}
+
+impl<'a, T> ProjectedView<'a, T> {
+    /// Returns the number of selected entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no requested name was present.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the value of `name` if it was selected.
+    pub fn get(&self, name: &str) -> Option<&'a T> {
+        self.entries
+            .iter()
+            .find(|(key, _)| key.as_str() == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Iterates over the selected entries in the order requested.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a String, &'a T)> + '_ {
+        self.entries.iter().copied()
+    }
+}
+
+impl<T, S: BuildHasher> NamedMap<T, S> {
+    /// Selects the entries named in `names`, in that order, skipping names
+    /// that are absent or repeated. Nothing is cloned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let map: NamedMap<u16> = [("http", 80), ("https", 443), ("ssh", 22)]
+    ///     .into_iter()
+    ///     .map(|(name, port)| (name.to_string(), port))
+    ///     .collect();
+    ///
+    /// let view = map.project(&["ssh", "gopher", "http"]);
+    /// assert_eq!(view.len(), 2);
+    /// # #[cfg(feature = "serde_json")]
+    /// assert_eq!(view.to_json_value().unwrap(), serde_json::json!({"ssh": 22, "http": 80}));
+    /// ```
+    pub fn project<'a>(&'a self, names: &[&str]) -> ProjectedView<'a, T> {
+        let mut entries: Vec<(&'a String, &'a T)> = Vec::with_capacity(names.len());
+        for name in names {
+            if let Some(entry) = self.get_key_value(*name) {
+                if !entries.iter().any(|(key, _)| *key == entry.0) {
+                    entries.push(entry);
+                }
+            }
+        }
+        ProjectedView { entries }
+    }
+
+    /// Like [`project`](Self::project), but fails with every requested
+    /// name the map does not contain.
+    pub fn project_strict<'a>(
+        &'a self,
+        names: &[&str],
+    ) -> Result<ProjectedView<'a, T>, MissingNamesError> {
+        let missing = self.missing_names(names);
+        if !missing.is_empty() {
+            return Err(MissingNamesError { names: missing });
+        }
+        Ok(self.project(names))
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for ProjectedView<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.entries.iter().copied())
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T: serde::Serialize> ProjectedView<'_, T> {
+    /// Serializes the selected entries to a `serde_json::Value` object.
+    pub fn to_json_value(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(self)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn ports() -> NamedMap<u16> {
+        [("http", 80), ("https", 443), ("ssh", 22), ("dns", 53)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect()
+    }
+
+    #[test]
+    fn test_project_skips_absent_names() {
+        let map = ports();
+        let view = map.project(&["ssh", "gopher", "http", "ssh"]);
+        assert_eq!(view.len(), 2);
+        let names: Vec<&String> = view.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, ["ssh", "http"]);
+        assert_eq!(view.get("http"), Some(&80));
+        assert_eq!(view.get("https"), None);
+
+        assert!(map.project(&[]).is_empty());
+        assert!(map.project(&["gopher"]).is_empty());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_projected_json_excludes_absent_keys() {
+        let map = ports();
+        let view = map.project(&["dns", "gopher", "https"]);
+        assert_eq!(
+            view.to_json_value().unwrap(),
+            serde_json::json!({"dns": 53, "https": 443})
+        );
+        assert_eq!(
+            serde_json::to_string(&view).unwrap(),
+            r#"{"dns":53,"https":443}"#
+        );
+        assert_eq!(
+            map.project(&["gopher"]).to_json_value().unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[test]
+    fn test_project_strict() {
+        let map = ports();
+        assert_eq!(map.project_strict(&["http", "dns"]).unwrap().len(), 2);
+
+        let err = map
+            .project_strict(&["gopher", "http", "finger", "gopher"])
+            .unwrap_err();
+        assert_eq!(err.names, ["gopher", "finger"]);
+        assert_eq!(err.to_string(), "missing names \"gopher\", \"finger\"");
+        assert_eq!(
+            map.project_strict(&["gopher"]).unwrap_err().to_string(),
+            "missing name \"gopher\""
+        );
+    }
+}
@@ -0,0 +1,197 @@
+// flexicon/src/adaptive/provider.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `figment` integration: `NamedMap` as a provider and as an extraction
+//! target.
+
+// `figment::Error` is large, but it is what figment's own API returns.
+#![allow(clippy::result_large_err)]
+
+use figment::providers::Serialized;
+use figment::value::{Dict, Map};
+use figment::{Error, Figment, Metadata, Profile, Provider};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{FromName, NamedMap};
+
+/// A `figment::Provider` that emits a [`NamedMap`] under a profile and key
+/// path.
+///
+/// ```rust,ignore
+/// let figment = Figment::new()
+///     .merge(NamedMapProvider::new(defaults).key("plugins"))
+///     .merge(Toml::file("App.toml"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct NamedMapProvider<T> {
+    map: NamedMap<T>,
+    profile: Profile,
+    key: Option<String>,
+}
+
+impl<T> NamedMapProvider<T> {
+    /// Emits `map` at the root of the default profile.
+    pub fn new(map: NamedMap<T>) -> Self {
+        Self {
+            map,
+            profile: Profile::Default,
+            key: None,
+        }
+    }
+
+    /// Emits the map to `profile` instead of the default one.
+    pub fn profile(mut self, profile: impl Into<Profile>) -> Self {
+        self.profile = profile.into();
+        self
+    }
+
+    /// Emits the map under the dotted key path `key` (e.g. `app.plugins`)
+    /// instead of the root.
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = Some(key.to_string());
+        self
+    }
+}
+
+impl<T> From<NamedMap<T>> for NamedMapProvider<T> {
+    fn from(map: NamedMap<T>) -> Self {
+        Self::new(map)
+    }
+}
+
+impl<T: Serialize> Provider for NamedMapProvider<T> {
+    fn metadata(&self) -> Metadata {
+        Metadata::named("NamedMap")
+    }
+
+    fn data(&self) -> Result<Map<Profile, Dict>, Error> {
+        let mut serialized = Serialized::from(&self.map, self.profile.clone());
+        serialized.key = self.key.clone();
+        serialized.data()
+    }
+}
+
+impl<T> NamedMap<T>
+where
+    T: DeserializeOwned + FromName + Clone,
+{
+    /// Extracts the map at the dotted key path `key` of `figment`.
+    ///
+    /// Every form `NamedMap` accepts works here, whichever source provided
+    /// it: a table of entries, an array of names (`plugins = ["a", "b"]` in
+    /// TOML, `APP_PLUGINS=[a, b]` in the environment) or a single name.
+    /// Note that figment replaces, rather than merges, an array with a table
+    /// from a later source.
+    pub fn extract_from(figment: &Figment, key: &str) -> Result<Self, Error> {
+        figment.extract_inner(key)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use figment::providers::{Env, Format, Toml};
+    use figment::Jail;
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct TestItem {
+        value: String,
+        optional: bool,
+    }
+
+    impl FromName for TestItem {
+        fn from_name(name: &str) -> Self {
+            Self {
+                value: format!("from_name({})", name),
+                optional: false,
+            }
+        }
+    }
+
+    #[test]
+    fn test_toml_and_env_layers() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "App.toml",
+                r#"
+                    names = ["a", "b"]
+
+                    [plugins]
+                    logger = { value = "file", optional = true }
+                    http = { value = "file", optional = false }
+                "#,
+            )?;
+            jail.set_env("APP_PLUGINS__HTTP__VALUE", "env");
+            jail.set_env("APP_EXTRA", "[x, y]");
+
+            let figment = Figment::new()
+                .merge(Toml::file("App.toml"))
+                .merge(Env::prefixed("APP_").split("__"));
+
+            let plugins: NamedMap<TestItem> = NamedMap::extract_from(&figment, "plugins")?;
+            assert_eq!(plugins["logger"].value, "file");
+            assert!(plugins["logger"].optional);
+            assert_eq!(plugins["http"].value, "env");
+
+            let names: NamedMap<TestItem> = NamedMap::extract_from(&figment, "names")?;
+            assert_eq!(names["b"].value, "from_name(b)");
+
+            let extra: NamedMap<TestItem> = NamedMap::extract_from(&figment, "extra")?;
+            assert_eq!(extra.len(), 2);
+            assert_eq!(extra["x"].value, "from_name(x)");
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_provider_layered_under_file() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "App.toml",
+                "[app.plugins.logger]\nvalue = \"file\"\noptional = true\n",
+            )?;
+            let mut defaults = NamedMap::from(vec!["logger".to_string()]);
+            defaults.insert(
+                "http".to_string(),
+                TestItem {
+                    value: "default".to_string(),
+                    optional: false,
+                },
+            );
+
+            let figment = Figment::new()
+                .merge(NamedMapProvider::new(defaults).key("app.plugins"))
+                .merge(Toml::file("App.toml"));
+
+            let plugins: NamedMap<TestItem> = NamedMap::extract_from(&figment, "app.plugins")?;
+            assert_eq!(plugins.len(), 2);
+            assert_eq!(plugins["logger"].value, "file");
+            assert_eq!(plugins["http"].value, "default");
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_provider_profile() {
+        let map: NamedMap<TestItem> = NamedMap::from(vec!["logger".to_string()]);
+        let provider = NamedMapProvider::from(map).profile("debug").key("plugins");
+        let data = provider.data().unwrap();
+        assert!(data.contains_key(&Profile::new("debug")));
+
+        let figment = Figment::from(provider).select("debug");
+        let plugins: NamedMap<TestItem> = NamedMap::extract_from(&figment, "plugins").unwrap();
+        assert_eq!(plugins["logger"].value, "from_name(logger)");
+
+        let err = NamedMap::<TestItem>::extract_from(&Figment::new(), "plugins").unwrap_err();
+        assert!(err.to_string().contains("plugins"), "{}", err);
+    }
+}
@@ -0,0 +1,258 @@
+// flexicon/src/adaptive/raw.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::ops::{Deref, DerefMut};
+
+use super::{FromName, NamedMap};
+
+/// A [`NamedMap`] of untyped `serde_json::Value`s, for entries whose type is
+/// only known later (e.g. plugins that each bring their own config schema).
+///
+/// Accepts the same input forms as `NamedMap`. A bare name in the array form
+/// (or a single-name string) is stored as the placeholder `Value::Null`;
+/// the typed accessors turn that placeholder into `T::from_name(key)`.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::Deserialize;
+/// use flexicon::adaptive::{FromName, RawNamedMap};
+///
+/// #[derive(Clone, Deserialize)]
+/// struct Http { port: u16 }
+///
+/// impl FromName for Http {
+///     fn from_name(_name: &str) -> Self { Http { port: 80 } }
+/// }
+///
+/// let raw: RawNamedMap = serde_json::from_str(r#"["metrics", {"http": {"port": 8080}}]"#).unwrap();
+/// assert!(raw["metrics"].is_null());
+/// assert_eq!(raw.get_typed::<Http>("http").unwrap().unwrap().port, 8080);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RawNamedMap(NamedMap<Value>);
+
+impl RawNamedMap {
+    /// Creates an empty `RawNamedMap`.
+    pub fn new() -> Self {
+        Self(NamedMap::new())
+    }
+
+    /// Consumes the wrapper and returns the underlying `NamedMap`.
+    pub fn into_inner(self) -> NamedMap<Value> {
+        self.0
+    }
+
+    /// Deserializes the entry `name` as a `T`.
+    ///
+    /// Returns `Ok(None)` if there is no such entry; the `Value::Null`
+    /// placeholder becomes `T::from_name(name)`.
+    pub fn get_typed<T>(&self, name: &str) -> serde_json::Result<Option<T>>
+    where
+        T: DeserializeOwned + FromName,
+    {
+        self.0
+            .get(name)
+            .map(|value| typed_value(name, value.clone()))
+            .transpose()
+    }
+
+    /// Serializes `value` and stores it under `name`, returning the previous
+    /// raw value, if any.
+    pub fn insert_typed<T>(
+        &mut self,
+        name: impl Into<String>,
+        value: &T,
+    ) -> serde_json::Result<Option<Value>>
+    where
+        T: Serialize,
+    {
+        let value = serde_json::to_value(value)?;
+        Ok(self.0.insert(name.into(), value))
+    }
+
+    /// Converts every entry into a `T`, failing on the first entry that
+    /// does not deserialize (the error names the entry).
+    pub fn typed<T>(self) -> serde_json::Result<NamedMap<T>>
+    where
+        T: DeserializeOwned + FromName,
+    {
+        self.0
+            .into_iter()
+            .map(|(name, value)| {
+                let value = typed_value(&name, value)?;
+                Ok((name, value))
+            })
+            .collect()
+    }
+}
+
+/// Deserializes one raw entry, treating the `Value::Null` placeholder as
+/// `T::from_name(name)`.
+fn typed_value<T>(name: &str, value: Value) -> serde_json::Result<T>
+where
+    T: DeserializeOwned + FromName,
+{
+    if value.is_null() {
+        return Ok(T::from_name(name));
+    }
+    T::deserialize(value).map_err(|err| {
+        serde::de::Error::custom(format_args!("error in entry {:?}: {}", name, err))
+    })
+}
+
+impl From<NamedMap<Value>> for RawNamedMap {
+    fn from(map: NamedMap<Value>) -> Self {
+        RawNamedMap(map)
+    }
+}
+
+impl From<RawNamedMap> for NamedMap<Value> {
+    fn from(map: RawNamedMap) -> Self {
+        map.0
+    }
+}
+
+impl Deref for RawNamedMap {
+    type Target = NamedMap<Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for RawNamedMap {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+/// A raw value that names alone resolve to: the `Value::Null` placeholder.
+#[derive(Clone, Deserialize)]
+#[serde(transparent)]
+struct Placeholder(Value);
+
+impl FromName for Placeholder {
+    fn from_name(_name: &str) -> Self {
+        Placeholder(Value::Null)
+    }
+}
+
+impl Serialize for RawNamedMap {
+    /// Serializes as an object; placeholders are written as `null`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RawNamedMap {
+    /// Deserializes from the same forms as `NamedMap`.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let map = NamedMap::<Placeholder>::deserialize(deserializer)?;
+        Ok(RawNamedMap(
+            map.into_inner()
+                .into_iter()
+                .map(|(name, Placeholder(value))| (name, value))
+                .collect(),
+        ))
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Http {
+        port: u16,
+    }
+
+    impl FromName for Http {
+        fn from_name(_name: &str) -> Self {
+            Http { port: 80 }
+        }
+    }
+
+    #[test]
+    fn test_array_form_stores_placeholder() {
+        let raw: RawNamedMap = serde_json::from_str(r#"["logger", "http"]"#).unwrap();
+        assert_eq!(raw["logger"], Value::Null);
+        assert_eq!(
+            raw.get_typed::<Http>("http").unwrap(),
+            Some(Http { port: 80 })
+        );
+        assert_eq!(raw.get_typed::<Http>("missing").unwrap(), None);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let raw: RawNamedMap =
+            serde_json::from_str(r#"["metrics", {"http": {"port": 8080, "extra": [1, 2]}}]"#)
+                .unwrap();
+        let json = serde_json::to_string(&raw).unwrap();
+        let restored: RawNamedMap = serde_json::from_str(&json).unwrap();
+        assert_eq!(raw, restored);
+
+        let toml =
+            toml::to_string(&serde_json::json!({ "plugins": { "http": { "port": 1 } } })).unwrap();
+        #[derive(Deserialize)]
+        struct Config {
+            plugins: RawNamedMap,
+        }
+        let config: Config = toml::from_str(&toml).unwrap();
+        assert_eq!(config.plugins["http"]["port"], 1);
+    }
+
+    #[test]
+    fn test_typed_subset() {
+        #[derive(Debug, Deserialize)]
+        struct Cache {
+            size: u32,
+        }
+
+        let mut raw: RawNamedMap = serde_json::from_str(
+            r#"{"http": {"port": 8080}, "cache": {"size": 64}, "custom": {"anything": true}}"#,
+        )
+        .unwrap();
+
+        let http: Http = raw.get_typed("http").unwrap().unwrap();
+        assert_eq!(http.port, 8080);
+        let cache: Cache = serde_json::from_value(raw.remove("cache").unwrap()).unwrap();
+        assert_eq!(cache.size, 64);
+        assert_eq!(raw["custom"]["anything"], true);
+
+        raw.insert_typed("https", &Http { port: 443 }).unwrap();
+        assert_eq!(raw["https"]["port"], 443);
+    }
+
+    #[test]
+    fn test_typed_conversion() {
+        let raw: RawNamedMap = serde_json::from_str(r#"["a", {"b": {"port": 1}}]"#).unwrap();
+        let map = raw.clone().typed::<Http>().unwrap();
+        assert_eq!(map["a"], Http { port: 80 });
+        assert_eq!(map["b"], Http { port: 1 });
+
+        let mut broken = raw;
+        broken.insert("c".to_string(), serde_json::json!({ "port": "x" }));
+        let err = broken.typed::<Http>().unwrap_err();
+        assert!(err.to_string().contains("error in entry \"c\""), "{}", err);
+    }
+}
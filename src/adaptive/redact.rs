@@ -0,0 +1,250 @@
+// flexicon/src/adaptive/redact.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Hiding sensitive fields before a map is logged or serialized.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::map::DefaultHasher;
+use super::NamedMap;
+
+/// The placeholder conventionally written over secrets.
+pub const REDACTED: &str = "[redacted]";
+
+/// A value that can hide its sensitive parts, such as passwords and tokens.
+///
+/// Both methods default to doing nothing, so a type without secrets opts
+/// in with an empty `impl Redact for Type {}`. (A blanket no-op impl would
+/// forbid the overriding impls of sensitive types.) Primitive types,
+/// `String`, `Option` and `Vec` are implemented already.
+///
+/// # Example
+///
+/// ```rust
+/// use flexicon::adaptive::{Redact, REDACTED};
+///
+/// #[derive(Clone)]
+/// struct Credentials {
+///     user: String,
+///     password: String,
+/// }
+///
+/// impl Redact for Credentials {
+///     fn redact_in_place(&mut self) {
+///         self.password = REDACTED.to_string();
+///     }
+/// }
+///
+/// let credentials = Credentials { user: "admin".into(), password: "hunter2".into() };
+/// assert_eq!(credentials.redacted().password, REDACTED);
+/// ```
+pub trait Redact {
+    /// Overwrites the sensitive parts of `self`.
+    fn redact_in_place(&mut self) {}
+
+    /// Returns a copy with the sensitive parts overwritten.
+    fn redacted(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut copy = self.clone();
+        copy.redact_in_place();
+        copy
+    }
+}
+
+macro_rules! redact_nothing {
+    ($($ty:ty),*) => {
+        $(impl Redact for $ty {})*
+    };
+}
+
+redact_nothing!(
+    bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, String
+);
+
+impl<T: Redact> Redact for Option<T> {
+    fn redact_in_place(&mut self) {
+        if let Some(value) = self {
+            value.redact_in_place();
+        }
+    }
+}
+
+impl<T: Redact> Redact for Vec<T> {
+    fn redact_in_place(&mut self) {
+        self.iter_mut().for_each(Redact::redact_in_place);
+    }
+}
+
+impl<T: Redact, S> Redact for NamedMap<T, S> {
+    fn redact_in_place(&mut self) {
+        self.as_inner_mut()
+            .values_mut()
+            .for_each(Redact::redact_in_place);
+    }
+}
+
+/// A [`NamedMap`] shown with its values redacted, for logs; created by
+/// [`NamedMap::redacted_display`].
+///
+/// Formats as a debug map sorted by name, each value redacted first.
+pub struct RedactedDisplay<'a, T, S = DefaultHasher>(&'a NamedMap<T, S>);
+
+impl<T: Redact + Clone + fmt::Debug, S> fmt::Display for RedactedDisplay<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.0
+                    .iter_sorted()
+                    .map(|(name, value)| (name, value.redacted())),
+            )
+            .finish()
+    }
+}
+
+impl<T: Redact + Clone, S> NamedMap<T, S> {
+    /// Returns an adapter that displays the map with every value redacted.
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::{NamedMap, Redact, REDACTED};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct Token(String);
+    ///
+    /// impl Redact for Token {
+    ///     fn redact_in_place(&mut self) {
+    ///         self.0 = REDACTED.to_string();
+    ///     }
+    /// }
+    ///
+    /// let mut map = NamedMap::new();
+    /// map.insert("github".to_string(), Token("ghp_secret".to_string()));
+    /// assert_eq!(
+    ///     map.redacted_display().to_string(),
+    ///     r#"{"github": Token("[redacted]")}"#
+    /// );
+    /// ```
+    pub fn redacted_display(&self) -> RedactedDisplay<'_, T, S> {
+        RedactedDisplay(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Redact + Clone + serde::Serialize, S> NamedMap<T, S> {
+    /// Serializes the map in the object form, as `Serialize` does, with
+    /// every value redacted first. Each value is cloned and redacted just
+    /// before it is written.
+    pub fn serialize_redacted<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        serializer.collect_map(self.iter().map(|(name, value)| (name, value.redacted())))
+    }
+
+    /// Like [`to_json_string`](Self::to_json_string), with every value
+    /// redacted first; safe to log.
+    #[cfg(feature = "serde_json")]
+    pub fn to_json_string_redacted(&self) -> serde_json::Result<String> {
+        let mut out = Vec::new();
+        self.serialize_redacted(&mut serde_json::Serializer::new(&mut out))?;
+        Ok(String::from_utf8(out).expect("serde_json writes UTF-8"))
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    struct Database {
+        user: String,
+        password: String,
+        port: u16,
+    }
+
+    impl Redact for Database {
+        fn redact_in_place(&mut self) {
+            self.password = REDACTED.to_string();
+        }
+    }
+
+    fn databases() -> NamedMap<Database> {
+        let mut map = NamedMap::new();
+        map.insert(
+            "primary".to_string(),
+            Database {
+                user: "admin".to_string(),
+                password: "hunter2".to_string(),
+                port: 5432,
+            },
+        );
+        map.insert(
+            "replica".to_string(),
+            Database {
+                user: "reader".to_string(),
+                password: "s3cr3t!".to_string(),
+                port: 5433,
+            },
+        );
+        map
+    }
+
+    #[test]
+    fn test_redacted_display_hides_secrets() {
+        let map = databases();
+        let shown = map.redacted_display().to_string();
+        assert!(!shown.contains("hunter2"), "{}", shown);
+        assert!(!shown.contains("s3cr3t!"), "{}", shown);
+        assert!(
+            shown.starts_with(r#"{"primary": Database { user: "admin", password: "[redacted]""#)
+        );
+        assert_eq!(map["primary"].password, "hunter2");
+    }
+
+    #[test]
+    fn test_redact_in_place() {
+        let mut map = databases();
+        map.redact_in_place();
+        assert!(map.values().all(|db| db.password == REDACTED));
+        assert_eq!(map["replica"].user, "reader");
+
+        let mut nested = vec![Some(databases()), None];
+        nested.redact_in_place();
+        assert_eq!(nested[0].as_ref().unwrap()["primary"].password, REDACTED);
+    }
+
+    #[test]
+    fn test_plain_types_unchanged() {
+        let mut map = NamedMap::new();
+        map.insert("http".to_string(), 80u16);
+        assert_eq!(map.redacted(), map);
+        assert_eq!(map.redacted_display().to_string(), r#"{"http": 80}"#);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_redacted() {
+        let map = databases();
+        let json = map.to_json_string_redacted().unwrap();
+        assert!(!json.contains("hunter2"), "{}", json);
+        assert!(!json.contains("s3cr3t!"), "{}", json);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["primary"]["password"], REDACTED);
+        assert_eq!(value["replica"]["user"], "reader");
+
+        assert!(map.to_json_string().unwrap().contains("hunter2"));
+    }
+}
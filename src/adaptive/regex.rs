@@ -0,0 +1,136 @@
+// flexicon/src/adaptive/regex.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Selecting names with regular expressions (the `regex` feature).
+
+use core::hash::BuildHasher;
+
+use ::regex::Regex;
+
+use super::NamedMap;
+
+impl<T, S> NamedMap<T, S> {
+    /// Returns the entries whose names match `re`, in arbitrary order.
+    ///
+    /// Like [`Regex::is_match`], a pattern matches anywhere in the name
+    /// unless anchored with `^` and `$`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    /// use regex::Regex;
+    ///
+    /// let map: NamedMap<u16> = [("eth0", 1), ("eth1", 2), ("veth0", 3)]
+    ///     .into_iter()
+    ///     .map(|(name, id)| (name.to_string(), id))
+    ///     .collect();
+    ///
+    /// let physical = Regex::new(r"^eth\d+$").unwrap();
+    /// let mut ids: Vec<u16> = map.matching_regex(&physical).map(|(_, id)| *id).collect();
+    /// ids.sort();
+    /// assert_eq!(ids, [1, 2]);
+    /// ```
+    pub fn matching_regex<'a>(
+        &'a self,
+        re: &'a Regex,
+    ) -> impl Iterator<Item = (&'a str, &'a T)> + 'a {
+        self.iter()
+            .map(|(name, value)| (name.as_str(), value))
+            .filter(move |(name, _)| re.is_match(name))
+    }
+
+    /// Returns the names that match `re`, in arbitrary order.
+    pub fn keys_matching_regex<'a>(&'a self, re: &'a Regex) -> impl Iterator<Item = &'a str> + 'a {
+        self.matching_regex(re).map(|(name, _)| name)
+    }
+
+    /// Like [`matching_regex`](Self::matching_regex), but compiles
+    /// `pattern` first. Prefer `matching_regex` with a compiled [`Regex`]
+    /// when querying repeatedly.
+    pub fn matching_pattern<'a>(
+        &'a self,
+        pattern: &str,
+    ) -> Result<impl Iterator<Item = (&'a str, &'a T)> + 'a, ::regex::Error> {
+        let re = Regex::new(pattern)?;
+        Ok(self
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+            .filter(move |(name, _)| re.is_match(name)))
+    }
+}
+
+impl<T, S: BuildHasher + Clone> NamedMap<T, S> {
+    /// Keeps the entries whose names match `re` and returns the others as
+    /// a new map, as [`retain_names`](Self::retain_names) does.
+    pub fn retain_matching_regex(&mut self, re: &Regex) -> NamedMap<T, S> {
+        self.retain_names(|name, _| re.is_match(name))
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map() -> NamedMap<i32> {
+        [("eth0", 0), ("eth1", 1), ("veth0", 2), ("lo", 3)]
+            .into_iter()
+            .map(|(name, id)| (name.to_string(), id))
+            .collect()
+    }
+
+    fn sorted<'a>(names: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+        let mut names: Vec<_> = names.collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_unanchored_and_anchored_patterns() {
+        let map = map();
+        let contains = Regex::new("eth").unwrap();
+        assert_eq!(
+            sorted(map.keys_matching_regex(&contains)),
+            ["eth0", "eth1", "veth0"]
+        );
+        let anchored = Regex::new(r"^eth\d$").unwrap();
+        assert_eq!(sorted(map.keys_matching_regex(&anchored)), ["eth0", "eth1"]);
+        let ends = Regex::new("0$").unwrap();
+        let mut ids: Vec<i32> = map.matching_regex(&ends).map(|(_, id)| *id).collect();
+        ids.sort();
+        assert_eq!(ids, [0, 2]);
+
+        let mut ids: Vec<i32> = map
+            .matching_pattern("^(lo|eth1)$")
+            .unwrap()
+            .map(|(_, id)| *id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, [1, 3]);
+    }
+
+    #[test]
+    fn test_retain_matching_regex() {
+        let mut map = map();
+        let removed = map.retain_matching_regex(&Regex::new("^eth").unwrap());
+        assert_eq!(sorted(map.keys().map(String::as_str)), ["eth0", "eth1"]);
+        assert_eq!(sorted(removed.keys().map(String::as_str)), ["lo", "veth0"]);
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let map = map();
+        assert!(matches!(
+            map.matching_pattern("eth(").err(),
+            Some(::regex::Error::Syntax(_))
+        ));
+    }
+}
@@ -0,0 +1,414 @@
+// flexicon/src/adaptive/rename.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::BuildHasher;
+
+use super::map::HashMap;
+use super::NamedMap;
+
+/// Why [`NamedMap::rename_key`] or [`NamedMap::rename_keys`] left the map
+/// unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenameError {
+    /// There is no entry to rename.
+    SourceMissing {
+        /// The missing key.
+        from: String,
+    },
+    /// The new name is already taken, by an existing entry or by another
+    /// rename in the same batch.
+    TargetExists {
+        /// The key being renamed.
+        from: String,
+        /// The name that is already taken.
+        to: String,
+    },
+}
+
+impl fmt::Display for RenameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenameError::SourceMissing { from } => {
+                write!(f, "cannot rename {:?}: no such key", from)
+            }
+            RenameError::TargetExists { from, to } => {
+                write!(
+                    f,
+                    "cannot rename {:?} to {:?}: key already exists",
+                    from, to
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RenameError {}
+
+/// Distinct keys that [`NamedMap::map_names`] would rename to the same name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyCollision {
+    /// The shared new name.
+    pub name: String,
+    /// The original keys, sorted.
+    pub originals: Vec<String>,
+}
+
+impl fmt::Display for KeyCollision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("keys ")?;
+        for (i, original) in self.originals.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{:?}", original)?;
+        }
+        write!(f, " would all be renamed to {:?}", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyCollision {}
+
+impl<T, S: BuildHasher> NamedMap<T, S> {
+    /// Moves the value of `from` to the key `to`.
+    ///
+    /// Fails without touching the map if `from` is missing or `to` is
+    /// already present; renaming a key to itself is a no-op.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::{NamedMap, RenameError};
+    ///
+    /// let mut map = NamedMap::new();
+    /// map.insert("http".to_string(), 80);
+    /// map.insert("https".to_string(), 443);
+    ///
+    /// map.rename_key("http", "http-server").unwrap();
+    /// assert_eq!(map["http-server"], 80);
+    ///
+    /// let err = map.rename_key("https", "http-server").unwrap_err();
+    /// assert_eq!(
+    ///     err,
+    ///     RenameError::TargetExists { from: "https".into(), to: "http-server".into() }
+    /// );
+    /// ```
+    pub fn rename_key(&mut self, from: &str, to: impl Into<String>) -> Result<(), RenameError> {
+        let to = to.into();
+        if !self.as_inner().contains_key(from) {
+            return Err(RenameError::SourceMissing { from: from.into() });
+        }
+        if from == to {
+            return Ok(());
+        }
+        if self.as_inner().contains_key(&to) {
+            return Err(RenameError::TargetExists {
+                from: from.into(),
+                to,
+            });
+        }
+        let value = self.as_inner_mut().remove(from).expect("checked above");
+        self.as_inner_mut().insert(to, value);
+        Ok(())
+    }
+
+    /// Like [`rename_key`](Self::rename_key), but replaces an existing `to`,
+    /// returning the value it displaced.
+    pub fn rename_key_overwriting(
+        &mut self,
+        from: &str,
+        to: impl Into<String>,
+    ) -> Result<Option<T>, RenameError> {
+        let to = to.into();
+        if from == to {
+            if !self.as_inner().contains_key(from) {
+                return Err(RenameError::SourceMissing { from: to });
+            }
+            return Ok(None);
+        }
+        let value = self
+            .as_inner_mut()
+            .remove(from)
+            .ok_or_else(|| RenameError::SourceMissing { from: from.into() })?;
+        Ok(self.as_inner_mut().insert(to, value))
+    }
+
+    /// Applies every `from → to` rename in `mapping`, or none of them.
+    ///
+    /// All renames are checked first: each source must exist, and each
+    /// target must be free, be renamed away in the same batch, or be its
+    /// own source. Because sources are moved out before any target is
+    /// written, swaps such as `a → b, b → a` work. On failure the error
+    /// for the alphabetically first offending source is returned.
+    pub fn rename_keys(&mut self, mapping: &HashMap<String, String>) -> Result<(), RenameError> {
+        let mut renames: Vec<(&String, &String)> = mapping.iter().collect();
+        renames.sort_unstable();
+
+        for &(from, to) in &renames {
+            if !self.as_inner().contains_key(from.as_str()) {
+                return Err(RenameError::SourceMissing { from: from.clone() });
+            }
+            let taken = self.as_inner().contains_key(to.as_str()) && !mapping.contains_key(to);
+            let claimed = renames
+                .iter()
+                .any(|&(other, target)| other < from && target == to);
+            if taken || claimed {
+                return Err(RenameError::TargetExists {
+                    from: from.clone(),
+                    to: to.clone(),
+                });
+            }
+        }
+
+        let moved: Vec<(String, T)> = renames
+            .into_iter()
+            .map(|(from, to)| {
+                let value = self
+                    .as_inner_mut()
+                    .remove(from.as_str())
+                    .expect("checked above");
+                (to.clone(), value)
+            })
+            .collect();
+        self.as_inner_mut().extend(moved);
+        Ok(())
+    }
+}
+
+impl<T, S: BuildHasher + Clone> NamedMap<T, S> {
+    /// Renames every key to `f(key)`, moving the values.
+    ///
+    /// Fails if distinct keys would end up with the same name; the error
+    /// lists them (for the alphabetically first such name).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let mut map = NamedMap::new();
+    /// map.insert("http_server".to_string(), 80);
+    ///
+    /// let map = map.map_names(|name| name.replace('_', "-")).unwrap();
+    /// assert_eq!(map["http-server"], 80);
+    /// ```
+    pub fn map_names<F>(self, f: F) -> Result<NamedMap<T, S>, KeyCollision>
+    where
+        F: FnMut(&str) -> String,
+    {
+        let (renamed, hasher) = self.renamed(f);
+        if let Some(window) = renamed.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            let name = window[0].0.clone();
+            let originals = renamed
+                .into_iter()
+                .filter(|(new, _, _)| *new == name)
+                .map(|(_, original, _)| original)
+                .collect();
+            return Err(KeyCollision { name, originals });
+        }
+        let mut map = HashMap::with_capacity_and_hasher(renamed.len(), hasher);
+        map.extend(renamed.into_iter().map(|(new, _, value)| (new, value)));
+        Ok(NamedMap::from(map))
+    }
+
+    /// Like [`map_names`](Self::map_names), but when keys collide the value
+    /// of the alphabetically last original key wins.
+    pub fn map_names_overwriting<F>(self, f: F) -> NamedMap<T, S>
+    where
+        F: FnMut(&str) -> String,
+    {
+        let (renamed, hasher) = self.renamed(f);
+        let mut map = HashMap::with_capacity_and_hasher(renamed.len(), hasher);
+        map.extend(renamed.into_iter().map(|(new, _, value)| (new, value)));
+        NamedMap::from(map)
+    }
+
+    /// Returns `(new, original, value)` triples sorted by new then original
+    /// name, so collisions are adjacent and resolved deterministically.
+    fn renamed<F>(self, mut f: F) -> (Vec<(String, String, T)>, S)
+    where
+        F: FnMut(&str) -> String,
+    {
+        let hasher = self.as_inner().hasher().clone();
+        let mut renamed: Vec<(String, String, T)> = self
+            .into_inner()
+            .into_iter()
+            .map(|(original, value)| (f(&original), original, value))
+            .collect();
+        renamed.sort_unstable_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        (renamed, hasher)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn sample() -> NamedMap<u16> {
+        [("http", 80), ("https", 443), ("ssh", 22)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect()
+    }
+
+    fn mapping(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(from, to)| (from.to_string(), to.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_rename_key() {
+        let mut map = sample();
+        map.rename_key("http", "http-server").unwrap();
+        assert_eq!(map["http-server"], 80);
+        assert!(!map.contains_key("http"));
+        map.rename_key("ssh", "ssh").unwrap();
+        assert_eq!(map["ssh"], 22);
+    }
+
+    #[test]
+    fn test_rename_key_errors_leave_map_unchanged() {
+        let mut map = sample();
+        assert_eq!(
+            map.rename_key("ftp", "files"),
+            Err(RenameError::SourceMissing {
+                from: "ftp".to_string()
+            })
+        );
+        let err = map.rename_key("http", "https").unwrap_err();
+        assert_eq!(
+            err,
+            RenameError::TargetExists {
+                from: "http".to_string(),
+                to: "https".to_string()
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "cannot rename \"http\" to \"https\": key already exists"
+        );
+        assert_eq!(map, sample());
+    }
+
+    #[test]
+    fn test_rename_key_overwriting() {
+        let mut map = sample();
+        assert_eq!(map.rename_key_overwriting("http", "https"), Ok(Some(443)));
+        assert_eq!(map["https"], 80);
+        assert_eq!(map.rename_key_overwriting("ssh", "shell"), Ok(None));
+        assert_eq!(
+            map.rename_key_overwriting("http", "web"),
+            Err(RenameError::SourceMissing {
+                from: "http".to_string()
+            })
+        );
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_map_names_lossless() {
+        let map: NamedMap<u16> = [("http_server", 80), ("admin_api", 9000), ("ssh", 22)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect();
+        let map = map.map_names(|name| name.replace('_', "-")).unwrap();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["http-server"], 80);
+        assert_eq!(map["admin-api"], 9000);
+        assert_eq!(map["ssh"], 22);
+    }
+
+    #[test]
+    fn test_map_names_collision() {
+        let map: NamedMap<u16> = [("http_server", 80), ("http-server", 8080), ("ssh", 22)]
+            .into_iter()
+            .map(|(name, port)| (name.to_string(), port))
+            .collect();
+        let err = map
+            .clone()
+            .map_names(|name| name.replace('_', "-"))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            KeyCollision {
+                name: "http-server".to_string(),
+                originals: vec!["http-server".to_string(), "http_server".to_string()],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "keys \"http-server\", \"http_server\" would all be renamed to \"http-server\""
+        );
+
+        let map = map.map_names_overwriting(|name| name.replace('_', "-"));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["http-server"], 80);
+    }
+
+    #[test]
+    fn test_rename_keys_applies_all() {
+        let mut map = sample();
+        map.rename_keys(&mapping(&[
+            ("http", "https"),
+            ("https", "http"),
+            ("ssh", "shell"),
+        ]))
+        .unwrap();
+        assert_eq!(map["http"], 443);
+        assert_eq!(map["https"], 80);
+        assert_eq!(map["shell"], 22);
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn test_rename_keys_is_atomic() {
+        let mut map = sample();
+        let err = map
+            .rename_keys(&mapping(&[("http", "web"), ("ftp", "files")]))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            RenameError::SourceMissing {
+                from: "ftp".to_string()
+            }
+        );
+
+        let err = map
+            .rename_keys(&mapping(&[("http", "web"), ("ssh", "https")]))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            RenameError::TargetExists {
+                from: "ssh".to_string(),
+                to: "https".to_string()
+            }
+        );
+
+        let err = map
+            .rename_keys(&mapping(&[("http", "web"), ("https", "web")]))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            RenameError::TargetExists {
+                from: "https".to_string(),
+                to: "web".to_string()
+            }
+        );
+        assert_eq!(map, sample());
+    }
+}
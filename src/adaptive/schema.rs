@@ -0,0 +1,171 @@
+// flexicon/src/adaptive/schema.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+
+use super::NamedMap;
+
+impl<T: JsonSchema> JsonSchema for NamedMap<T> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("NamedMap_of_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("flexicon::adaptive::NamedMap<{}>", T::schema_id()).into()
+    }
+
+    /// Describes every shape accepted by the `Deserialize` impl:
+    /// the detailed object, the (mixed) array of names, and a single name.
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let value = generator.subschema_for::<T>();
+        json_schema!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "additionalProperties": value,
+                },
+                {
+                    "type": "array",
+                    "items": {
+                        "anyOf": [
+                            { "type": "string", "minLength": 1 },
+                            {
+                                "type": "object",
+                                "additionalProperties": value,
+                                "minProperties": 1,
+                                "maxProperties": 1,
+                            },
+                        ],
+                    },
+                },
+                { "type": "string", "minLength": 1 },
+            ],
+        })
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Interface {
+        version: String,
+    }
+
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Manifest {
+        interfaces: NamedMap<Interface>,
+    }
+
+    /// A deliberately small validator covering the keywords used above.
+    fn validates(schema: &Value, root: &Value, doc: &Value) -> bool {
+        let schema = match schema.get("$ref").and_then(Value::as_str) {
+            Some(path) => root.pointer(path.trim_start_matches('#')).unwrap(),
+            None => schema,
+        };
+        if let Some(options) = schema.get("anyOf").and_then(Value::as_array) {
+            return options.iter().any(|s| validates(s, root, doc));
+        }
+        let type_ok = match schema.get("type").and_then(Value::as_str) {
+            Some("object") => doc.is_object(),
+            Some("array") => doc.is_array(),
+            Some("string") => doc.is_string(),
+            _ => true,
+        };
+        if !type_ok {
+            return false;
+        }
+        if let (Some(min), Some(s)) = (
+            schema.get("minLength").and_then(Value::as_u64),
+            doc.as_str(),
+        ) {
+            if (s.chars().count() as u64) < min {
+                return false;
+            }
+        }
+        if let Some(obj) = doc.as_object() {
+            let len = obj.len() as u64;
+            if schema
+                .get("minProperties")
+                .and_then(Value::as_u64)
+                .is_some_and(|m| len < m)
+                || schema
+                    .get("maxProperties")
+                    .and_then(Value::as_u64)
+                    .is_some_and(|m| len > m)
+            {
+                return false;
+            }
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                if !required
+                    .iter()
+                    .all(|k| obj.contains_key(k.as_str().unwrap()))
+                {
+                    return false;
+                }
+            }
+            for (key, value) in obj {
+                let sub = schema
+                    .get("properties")
+                    .and_then(|p| p.get(key))
+                    .or_else(|| schema.get("additionalProperties"));
+                if let Some(sub) = sub {
+                    if !validates(sub, root, value) {
+                        return false;
+                    }
+                }
+            }
+        }
+        if let (Some(items), Some(arr)) = (schema.get("items"), doc.as_array()) {
+            return arr.iter().all(|item| validates(items, root, item));
+        }
+        true
+    }
+
+    #[test]
+    fn test_schema_accepts_both_forms() {
+        let schema = schemars::schema_for!(Manifest).to_value();
+        let check = |doc: Value| validates(&schema, &schema, &doc);
+
+        assert!(check(json!({ "interfaces": ["logger", "http"] })));
+        assert!(check(json!({
+            "interfaces": {
+                "logger": { "version": "1.0" },
+                "http": { "version": "0.2" }
+            }
+        })));
+        assert!(check(
+            json!({ "interfaces": ["logger", { "http": { "version": "0.2" } }] })
+        ));
+
+        assert!(!check(json!({ "interfaces": 42 })));
+        assert!(!check(
+            json!({ "interfaces": { "logger": { "name": "x" } } })
+        ));
+    }
+
+    #[test]
+    fn test_schema_name_includes_value_type() {
+        assert_eq!(
+            NamedMap::<Interface>::schema_name(),
+            "NamedMap_of_Interface"
+        );
+        assert_ne!(
+            NamedMap::<Interface>::schema_id(),
+            NamedMap::<String>::schema_id()
+        );
+    }
+}
@@ -0,0 +1,271 @@
+// flexicon/src/adaptive/seq.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Shared pieces of the sequence (simple) form used by every adaptive map.
+
+use serde::{
+    de::{self, DeserializeSeed, Deserializer, MapAccess, Visitor},
+    Deserialize,
+};
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::marker::PhantomData;
+
+use super::{EmptyNameError, FromName, NamePolicy, TryFromName};
+
+/// Caps a deserializer's length hint before reserving capacity, so that a
+/// length prefix in untrusted input (bincode, postcard, MessagePack) cannot
+/// force a huge allocation up front; the map still grows past the cap as
+/// entries actually arrive.
+pub(crate) fn cautious_capacity<T>(hint: Option<usize>) -> usize {
+    const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+    let entry = core::mem::size_of::<(String, T)>().max(1);
+    hint.unwrap_or(0).min(MAX_PREALLOC_BYTES / entry)
+}
+
+/// A single element of the sequence form: either a bare name or
+/// an inline single-key map (`{ "name": {...} }`).
+pub(crate) enum SeqItem<T> {
+    Name(String),
+    Entry(String, T),
+}
+
+impl<T: FromName> SeqItem<T> {
+    /// Resolves the item into a `(name, value)` pair, building bare names
+    /// through `FromName`.
+    pub(crate) fn into_entry(self) -> (String, T) {
+        match self {
+            SeqItem::Name(name) => {
+                let value = T::from_name(&name);
+                (name, value)
+            }
+            SeqItem::Entry(name, value) => (name, value),
+        }
+    }
+}
+
+impl<T> SeqItem<Option<T>> {
+    /// Turns an inline `null` value into a bare name, which then falls
+    /// back to `FromName` like any other.
+    pub(crate) fn into_nullable_item(self) -> SeqItem<T> {
+        match self {
+            SeqItem::Entry(name, Some(value)) => SeqItem::Entry(name, value),
+            SeqItem::Name(name) | SeqItem::Entry(name, None) => SeqItem::Name(name),
+        }
+    }
+}
+
+impl<T> SeqItem<T> {
+    /// Like [`SeqItem::into_entry`], but builds bare names with `from_name`,
+    /// which may fail.
+    pub(crate) fn into_entry_with<E>(
+        self,
+        from_name: impl FnOnce(&str) -> Result<T, E>,
+    ) -> Result<(String, T), E> {
+        match self {
+            SeqItem::Name(name) => {
+                let value = from_name(&name)?;
+                Ok((name, value))
+            }
+            SeqItem::Entry(name, value) => Ok((name, value)),
+        }
+    }
+
+    /// Applies `policy` to the item's name, the `index`-th of its list;
+    /// `Ok(None)` means the item is skipped.
+    pub(crate) fn check_name(
+        self,
+        policy: NamePolicy,
+        index: usize,
+    ) -> Result<Option<Self>, EmptyNameError> {
+        Ok(match self {
+            SeqItem::Name(name) => policy.check_name(name, index)?.map(SeqItem::Name),
+            SeqItem::Entry(name, value) => policy
+                .check_name(name, index)?
+                .map(|name| SeqItem::Entry(name, value)),
+        })
+    }
+}
+
+/// Builds a bare name through `TryFromName`, reporting a failure as a
+/// deserialization error naming the offending entry.
+pub(crate) fn try_from_name<T: TryFromName, E: de::Error>(name: &str) -> Result<T, E> {
+    T::try_from_name(name)
+        .map_err(|err| E::custom(format_args!("invalid name {:?}: {}", name, err)))
+}
+
+/// A map key, borrowed from the input when the format allows it.
+///
+/// Keys only become `String`s once they are inserted, so rejected and
+/// looked-up keys never allocate.
+pub(crate) struct Key<'de>(pub(crate) Cow<'de, str>);
+
+struct KeyVisitor;
+
+impl<'de> Visitor<'de> for KeyVisitor {
+    type Value = Key<'de>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a name")
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Key(Cow::Borrowed(v)))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Key(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Key(Cow::Owned(v)))
+    }
+
+    /// Binary formats (CBOR) may carry byte-string keys; names must be text.
+    fn visit_bytes<E>(self, _v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Err(E::custom(
+            "map keys must be text strings, found a byte string",
+        ))
+    }
+}
+
+/// Reads a [`Key`], letting self-describing formats report what they
+/// actually found (e.g. a CBOR byte string) instead of a generic type error.
+///
+/// Formats that are not self-describing (bincode, postcard) need the
+/// `deserialize_str` hint instead.
+#[derive(Clone, Copy)]
+pub(crate) struct KeySeed {
+    pub(crate) self_describing: bool,
+}
+
+impl<'de> DeserializeSeed<'de> for KeySeed {
+    type Value = Key<'de>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if self.self_describing {
+            deserializer.deserialize_any(KeyVisitor)
+        } else {
+            deserializer.deserialize_str(KeyVisitor)
+        }
+    }
+}
+
+struct SeqItemVisitor<T> {
+    _phantom: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for SeqItemVisitor<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = SeqItem<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a name or a single-key map (e.g., {{ \"a\": {{...}} }})")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(SeqItem::Name(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(SeqItem::Name(v))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        // Only reachable through `deserialize_any`, so the format is self-describing.
+        let seed = KeySeed {
+            self_describing: true,
+        };
+        let Key(name) = map
+            .next_key_seed(seed)?
+            .ok_or_else(|| de::Error::invalid_length(0, &"a single-key map"))?;
+        let value = next_named_value(&mut map, &name)?;
+        if let Some(Key(extra)) = map.next_key_seed(seed)? {
+            return Err(de::Error::custom(format_args!(
+                "inline entry {:?} must be a single-key map, found additional key {:?}",
+                name, extra
+            )));
+        }
+        Ok(SeqItem::Entry(name.into_owned(), value))
+    }
+}
+
+/// Reads the value of entry `name`, naming the entry in any error so that
+/// one bad value in a large map is easy to find.
+pub(crate) fn next_named_value<'de, A, V>(access: &mut A, name: &str) -> Result<V, A::Error>
+where
+    A: MapAccess<'de>,
+    V: Deserialize<'de>,
+{
+    access
+        .next_value()
+        .map_err(|err| de::Error::custom(format_args!("error in entry {:?}: {}", name, err)))
+}
+
+impl<'de, T> Deserialize<'de> for SeqItem<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(SeqItemVisitor {
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// Drives `visitor` with `deserialize_any` for human-readable formats, where
+/// the adaptive forms make sense, and strictly as a map otherwise.
+///
+/// Non-self-describing formats (bincode, postcard, ...) cannot answer
+/// `deserialize_any`; they only ever see what `Serialize` wrote, which is
+/// always a map.
+pub(crate) fn deserialize_adaptive<'de, D, V>(
+    deserializer: D,
+    visitor: V,
+) -> Result<V::Value, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Visitor<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_map(visitor)
+    }
+}
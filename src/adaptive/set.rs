@@ -0,0 +1,204 @@
+// flexicon/src/adaptive/set.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `--set entry.field=value`-style overrides.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::env::{KeyMatch, Staged};
+use super::{FromName, NamedMap, OverrideError};
+
+/// Splits `entry.field."dotted.key"=value` into its path and raw value.
+///
+/// Segments are separated by `.`; a segment in double quotes may contain
+/// `.` and `=`, and `\"` or `\\` inside it. The value is everything after
+/// the first unquoted `=`.
+fn parse_spec(spec: &str) -> Result<(Vec<String>, &str), String> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = spec.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => quoted = !quoted,
+            '\\' if quoted => match chars.next() {
+                Some((_, escaped)) => current.push(escaped),
+                None => break,
+            },
+            '.' if !quoted => segments.push(std::mem::take(&mut current)),
+            '=' if !quoted => {
+                segments.push(current);
+                if segments.iter().any(String::is_empty) {
+                    return Err("empty path segment".to_string());
+                }
+                return Ok((segments, &spec[index + 1..]));
+            }
+            _ => current.push(c),
+        }
+    }
+    if quoted {
+        Err("unterminated quote".to_string())
+    } else {
+        Err("expected `path=value`".to_string())
+    }
+}
+
+impl<T> NamedMap<T>
+where
+    T: Serialize + DeserializeOwned + FromName,
+{
+    /// Applies one `entry.field.subfield=value` override.
+    ///
+    /// The first segment names the entry; a missing entry is created with
+    /// `T::from_name` before the fields are set. Field segments must name
+    /// fields present in the entry's serialized form, matched exactly; quote
+    /// a segment that contains dots (`name."weird.key".field=1`). A spec
+    /// without fields (`logger={...}`) replaces the whole entry.
+    ///
+    /// The value is parsed as JSON if possible (`true`, `3`, `[1,2]`) and
+    /// used as a string otherwise, or whenever the field already holds a
+    /// string, so `logger.version=2.0` keeps `"2.0"` for a `String` field.
+    pub fn apply_override(&mut self, spec: &str) -> Result<(), OverrideError> {
+        self.apply_overrides([spec])
+    }
+
+    /// Applies several [`apply_override`](Self::apply_override) specs in
+    /// order: either all of them or (on error) none.
+    pub fn apply_overrides<I>(&mut self, specs: I) -> Result<(), OverrideError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut staged = Staged::new(self, KeyMatch::Strict);
+        for spec in specs {
+            let spec = spec.as_ref();
+            let error = |message: String| OverrideError {
+                variable: spec.to_string(),
+                message,
+            };
+            let (segments, raw) = parse_spec(spec).map_err(error)?;
+            let path: Vec<&str> = segments[1..].iter().map(String::as_str).collect();
+            staged.set(spec, &segments[0], &path, raw).map_err(error)?;
+        }
+        staged.commit();
+        Ok(())
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Plugin {
+        version: String,
+        enabled: bool,
+        ports: Vec<u16>,
+        labels: BTreeMap<String, BTreeMap<String, u32>>,
+    }
+
+    impl FromName for Plugin {
+        fn from_name(_name: &str) -> Self {
+            let mut labels = BTreeMap::new();
+            labels.insert(
+                "weird.key".to_string(),
+                BTreeMap::from([("field".to_string(), 0)]),
+            );
+            Plugin {
+                version: "latest".to_string(),
+                enabled: false,
+                ports: Vec::new(),
+                labels,
+            }
+        }
+    }
+
+    fn sample() -> NamedMap<Plugin> {
+        NamedMap::from(vec!["logger".to_string()])
+    }
+
+    #[test]
+    fn test_parse_spec() {
+        assert_eq!(
+            parse_spec(r#"name."weird.key".field=1"#).unwrap(),
+            (vec!["name".into(), "weird.key".into(), "field".into()], "1")
+        );
+        assert_eq!(
+            parse_spec(r#"a."x=\"y\""=b=c"#).unwrap(),
+            (vec!["a".into(), r#"x="y""#.into()], "b=c")
+        );
+        assert_eq!(parse_spec("a.=1").unwrap_err(), "empty path segment");
+        assert_eq!(parse_spec(r#"a."b=1"#).unwrap_err(), "unterminated quote");
+        assert_eq!(parse_spec("a.b").unwrap_err(), "expected `path=value`");
+    }
+
+    #[test]
+    fn test_values() {
+        let mut map = sample();
+        map.apply_overrides([
+            "logger.version=2.0",
+            "logger.enabled=true",
+            "logger.ports=[80,443]",
+            r#"logger.labels."weird.key".field=1"#,
+        ])
+        .unwrap();
+        let logger = &map["logger"];
+        assert_eq!(logger.version, "2.0");
+        assert!(logger.enabled);
+        assert_eq!(logger.ports, [80, 443]);
+        assert_eq!(logger.labels["weird.key"]["field"], 1);
+    }
+
+    #[test]
+    fn test_missing_entry_is_created() {
+        let mut map = sample();
+        map.apply_override("http.enabled=true").unwrap();
+        assert!(map["http"].enabled);
+        assert_eq!(map["http"].version, "latest");
+    }
+
+    #[test]
+    fn test_failures() {
+        let mut map = sample();
+        let err = map
+            .apply_overrides(["logger.enabled=true", "logger.limits.cpu=2"])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "error in override \"logger.limits.cpu=2\": unknown field \"logger.limits\""
+        );
+        // Nothing was applied.
+        assert!(!map["logger"].enabled);
+
+        let err = map
+            .apply_override(r#"logger.labels."weird.key".other=1"#)
+            .unwrap_err();
+        assert_eq!(err.message, "unknown field \"logger.labels.weird.key.other\"");
+
+        let err = map.apply_override("logger.ports=many").unwrap_err();
+        assert!(
+            err.message.starts_with("error in entry \"logger\""),
+            "{}",
+            err
+        );
+
+        let err = map.apply_override("logger.version.major=1").unwrap_err();
+        assert_eq!(
+            err.message,
+            "cannot set field \"major\" on a non-object value"
+        );
+
+        let err = map.apply_override("logger.enabled").unwrap_err();
+        assert_eq!(err.message, "expected `path=value`");
+    }
+}
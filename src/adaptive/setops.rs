@@ -0,0 +1,184 @@
+// flexicon/src/adaptive/setops.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Set operations on the names of two maps.
+
+use alloc::string::String;
+use core::hash::BuildHasher;
+
+use super::map::{HashMap, HashSet};
+use super::{MergeStrategy, NamedMap};
+
+impl<T, S: BuildHasher> NamedMap<T, S> {
+    /// Returns every entry of `self` and `other`, resolving names present
+    /// in both with `strategy` as [`merge`](Self::merge) does.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::{MergeStrategy, NamedMap};
+    ///
+    /// let staging: NamedMap<u16> = [("http".to_string(), 8080)].into_iter().collect();
+    /// let prod: NamedMap<u16> = [("http".to_string(), 80), ("https".to_string(), 443)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let all = staging.union(prod, MergeStrategy::KeepExisting);
+    /// assert_eq!(all["http"], 8080);
+    /// assert_eq!(all["https"], 443);
+    /// ```
+    pub fn union(mut self, other: NamedMap<T, S>, strategy: MergeStrategy<T>) -> NamedMap<T, S> {
+        self.merge(other, strategy);
+        self
+    }
+
+    /// Returns the names present in `self`, `other` or both.
+    pub fn union_names<'a, U, S2>(&'a self, other: &'a NamedMap<U, S2>) -> HashSet<&'a str> {
+        self.keys()
+            .chain(other.keys())
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Returns the names present in both `self` and `other`.
+    pub fn intersection_names<'a, U, S2: BuildHasher>(
+        &'a self,
+        other: &NamedMap<U, S2>,
+    ) -> HashSet<&'a str> {
+        self.keys()
+            .filter(|name| other.contains_key(name.as_str()))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Returns the names present in `self` but not in `other`.
+    pub fn difference_names<'a, U, S2: BuildHasher>(
+        &'a self,
+        other: &NamedMap<U, S2>,
+    ) -> HashSet<&'a str> {
+        self.keys()
+            .filter(|name| !other.contains_key(name.as_str()))
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+impl<T: Clone, S: BuildHasher + Clone> NamedMap<T, S> {
+    /// Returns the entries of `self` whose names are also in `other`, with
+    /// `self`'s values and hasher.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flexicon::adaptive::NamedMap;
+    ///
+    /// let staging: NamedMap<u16> = [("http".to_string(), 8080), ("debug".to_string(), 9229)]
+    ///     .into_iter()
+    ///     .collect();
+    /// let prod: NamedMap<u16> = [("http".to_string(), 80)].into_iter().collect();
+    ///
+    /// assert_eq!(staging.intersection(&prod)["http"], 8080);
+    /// assert!(staging.difference(&prod).contains_key("debug"));
+    /// ```
+    pub fn intersection<U, S2: BuildHasher>(&self, other: &NamedMap<U, S2>) -> NamedMap<T, S> {
+        self.filter_cloned(|name| other.contains_key(name))
+    }
+
+    /// Returns the entries of `self` whose names are not in `other`.
+    pub fn difference<U, S2: BuildHasher>(&self, other: &NamedMap<U, S2>) -> NamedMap<T, S> {
+        self.filter_cloned(|name| !other.contains_key(name))
+    }
+
+    pub(super) fn filter_cloned(&self, mut keep: impl FnMut(&str) -> bool) -> NamedMap<T, S> {
+        let mut map = HashMap::with_hasher(self.hasher().clone());
+        map.extend(
+            self.iter()
+                .filter(|(name, _)| keep(name))
+                .map(|(name, value)| (name.clone(), value.clone())),
+        );
+        NamedMap::from(map)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    fn map(entries: &[(&str, i32)]) -> NamedMap<i32> {
+        let mut map = NamedMap::new();
+        for (k, v) in entries {
+            map.insert(k.to_string(), *v);
+        }
+        map
+    }
+
+    fn sorted(names: HashSet<&str>) -> Vec<&str> {
+        let mut names: Vec<_> = names.into_iter().collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_disjoint_maps() {
+        let a = map(&[("a", 1)]);
+        let b = map(&[("b", 2)]);
+
+        assert_eq!(
+            a.clone().union(b.clone(), MergeStrategy::KeepExisting),
+            map(&[("a", 1), ("b", 2)])
+        );
+        assert!(a.intersection(&b).is_empty());
+        assert_eq!(a.difference(&b), a);
+
+        assert_eq!(sorted(a.union_names(&b)), ["a", "b"]);
+        assert!(a.intersection_names(&b).is_empty());
+        assert_eq!(sorted(a.difference_names(&b)), ["a"]);
+    }
+
+    #[test]
+    fn test_identical_names() {
+        let a = map(&[("a", 1), ("b", 2)]);
+        let b = map(&[("a", 10), ("b", 20)]);
+
+        assert_eq!(a.clone().union(b.clone(), MergeStrategy::KeepExisting), a);
+        assert_eq!(a.clone().union(b.clone(), MergeStrategy::Overwrite), b);
+        assert_eq!(a.intersection(&b), a);
+        assert!(a.difference(&b).is_empty());
+
+        assert_eq!(sorted(a.union_names(&b)), ["a", "b"]);
+        assert_eq!(sorted(a.intersection_names(&b)), ["a", "b"]);
+        assert!(a.difference_names(&b).is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_maps() {
+        let a = map(&[("a", 1), ("b", 2)]);
+        let b = map(&[("b", 20), ("c", 30)]);
+
+        let sum = |_: &str, x: i32, y: i32| x + y;
+        assert_eq!(
+            a.clone().union(b.clone(), MergeStrategy::WithFn(sum)),
+            map(&[("a", 1), ("b", 22), ("c", 30)])
+        );
+        assert_eq!(a.intersection(&b), map(&[("b", 2)]));
+        assert_eq!(a.difference(&b), map(&[("a", 1)]));
+        assert_eq!(b.difference(&a), map(&[("c", 30)]));
+
+        let names: NamedMap<()> = [("b".to_string(), ())].into_iter().collect();
+        assert_eq!(a.intersection(&names), map(&[("b", 2)]));
+
+        assert_eq!(sorted(a.union_names(&b)), ["a", "b", "c"]);
+        assert_eq!(sorted(a.intersection_names(&b)), ["b"]);
+        assert_eq!(sorted(a.difference_names(&b)), ["a"]);
+    }
+}
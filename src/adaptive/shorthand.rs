@@ -0,0 +1,354 @@
+// flexicon/src/adaptive/shorthand.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::FromName;
+
+/// A [`FromName`] type that can also be built from a scalar shorthand.
+///
+/// Lets the detailed form carry a plain string for the most important
+/// field, e.g. `interfaces = { logger = "1.2", http = "0.4" }` where the
+/// string is the version. Used by [`NamedMap::deserialize_with_shorthand`](super::NamedMap::deserialize_with_shorthand).
+///
+/// # Example
+///
+/// ```rust
+/// use flexicon::adaptive::{FromName, FromNameAndShorthand};
+///
+/// #[derive(Clone)]
+/// struct Interface {
+///     version: String,
+/// }
+///
+/// impl FromName for Interface {
+///     fn from_name(_name: &str) -> Self {
+///         Self { version: "latest".into() }
+///     }
+/// }
+///
+/// impl FromNameAndShorthand for Interface {
+///     fn from_shorthand(_name: &str, value: &str) -> Self {
+///         Self { version: value.into() }
+///     }
+/// }
+/// ```
+pub trait FromNameAndShorthand: FromName {
+    /// Construct a value from its name and a string shorthand.
+    fn from_shorthand(name: &str, value: &str) -> Self;
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::adaptive::map::HashMap;
+    use crate::adaptive::namedmap::{NamedMapVisitor, Place};
+    use crate::adaptive::seq::{next_named_value, SeqItem};
+    use crate::adaptive::{DuplicateNameError, DuplicatePolicy, NamePolicy, NamedMap};
+    use serde::{
+        de::{
+            self, value::MapAccessDeserializer, DeserializeOwned, Deserializer, MapAccess,
+            SeqAccess, Visitor,
+        },
+        Deserialize,
+    };
+    use alloc::string::{String, ToString};
+    use core::fmt;
+    use core::hash::BuildHasher;
+    use core::marker::PhantomData;
+
+    /// A value in the detailed form: a string shorthand or a full `T`.
+    enum ShorthandValue<T> {
+        Short(String),
+        Full(T),
+    }
+
+    impl<T: FromNameAndShorthand> ShorthandValue<T> {
+        fn resolve(self, name: &str) -> T {
+            match self {
+                ShorthandValue::Short(value) => T::from_shorthand(name, &value),
+                ShorthandValue::Full(value) => value,
+            }
+        }
+    }
+
+    struct ShorthandValueVisitor<T> {
+        _phantom: PhantomData<T>,
+    }
+
+    impl<'de, T> Visitor<'de> for ShorthandValueVisitor<T>
+    where
+        T: DeserializeOwned,
+    {
+        type Value = ShorthandValue<T>;
+
+        // Numbers and booleans are deliberately rejected rather than
+        // stringified: `1.20` would silently become "1.2".
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a string shorthand or a full object (numbers and booleans must be quoted to be used as shorthand)")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(ShorthandValue::Short(v.to_string()))
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(ShorthandValue::Short(v))
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            T::deserialize(MapAccessDeserializer::new(map)).map(ShorthandValue::Full)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for ShorthandValue<T>
+    where
+        T: DeserializeOwned,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(ShorthandValueVisitor {
+                _phantom: PhantomData,
+            })
+        }
+    }
+
+    /// The plain map, filled by `NamedMapVisitor` with values that may be
+    /// string shorthands, resolved as soon as their name is known.
+    struct WithShorthand<T, S>(HashMap<String, T, S>);
+
+    impl<T, S> Place for WithShorthand<T, S>
+    where
+        T: DeserializeOwned + FromNameAndShorthand,
+        S: BuildHasher,
+    {
+        type Value = T;
+        type Meta = ();
+
+        fn name_meta() {}
+
+        fn from_name<E: de::Error>(name: &str) -> Result<T, E> {
+            Ok(T::from_name(name))
+        }
+
+        fn next_item<'de, A>(
+            seq: &mut A,
+            _nullable: bool,
+        ) -> Result<Option<(SeqItem<T>, ())>, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let item = match seq.next_element::<SeqItem<ShorthandValue<T>>>()? {
+                None => return Ok(None),
+                Some(SeqItem::Name(name)) => SeqItem::Name(name),
+                Some(SeqItem::Entry(name, value)) => {
+                    let value = value.resolve(&name);
+                    SeqItem::Entry(name, value)
+                }
+            };
+            Ok(Some((item, ())))
+        }
+
+        fn next_value<'de, A>(
+            access: &mut A,
+            name: &str,
+            _nullable: bool,
+        ) -> Result<(T, ()), A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let value: ShorthandValue<T> = next_named_value(access, name)?;
+            Ok((value.resolve(name), ()))
+        }
+
+        fn clear(&mut self) {
+            self.0.clear();
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            self.0.reserve(additional);
+        }
+
+        fn contains_key(&self, name: &str) -> bool {
+            self.0.contains_key(name)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn insert(
+            &mut self,
+            policy: DuplicatePolicy,
+            name: String,
+            value: T,
+            _meta: (),
+            index: usize,
+        ) -> Result<(), DuplicateNameError> {
+            policy.insert(&mut self.0, name, value, index)
+        }
+    }
+
+    impl<T> NamedMap<T>
+    where
+        T: DeserializeOwned + FromNameAndShorthand,
+    {
+        /// Deserializes like the `Deserialize` impl, but also accepts a string
+        /// in place of a detailed value, built with
+        /// [`FromNameAndShorthand::from_shorthand`].
+        ///
+        /// Numbers and booleans are rejected (quote them to use them as
+        /// shorthand). Serialization still emits the full object form.
+        ///
+        /// Use as `#[serde(deserialize_with = "NamedMap::deserialize_with_shorthand")]`.
+        /// Non-human-readable formats only accept the object form with full
+        /// values, which is what serialization emits.
+        pub fn deserialize_with_shorthand<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if !deserializer.is_human_readable() {
+                return NamedMap::deserialize(deserializer);
+            }
+            let mut map = WithShorthand(HashMap::default());
+            deserializer.deserialize_any(NamedMapVisitor {
+                place: &mut map,
+                policy: DuplicatePolicy::Error,
+                names: NamePolicy::RejectEmpty,
+                nullable: false,
+                self_describing: true,
+            })?;
+            Ok(NamedMap::from(map.0))
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::adaptive::NamedMap;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Interface {
+        version: String,
+        optional: bool,
+    }
+
+    impl FromName for Interface {
+        fn from_name(_name: &str) -> Self {
+            Self {
+                version: "latest".to_string(),
+                optional: false,
+            }
+        }
+    }
+
+    impl FromNameAndShorthand for Interface {
+        fn from_shorthand(_name: &str, value: &str) -> Self {
+            Self {
+                version: value.to_string(),
+                optional: false,
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Manifest {
+        #[serde(deserialize_with = "NamedMap::deserialize_with_shorthand")]
+        interfaces: NamedMap<Interface>,
+    }
+
+    #[test]
+    fn test_toml_shorthand_roundtrip() {
+        let manifest: Manifest = toml::from_str(
+            r#"interfaces = { logger = "1.2", http = { version = "0.4", optional = true } }"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.interfaces["logger"].version, "1.2");
+        assert!(manifest.interfaces["http"].optional);
+
+        let out = toml::to_string(&manifest).unwrap();
+        assert!(!out.contains("logger = \"1.2\""), "{}", out);
+        let restored: Manifest = toml::from_str(&out).unwrap();
+        assert_eq!(manifest, restored);
+    }
+
+    #[test]
+    fn test_toml_rejects_numbers() {
+        let err = toml::from_str::<Manifest>(r#"interfaces = { logger = 1 }"#).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("\"logger\""), "{}", msg);
+        assert!(msg.contains("must be quoted"), "{}", msg);
+    }
+
+    #[test]
+    fn test_toml_checked_like_named_map() {
+        let manifest: Manifest = toml::from_str(r#"interfaces = "logger""#).unwrap();
+        assert_eq!(
+            manifest.interfaces["logger"],
+            Interface::from_name("logger")
+        );
+
+        let err = toml::from_str::<Manifest>(r#"interfaces = ["logger", { logger = "1.2" }]"#)
+            .unwrap_err();
+        assert_eq!(err.message(), "duplicate name \"logger\" at index 1");
+
+        let err = toml::from_str::<Manifest>(r#"interfaces = { "" = "1.2" }"#).unwrap_err();
+        assert_eq!(err.message(), "empty key \"\"");
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_shorthand_roundtrip() {
+        let manifest: Manifest =
+            serde_json::from_str(r#"{"interfaces": ["metrics", {"logger": "1.2"}]}"#).unwrap();
+        assert_eq!(
+            manifest.interfaces["metrics"],
+            Interface::from_name("metrics")
+        );
+        assert_eq!(manifest.interfaces["logger"].version, "1.2");
+
+        let out = serde_json::to_string(&manifest).unwrap();
+        let restored: Manifest = serde_json::from_str(&out).unwrap();
+        assert_eq!(manifest, restored);
+
+        let err = serde_json::from_value::<Manifest>(
+            serde_json::json!({ "interfaces": { "logger": true } }),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "error in entry \"logger\": invalid type: boolean `true`, expected a string \
+             shorthand or a full object (numbers and booleans must be quoted to be used as shorthand)"
+        );
+    }
+
+    #[test]
+    fn test_bincode_roundtrip() {
+        let manifest: Manifest = toml::from_str(
+            r#"interfaces = { logger = "1.2", http = { version = "0.4", optional = true } }"#,
+        )
+        .unwrap();
+        let bytes = bincode::serialize(&manifest).unwrap();
+        assert_eq!(bincode::deserialize::<Manifest>(&bytes).unwrap(), manifest);
+    }
+}
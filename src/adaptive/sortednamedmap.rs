@@ -0,0 +1,396 @@
+// flexicon/src/adaptive/sortednamedmap.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::borrow::Cow;
+use alloc::collections::btree_map::{self, BTreeMap};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+use core::ops::{Deref, DerefMut};
+
+use super::{FromName, NamePolicy, NamedMap};
+
+/// A key-sorted variant of [`NamedMap`], backed by `BTreeMap<String, T>`.
+///
+/// Accepts the same dual formats as `NamedMap`, but iterates and serializes
+/// entries in lexicographic key order, in every format. Use it for files
+/// that are rewritten by tools and kept under version control, where a
+/// stable output matters more than lookup speed.
+///
+/// Compares equal to a `NamedMap` holding the same entries.
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # #[derive(Clone, Serialize, Deserialize)]
+/// # struct Layer { enabled: bool }
+/// # impl flexicon::adaptive::FromName for Layer {
+/// #     fn from_name(_name: &str) -> Self { Self { enabled: true } }
+/// # }
+/// use flexicon::adaptive::SortedNamedMap;
+///
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() -> Result<(), serde_json::Error> {
+/// let layers: SortedNamedMap<Layer> = serde_json::from_str(r#"["gzip", "auth", "cors"]"#)?;
+/// assert_eq!(
+///     layers.to_json_string()?,
+///     r#"{"auth":{"enabled":true},"cors":{"enabled":true},"gzip":{"enabled":true}}"#
+/// );
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortedNamedMap<T>(BTreeMap<String, T>);
+
+impl<T> SortedNamedMap<T> {
+    /// Creates an empty `SortedNamedMap`.
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    /// Inserts a key-value pair into the map, returning the value it
+    /// replaced, if any.
+    pub fn insert(&mut self, key: String, value: T) -> Option<T> {
+        self.0.insert(key, value)
+    }
+
+    /// Returns `true` if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Consumes the map and returns the inner `BTreeMap`.
+    pub fn into_inner(self) -> BTreeMap<String, T> {
+        self.0
+    }
+
+    /// Returns a reference to the inner map.
+    pub fn as_inner(&self) -> &BTreeMap<String, T> {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the inner map.
+    ///
+    /// ⚠️ **Warning**: Direct mutation bypasses any future validation or invariants
+    /// that `SortedNamedMap` might enforce.
+    /// Prefer using `insert` or higher-level APIs when possible.
+    pub fn as_inner_mut(&mut self) -> &mut BTreeMap<String, T> {
+        &mut self.0
+    }
+}
+
+impl<T> Default for SortedNamedMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<BTreeMap<String, T>> for SortedNamedMap<T> {
+    fn from(map: BTreeMap<String, T>) -> Self {
+        SortedNamedMap(map)
+    }
+}
+
+// Conversions to and from the unordered map.
+impl<T, S> From<NamedMap<T, S>> for SortedNamedMap<T> {
+    fn from(map: NamedMap<T, S>) -> Self {
+        SortedNamedMap(map.into_iter().collect())
+    }
+}
+
+impl<T, S: BuildHasher + Default> From<SortedNamedMap<T>> for NamedMap<T, S> {
+    fn from(map: SortedNamedMap<T>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+// Compared entry by entry, whatever the iteration order of either side.
+impl<T: PartialEq, S: BuildHasher> PartialEq<NamedMap<T, S>> for SortedNamedMap<T> {
+    fn eq(&self, other: &NamedMap<T, S>) -> bool {
+        self.0.len() == other.len()
+            && self
+                .0
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<T: PartialEq, S: BuildHasher> PartialEq<SortedNamedMap<T>> for NamedMap<T, S> {
+    fn eq(&self, other: &SortedNamedMap<T>) -> bool {
+        other == self
+    }
+}
+
+impl<T> Deref for SortedNamedMap<T> {
+    type Target = BTreeMap<String, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for SortedNamedMap<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> IntoIterator for SortedNamedMap<T> {
+    type Item = (String, T);
+    type IntoIter = btree_map::IntoIter<String, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SortedNamedMap<T> {
+    type Item = (&'a String, &'a T);
+    type IntoIter = btree_map::Iter<'a, String, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut SortedNamedMap<T> {
+    type Item = (&'a String, &'a mut T);
+    type IntoIter = btree_map::IterMut<'a, String, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T> FromIterator<(String, T)> for SortedNamedMap<T> {
+    fn from_iter<I: IntoIterator<Item = (String, T)>>(iter: I) -> Self {
+        SortedNamedMap(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<(String, T)> for SortedNamedMap<T> {
+    /// Inserts every pair; later values overwrite existing keys.
+    fn extend<I: IntoIterator<Item = (String, T)>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+// Allow construction from a simple list of names; repeated names collapse
+// and empty ones are skipped, as for `NamedMap`.
+impl<T: FromName> From<Vec<String>> for SortedNamedMap<T> {
+    fn from(list: Vec<String>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T: FromName> FromIterator<String> for SortedNamedMap<T> {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        SortedNamedMap(
+            iter.into_iter()
+                // `SkipEmpty` never fails.
+                .filter_map(|name| NamePolicy::SkipEmpty.check(Cow::Owned(name)).ok().flatten())
+                .map(|name| {
+                    let value = T::from_name(&name);
+                    (name.into_owned(), value)
+                })
+                .collect(),
+        )
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use serde::{ser::Serializer, Deserialize, Deserializer, Serialize};
+
+    impl<T> Serialize for SortedNamedMap<T>
+    where
+        T: Serialize,
+    {
+        /// Serializes as an object whose keys are in lexicographic order.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_map(&self.0)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for SortedNamedMap<T>
+    where
+        T: Deserialize<'de> + FromName + Clone,
+    {
+        /// Deserializes exactly like [`NamedMap`] (every form, repeated keys
+        /// and names rejected), then sorts the entries.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            NamedMap::<T>::deserialize(deserializer).map(SortedNamedMap::from)
+        }
+    }
+}
+
+// === JSON-SPECIFIC CONVENIENCE METHODS ===
+
+#[cfg(feature = "serde_json")]
+impl<T> SortedNamedMap<T>
+where
+    T: serde::Serialize,
+{
+    /// Serialize this map to a `serde_json::Value`.
+    pub fn to_json_value(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(self)
+    }
+
+    /// Serialize this map to a compact JSON string, with sorted keys.
+    pub fn to_json_string(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Serialize this map to an indented JSON string, with sorted keys.
+    pub fn to_json_string_pretty(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> SortedNamedMap<T>
+where
+    T: for<'de> serde::Deserialize<'de> + FromName + Clone,
+{
+    /// Parse a `SortedNamedMap` from a `serde_json::Value`.
+    ///
+    /// Like [`NamedMap::from_json_value`], `null` entries become
+    /// `T::from_name(key)`.
+    pub fn from_json_value(value: serde_json::Value) -> serde_json::Result<Self> {
+        NamedMap::<T>::from_json_value(value).map(Self::from)
+    }
+
+    /// Parse a `SortedNamedMap` from a JSON string.
+    pub fn from_json_str(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    struct TestItem {
+        value: String,
+    }
+
+    impl FromName for TestItem {
+        fn from_name(name: &str) -> Self {
+            Self {
+                value: format!("from_name({})", name),
+            }
+        }
+    }
+
+    fn names<T>(map: &SortedNamedMap<T>) -> Vec<&str> {
+        map.keys().map(String::as_str).collect()
+    }
+
+    #[test]
+    fn test_from_vec_sorts() {
+        let map: SortedNamedMap<TestItem> = SortedNamedMap::from(vec![
+            "zeta".to_string(),
+            "alpha".to_string(),
+            "Mid".to_string(),
+            "alpha".to_string(),
+            " ".to_string(),
+        ]);
+        assert_eq!(names(&map), ["Mid", "alpha", "zeta"]);
+    }
+
+    #[test]
+    fn test_named_map_conversions_and_equality() {
+        let unordered = NamedMap::<TestItem>::from(vec!["x".to_string(), "y".to_string()]);
+        let sorted = SortedNamedMap::from(unordered.clone());
+        assert_eq!(sorted, unordered);
+        assert_eq!(unordered, sorted);
+
+        let back: NamedMap<TestItem> = sorted.clone().into();
+        assert_eq!(back, unordered);
+
+        let mut changed = sorted.clone();
+        changed.insert("y".to_string(), TestItem::from_name("z"));
+        assert_ne!(changed, unordered);
+        changed.remove("y");
+        assert_ne!(unordered, changed);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_sorted_output() {
+        let map = SortedNamedMap::<TestItem>::from_json_str(
+            r#"["zeta", {"alpha": {"value": "a"}}, "mid"]"#,
+        )
+        .unwrap();
+        assert_eq!(names(&map), ["alpha", "mid", "zeta"]);
+        assert_eq!(
+            map.to_json_string().unwrap(),
+            r#"{"alpha":{"value":"a"},"mid":{"value":"from_name(mid)"},"zeta":{"value":"from_name(zeta)"}}"#
+        );
+        assert_eq!(
+            SortedNamedMap::from_json_value(map.to_json_value().unwrap()).unwrap(),
+            map
+        );
+
+        let err = SortedNamedMap::<TestItem>::from_json_str(r#"["a", "a"]"#).unwrap_err();
+        assert!(
+            err.to_string().contains("duplicate name \"a\" at index 1"),
+            "{}",
+            err
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_toml_sorted_output() {
+        #[derive(Serialize, Deserialize)]
+        struct Manifest {
+            layers: SortedNamedMap<TestItem>,
+        }
+
+        let manifest: Manifest = toml::from_str(r#"layers = ["zeta", "alpha", "mid"]"#).unwrap();
+        let toml_str = toml::to_string(&manifest).unwrap();
+        let alpha = toml_str.find("[layers.alpha]").unwrap();
+        let mid = toml_str.find("[layers.mid]").unwrap();
+        let zeta = toml_str.find("[layers.zeta]").unwrap();
+        assert!(alpha < mid && mid < zeta, "{}", toml_str);
+
+        let restored: Manifest = toml::from_str(&toml_str).unwrap();
+        assert_eq!(restored.layers, manifest.layers);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_binary_roundtrip() {
+        let map = SortedNamedMap::<TestItem>::from(vec!["b".to_string(), "a".to_string()]);
+        let bytes = bincode::serialize(&map).unwrap();
+        assert_eq!(
+            bincode::deserialize::<SortedNamedMap<TestItem>>(&bytes).unwrap(),
+            map
+        );
+    }
+}
@@ -0,0 +1,208 @@
+// flexicon/src/adaptive/source.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `config` crate integration: `NamedMap` as a `Source` and conversion from
+//! `config::Value`.
+
+use config::{Config, ConfigError, Map, Source, Value, ValueKind};
+use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::Debug;
+
+use super::{FromName, NamedMap};
+
+/// A `config::Source` that layers a [`NamedMap`] into a `Config`, at the
+/// root or under a key path.
+///
+/// ```rust,ignore
+/// let config = Config::builder()
+///     .add_source(NamedMapSource::new(defaults).key("app.plugins"))
+///     .add_source(File::with_name("App"))
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct NamedMapSource<T> {
+    map: NamedMap<T>,
+    key: Option<String>,
+}
+
+impl<T> NamedMapSource<T> {
+    /// Layers `map` at the root of the configuration.
+    pub fn new(map: NamedMap<T>) -> Self {
+        Self { map, key: None }
+    }
+
+    /// Layers the map under the dotted key path `key` (e.g. `app.plugins`)
+    /// instead of the root.
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = Some(key.to_string());
+        self
+    }
+}
+
+impl<T> From<NamedMap<T>> for NamedMapSource<T> {
+    fn from(map: NamedMap<T>) -> Self {
+        Self::new(map)
+    }
+}
+
+impl<T> Source for NamedMapSource<T>
+where
+    T: Serialize + Clone + Debug + Send + Sync + 'static,
+{
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        let table: Map<String, Value> = Config::try_from(&self.map)?.try_deserialize()?;
+        Ok(match &self.key {
+            // `Config` resolves the dotted key path when merging.
+            Some(key) => Map::from_iter([(key.clone(), Value::new(None, table))]),
+            None => table,
+        })
+    }
+}
+
+impl<T> NamedMap<T>
+where
+    T: DeserializeOwned + FromName + Clone,
+{
+    /// Converts a `config::Value` holding a table of entries, an array of
+    /// names or a single name.
+    ///
+    /// `config` keeps scalars loosely typed, and its deserializer already
+    /// coerces them to what `T` asks for (`version = 2` into a `String`
+    /// field, `"true"` into a `bool`). Names in the array form are coerced
+    /// to strings likewise, so `[1, 2]` names entries `"1"` and `"2"`.
+    pub fn from_config_value(value: Value) -> Result<Self, ConfigError> {
+        let origin = value.origin().map(str::to_string);
+        let value = match value.kind {
+            ValueKind::Array(items) => {
+                let items = items
+                    .into_iter()
+                    .map(|item| match item.kind {
+                        ValueKind::Table(_) => Ok(item),
+                        _ => item
+                            .into_string()
+                            .map(|name| Value::new(origin.as_ref(), name)),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Value::new(origin.as_ref(), items)
+            }
+            kind @ (ValueKind::Table(_) | ValueKind::String(_) | ValueKind::Nil) => {
+                Value::new(origin.as_ref(), kind)
+            }
+            kind => Value::new(origin.as_ref(), Value::new(None, kind).into_string()?),
+        };
+        value.try_deserialize()
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{File, FileFormat};
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct TestItem {
+        value: String,
+        optional: bool,
+    }
+
+    impl FromName for TestItem {
+        fn from_name(name: &str) -> Self {
+            Self {
+                value: format!("from_name({})", name),
+                optional: false,
+            }
+        }
+    }
+
+    const TOML: &str = r#"
+        [app.plugins.logger]
+        value = 42
+        optional = "true"
+
+        [app.plugins.http]
+        value = "file"
+        optional = false
+    "#;
+
+    fn build(sources: Vec<Box<dyn Source + Send + Sync>>) -> Config {
+        Config::builder().add_source(sources).build().unwrap()
+    }
+
+    #[test]
+    fn test_table_form_with_loose_scalars() {
+        let config = build(vec![Box::new(File::from_str(TOML, FileFormat::Toml))]);
+        let plugins =
+            NamedMap::<TestItem>::from_config_value(config.get("app.plugins").unwrap()).unwrap();
+        assert_eq!(plugins.len(), 2);
+        assert_eq!(plugins["logger"].value, "42");
+        assert!(plugins["logger"].optional);
+        assert_eq!(plugins["http"].value, "file");
+    }
+
+    #[test]
+    fn test_array_and_single_name_forms() {
+        let config = build(vec![Box::new(File::from_str(
+            "[app]\nnames = [\"a\", 2]\nsingle = \"only\"\n",
+            FileFormat::Toml,
+        ))]);
+        let names =
+            NamedMap::<TestItem>::from_config_value(config.get("app.names").unwrap()).unwrap();
+        assert_eq!(names["a"].value, "from_name(a)");
+        assert_eq!(names["2"].value, "from_name(2)");
+
+        let single =
+            NamedMap::<TestItem>::from_config_value(config.get("app.single").unwrap()).unwrap();
+        assert_eq!(single.len(), 1);
+
+        let err =
+            NamedMap::<TestItem>::from_config_value(Value::new(None, vec![vec![1]])).unwrap_err();
+        assert!(err.to_string().contains("string"), "{}", err);
+    }
+
+    #[test]
+    fn test_source_layered_under_file() {
+        let mut defaults = NamedMap::from(vec!["metrics".to_string()]);
+        defaults.insert(
+            "http".to_string(),
+            TestItem {
+                value: "default".to_string(),
+                optional: true,
+            },
+        );
+        let config = build(vec![
+            Box::new(NamedMapSource::new(defaults).key("app.plugins")),
+            Box::new(File::from_str(TOML, FileFormat::Toml)),
+        ]);
+
+        let plugins =
+            NamedMap::<TestItem>::from_config_value(config.get("app.plugins").unwrap()).unwrap();
+        assert_eq!(plugins.len(), 3);
+        assert_eq!(plugins["metrics"].value, "from_name(metrics)");
+        assert_eq!(plugins["http"].value, "file");
+        assert!(!plugins["http"].optional);
+    }
+
+    #[test]
+    fn test_source_at_root() {
+        let map = NamedMap::<TestItem>::from(vec!["logger".to_string()]);
+        let config = build(vec![Box::new(NamedMapSource::from(map.clone()))]);
+        let value = config.get::<Value>("logger.value").unwrap();
+        assert_eq!(value.into_string().unwrap(), "from_name(logger)");
+
+        let root: NamedMap<TestItem> = config.try_deserialize().unwrap();
+        assert_eq!(root, map);
+    }
+}
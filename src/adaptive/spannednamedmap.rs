@@ -0,0 +1,533 @@
+// flexicon/src/adaptive/spannednamedmap.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use core::ops::{Deref, Range};
+
+use serde::de::{self, Deserializer, MapAccess, SeqAccess};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_spanned::Spanned;
+
+use super::map::HashMap;
+use super::namedmap::{NamedMapVisitor, Place};
+use super::seq::{next_named_value, SeqItem};
+use super::{DuplicateNameError, DuplicatePolicy, FromName, NamePolicy, NamedMap};
+
+/// A [`NamedMap`] that remembers where each entry was written in the
+/// source document, so validation errors can point at a line.
+///
+/// The span of an entry covers its value in the object form (for TOML,
+/// the `[table.header]` of a table), the name string or the inline
+/// single-key map in the array form, and the whole string in the
+/// single-name form.
+///
+/// Spans come from [`serde_spanned::Spanned`], so the `Deserialize` impl
+/// works with formats that support it (TOML); for JSON, use
+/// [`from_json_str`](Self::from_json_str). Serialization emits the plain
+/// object form. The map dereferences to a read-only [`NamedMap`].
+///
+/// Requires the `spans` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::Deserialize;
+/// # #[derive(Debug, Clone, Deserialize)]
+/// # struct Plugin { version: String }
+/// # impl flexicon::adaptive::FromName for Plugin {
+/// #     fn from_name(_name: &str) -> Self { Self { version: "latest".into() } }
+/// # }
+/// use flexicon::adaptive::SpannedNamedMap;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     plugins: SpannedNamedMap<Plugin>,
+/// }
+///
+/// let source = r#"
+/// [plugins.http]
+/// version = "1.0"
+///
+/// [plugins.logger]
+/// version = "0.2"
+/// "#;
+/// let config: Config = toml::from_str(source).unwrap();
+/// assert_eq!(config.plugins.line_of("logger", source), Some(5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpannedNamedMap<T> {
+    map: NamedMap<T>,
+    spans: HashMap<String, Range<usize>>,
+}
+
+impl<T> SpannedNamedMap<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: NamedMap::with_capacity(capacity),
+            spans: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the byte range of the entry `name` in the source document.
+    pub fn span_of(&self, name: &str) -> Option<Range<usize>> {
+        self.spans.get(name).cloned()
+    }
+
+    /// Returns the 1-based line of the entry `name` in `source`, the
+    /// document the map was parsed from.
+    pub fn line_of(&self, name: &str, source: &str) -> Option<usize> {
+        let before = source.as_bytes().get(..self.spans.get(name)?.start)?;
+        Some(before.iter().filter(|&&byte| byte == b'\n').count() + 1)
+    }
+
+    /// Returns the map, dropping the spans.
+    pub fn into_inner(self) -> NamedMap<T> {
+        self.map
+    }
+}
+
+// Read-only: mutable access could insert entries without a span.
+impl<T> Deref for SpannedNamedMap<T> {
+    type Target = NamedMap<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<T> From<SpannedNamedMap<T>> for NamedMap<T> {
+    fn from(map: SpannedNamedMap<T>) -> Self {
+        map.map
+    }
+}
+
+// === SERDE INTEGRATION (formats with span support) ===
+
+/// The entries of one spanned value. The span of the single-name form is
+/// only known one level up, so its entry waits for it in `single`.
+struct Parsed<T> {
+    map: SpannedNamedMap<T>,
+    single: bool,
+}
+
+impl<T: FromName> Place for Parsed<T> {
+    type Value = T;
+    /// `None` for the single-name form.
+    type Meta = Option<Range<usize>>;
+
+    fn name_meta() -> Self::Meta {
+        None
+    }
+
+    fn from_name<E: de::Error>(name: &str) -> Result<T, E> {
+        Ok(T::from_name(name))
+    }
+
+    fn next_item<'de, A>(
+        seq: &mut A,
+        _nullable: bool,
+    ) -> Result<Option<(SeqItem<T>, Self::Meta)>, A::Error>
+    where
+        A: SeqAccess<'de>,
+        T: Deserialize<'de>,
+    {
+        Ok(seq.next_element::<Spanned<SeqItem<T>>>()?.map(|item| {
+            let span = item.span();
+            (item.into_inner(), Some(span))
+        }))
+    }
+
+    fn next_value<'de, A>(
+        access: &mut A,
+        name: &str,
+        _nullable: bool,
+    ) -> Result<(T, Self::Meta), A::Error>
+    where
+        A: MapAccess<'de>,
+        T: Deserialize<'de>,
+    {
+        let value: Spanned<T> = next_named_value(access, name)?;
+        let span = value.span();
+        Ok((value.into_inner(), Some(span)))
+    }
+
+    fn clear(&mut self) {
+        self.map.map.clear();
+        self.map.spans.clear();
+        self.single = false;
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.map.map.reserve(additional);
+        self.map.spans.reserve(additional);
+    }
+
+    fn contains_key(&self, name: &str) -> bool {
+        self.map.map.contains_key(name)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn len(&self) -> usize {
+        self.map.map.len()
+    }
+
+    fn insert(
+        &mut self,
+        policy: DuplicatePolicy,
+        name: String,
+        value: T,
+        span: Self::Meta,
+        index: usize,
+    ) -> Result<(), DuplicateNameError> {
+        if span.is_none() {
+            self.single = true;
+        }
+        // Both maps hold the same names, so `policy` decides alike for each.
+        policy.insert(
+            &mut self.map.spans,
+            name.clone(),
+            span.unwrap_or_default(),
+            index,
+        )?;
+        policy.insert(self.map.map.as_inner_mut(), name, value, index)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Parsed<T>
+where
+    T: Deserialize<'de> + FromName,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut parsed = Parsed {
+            map: SpannedNamedMap::with_capacity(0),
+            single: false,
+        };
+        // Span support implies a self-describing format.
+        deserializer.deserialize_any(NamedMapVisitor {
+            place: &mut parsed,
+            policy: DuplicatePolicy::Error,
+            names: NamePolicy::RejectEmpty,
+            nullable: false,
+            self_describing: true,
+        })?;
+        Ok(parsed)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for SpannedNamedMap<T>
+where
+    T: Deserialize<'de> + FromName,
+{
+    /// Deserializes from the same forms as [`NamedMap`], recording the
+    /// span of every entry. The format must support
+    /// [`serde_spanned::Spanned`].
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let parsed = Spanned::<Parsed<T>>::deserialize(deserializer)?;
+        let span = parsed.span();
+        let Parsed { mut map, single } = parsed.into_inner();
+        if single {
+            for entry in map.spans.values_mut() {
+                *entry = span.clone();
+            }
+        }
+        Ok(map)
+    }
+}
+
+impl<T: Serialize> Serialize for SpannedNamedMap<T> {
+    /// Serializes the entries only, as [`NamedMap`] does.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.map.serialize(serializer)
+    }
+}
+
+// === JSON ===
+
+#[cfg(feature = "serde_json")]
+mod json {
+    use super::*;
+    use crate::adaptive::map::Entry;
+    use alloc::vec::Vec;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::de::{self, DeserializeOwned, Visitor};
+    use serde_json::value::RawValue;
+
+    impl<T> SpannedNamedMap<T> {
+        /// Inserts a parsed entry; `Err` hands back the name if it is
+        /// already present.
+        fn insert_new(&mut self, name: String, value: T, span: Range<usize>) -> Result<(), String> {
+            match self.map.as_inner_mut().entry(name) {
+                Entry::Occupied(entry) => Err(entry.key().clone()),
+                Entry::Vacant(entry) => {
+                    self.spans.insert(entry.key().clone(), span);
+                    entry.insert(value);
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// The entries of a JSON object, values left unparsed.
+    struct RawEntries<'a>(Vec<(String, &'a RawValue)>);
+
+    impl<'de: 'a, 'a> Deserialize<'de> for RawEntries<'a> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct RawEntriesVisitor<'a>(PhantomData<&'a ()>);
+
+            impl<'de: 'a, 'a> Visitor<'de> for RawEntriesVisitor<'a> {
+                type Value = RawEntries<'a>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, "a map")
+                }
+
+                fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+                where
+                    A: MapAccess<'de>,
+                {
+                    let mut entries = Vec::new();
+                    while let Some(entry) = access.next_entry()? {
+                        entries.push(entry);
+                    }
+                    Ok(RawEntries(entries))
+                }
+            }
+
+            deserializer.deserialize_map(RawEntriesVisitor(PhantomData))
+        }
+    }
+
+    fn error(message: fmt::Arguments<'_>) -> serde_json::Error {
+        de::Error::custom(message)
+    }
+
+    fn parse_value<T: DeserializeOwned>(name: &str, raw: &RawValue) -> serde_json::Result<T> {
+        serde_json::from_str(raw.get())
+            .map_err(|err| error(format_args!("error in entry {:?}: {}", name, err)))
+    }
+
+    impl<T: DeserializeOwned + FromName> SpannedNamedMap<T> {
+        /// Parses JSON in any of the forms [`NamedMap`] accepts, recording
+        /// the byte range of every entry in `s`.
+        ///
+        /// ```rust
+        /// # #[derive(Clone, serde::Deserialize)]
+        /// # struct Port(u16);
+        /// # impl flexicon::adaptive::FromName for Port {
+        /// #     fn from_name(_name: &str) -> Self { Port(0) }
+        /// # }
+        /// use flexicon::adaptive::SpannedNamedMap;
+        ///
+        /// let source = "{\n  \"http\": 80,\n  \"ssh\": 22\n}";
+        /// let map = SpannedNamedMap::<Port>::from_json_str(source).unwrap();
+        /// assert_eq!(map.span_of("ssh"), Some(25..27));
+        /// assert_eq!(map.line_of("ssh", source), Some(3));
+        /// ```
+        pub fn from_json_str(s: &str) -> serde_json::Result<Self> {
+            let span = |raw: &RawValue| {
+                let start = raw.get().as_ptr() as usize - s.as_ptr() as usize;
+                start..start + raw.get().len()
+            };
+            let root: &RawValue = serde_json::from_str(s)?;
+            match root.get().as_bytes().first() {
+                Some(b'{') => {
+                    let RawEntries(entries) = serde_json::from_str(root.get())?;
+                    let mut map = Self::with_capacity(entries.len());
+                    for (name, raw) in entries {
+                        if name.trim().is_empty() {
+                            return Err(error(format_args!("empty key {:?}", name)));
+                        }
+                        let value = parse_value(&name, raw)?;
+                        map.insert_new(name, value, span(raw))
+                            .map_err(|name| error(format_args!("duplicate key {:?}", name)))?;
+                    }
+                    Ok(map)
+                }
+                Some(b'[') => {
+                    let items: Vec<&RawValue> = serde_json::from_str(root.get())?;
+                    let mut map = Self::with_capacity(items.len());
+                    for (index, raw) in items.into_iter().enumerate() {
+                        let item = match raw.get().as_bytes().first() {
+                            Some(b'"') => SeqItem::Name(serde_json::from_str(raw.get())?),
+                            Some(b'{') => {
+                                let RawEntries(mut entries) = serde_json::from_str(raw.get())?;
+                                if entries.len() != 1 {
+                                    return Err(error(format_args!(
+                                        "inline entry at index {} must be a single-key map",
+                                        index
+                                    )));
+                                }
+                                let (name, value) = entries.remove(0);
+                                let value = parse_value(&name, value)?;
+                                SeqItem::Entry(name, value)
+                            }
+                            _ => {
+                                return Err(error(format_args!(
+                                    "expected a name or a single-key map at index {}",
+                                    index
+                                )))
+                            }
+                        };
+                        let item = item
+                            .check_name(NamePolicy::RejectEmpty, index)
+                            .map_err(|err| error(format_args!("{}", err)))?;
+                        if let Some(item) = item {
+                            let (name, value) = item.into_entry();
+                            map.insert_new(name, value, span(raw)).map_err(|name| {
+                                error(format_args!("{}", DuplicateNameError { name, index }))
+                            })?;
+                        }
+                    }
+                    Ok(map)
+                }
+                Some(b'"') => {
+                    let name: String = serde_json::from_str(root.get())?;
+                    if name.trim().is_empty() {
+                        return Err(error(format_args!("empty name {:?}", name)));
+                    }
+                    let value = T::from_name(&name);
+                    let mut map = Self::with_capacity(1);
+                    let _ = map.insert_new(name, value, span(root));
+                    Ok(map)
+                }
+                _ => Err(error(format_args!(
+                    "expected a map, a sequence of names and single-key maps, or a single name"
+                ))),
+            }
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Plugin {
+        version: String,
+    }
+
+    impl FromName for Plugin {
+        fn from_name(_name: &str) -> Self {
+            Self {
+                version: "latest".to_string(),
+            }
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Config {
+        plugins: SpannedNamedMap<Plugin>,
+    }
+
+    #[test]
+    fn test_toml_table_lines() {
+        let source = r#"
+[plugins.http]
+version = "1.0"
+
+[plugins.logger]
+version = "0.2"
+"#;
+        let config: Config = toml::from_str(source).unwrap();
+        let plugins = &config.plugins;
+        assert_eq!(plugins.line_of("http", source), Some(2));
+        assert_eq!(plugins.line_of("logger", source), Some(5));
+        assert_eq!(&source[plugins.span_of("http").unwrap()], "[plugins.http]");
+        assert_eq!(plugins["logger"].version, "0.2");
+        assert_eq!(plugins.line_of("metrics", source), None);
+    }
+
+    #[test]
+    fn test_toml_array_and_single_name() {
+        let source = r#"plugins = [
+    "http",
+    { logger = { version = "0.2" } },
+]
+"#;
+        let config: Config = toml::from_str(source).unwrap();
+        let plugins = &config.plugins;
+        assert_eq!(&source[plugins.span_of("http").unwrap()], r#""http""#);
+        assert_eq!(plugins.line_of("http", source), Some(2));
+        assert_eq!(plugins.line_of("logger", source), Some(3));
+        assert_eq!(plugins["http"], Plugin::from_name("http"));
+
+        let source = "\n\nplugins = \"http\"\n";
+        let config: Config = toml::from_str(source).unwrap();
+        assert_eq!(
+            &source[config.plugins.span_of("http").unwrap()],
+            r#""http""#
+        );
+        assert_eq!(config.plugins.line_of("http", source), Some(3));
+    }
+
+    #[test]
+    fn test_toml_errors() {
+        for (source, message) in [
+            ("plugins = [\"a\", \"a\"]", "duplicate name \"a\" at index 1"),
+            ("plugins = [\"a\", \" \"]", "empty name at index 1"),
+            ("[plugins.\" \"]\nversion = \"1\"", "empty key \" \""),
+            ("plugins = \" \"", "expected a non-empty name"),
+        ] {
+            let err = toml::from_str::<Config>(source).err().unwrap();
+            assert!(err.to_string().contains(message), "{}: {}", source, err);
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_spans() {
+        let source =
+            "{\n  \"http\": {\"version\": \"1.0\"},\n  \"logger\": {\"version\": \"0.2\"}\n}";
+        let map = SpannedNamedMap::<Plugin>::from_json_str(source).unwrap();
+        assert_eq!(
+            &source[map.span_of("http").unwrap()],
+            r#"{"version": "1.0"}"#
+        );
+        assert_eq!(map.line_of("logger", source), Some(3));
+
+        let source = "[\n  \"http\",\n  {\"logger\": {\"version\": \"0.2\"}}\n]";
+        let map = SpannedNamedMap::<Plugin>::from_json_str(source).unwrap();
+        assert_eq!(&source[map.span_of("http").unwrap()], r#""http""#);
+        assert_eq!(map.line_of("logger", source), Some(3));
+        assert_eq!(map["logger"].version, "0.2");
+
+        let map = SpannedNamedMap::<Plugin>::from_json_str(r#"  "http""#).unwrap();
+        assert_eq!(map.span_of("http"), Some(2..8));
+
+        for (source, message) in [
+            (r#"["a", "a"]"#, "duplicate name \"a\" at index 1"),
+            (r#"{"a": {"version": 1}}"#, "error in entry \"a\""),
+            (
+                r#"[{"a": {"version": "1"}, "b": {"version": "2"}}]"#,
+                "single-key map",
+            ),
+            ("[1]", "expected a name or a single-key map at index 0"),
+            ("[\"a\", ", "EOF"),
+        ] {
+            let err = SpannedNamedMap::<Plugin>::from_json_str(source).unwrap_err();
+            assert!(err.to_string().contains(message), "{}: {}", source, err);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// flexicon/src/adaptive/strategy.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `proptest` strategies for generating maps in property tests.
+
+use core::fmt;
+use core::ops::Range;
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::collection::vec;
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use super::NamedMap;
+
+/// The names generated by `any::<NamedMap<T>>()`: short, lowercase,
+/// starting with a letter.
+const DEFAULT_NAMES: &str = "[a-z][a-z0-9_-]{0,11}";
+
+/// Generates maps from `size` pairs of `keys` and `values`, e.g. with a
+/// small key set to exercise repeated names or a unicode regex to stress
+/// key handling.
+///
+/// `size` bounds the number of generated pairs; a repeated key keeps its
+/// last value, so the map may come out smaller. Keys are used as
+/// generated, empty ones included.
+///
+/// # Example
+///
+/// ```rust
+/// use flexicon::adaptive::named_map_strategy;
+/// use proptest::prelude::*;
+/// use proptest::test_runner::TestRunner;
+///
+/// let strategy = named_map_strategy("[ab]", any::<u16>(), 0..10);
+/// TestRunner::default()
+///     .run(&strategy, |map| {
+///         prop_assert!(map.len() <= 2);
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+pub fn named_map_strategy<T, K, V>(
+    keys: K,
+    values: V,
+    size: Range<usize>,
+) -> impl Strategy<Value = NamedMap<T>>
+where
+    T: fmt::Debug,
+    K: Strategy<Value = String>,
+    V: Strategy<Value = T>,
+{
+    vec((keys, values), size).prop_map(|pairs| pairs.into_iter().collect())
+}
+
+impl<T: Arbitrary + 'static> Arbitrary for NamedMap<T> {
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates up to eight entries with unique, non-empty names made of
+    /// lowercase letters, digits, `_` and `-`.
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        named_map_strategy(DEFAULT_NAMES, any_with::<T>(args), 0..9).boxed()
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_default_names_are_non_empty(map in any::<NamedMap<u32>>()) {
+            prop_assert!(map.len() <= 8);
+            prop_assert!(map.keys().all(|name| !name.trim().is_empty()));
+        }
+
+        #[test]
+        fn test_duplicate_prone_keys(map in named_map_strategy("[xyz]", any::<u8>(), 1..20)) {
+            prop_assert!(!map.is_empty());
+            prop_assert!(map.len() <= 3);
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod serde_round_trip {
+        use super::*;
+        use crate::adaptive::FromName;
+        use proptest::strategy::Map;
+
+        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Port(u16);
+
+        impl FromName for Port {
+            fn from_name(_name: &str) -> Self {
+                Port(0)
+            }
+        }
+
+        impl Arbitrary for Port {
+            type Parameters = ();
+            type Strategy = Map<<u16 as Arbitrary>::Strategy, fn(u16) -> Port>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                any::<u16>().prop_map(Port)
+            }
+        }
+
+        proptest! {
+            #[test]
+            fn test_json_round_trip(map in any::<NamedMap<Port>>()) {
+                let json = serde_json::to_string(&map).unwrap();
+                prop_assert_eq!(serde_json::from_str::<NamedMap<Port>>(&json).unwrap(), map);
+            }
+
+            #[test]
+            fn test_json_round_trip_unicode_keys(
+                map in named_map_strategy("\\PC{1,8}", any::<Port>(), 0..6)
+            ) {
+                prop_assume!(map.keys().all(|name| !name.trim().is_empty()));
+                let json = serde_json::to_string(&map).unwrap();
+                prop_assert_eq!(serde_json::from_str::<NamedMap<Port>>(&json).unwrap(), map);
+            }
+        }
+    }
+}
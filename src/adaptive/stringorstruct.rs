@@ -0,0 +1,290 @@
+// flexicon/src/adaptive/stringorstruct.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Values written as either a shorthand string or a full struct.
+
+use core::ops::{Deref, DerefMut};
+
+use super::FromName;
+
+/// A `T` that can also be written as a bare string, built with
+/// [`FromName::from_name`]: `image = "nginx"` reads like
+/// `image = { name = "nginx" }` would with a suitable `from_name`.
+///
+/// With the `serde` feature it deserializes from a string or from `T`'s own
+/// form, and always serializes as `T`. Formats that are not human-readable
+/// (bincode, postcard, ...) only accept `T`'s form, which is what
+/// `Serialize` writes. It implements `FromName` itself, so it also works as
+/// the value of a [`NamedMap`](super::NamedMap).
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// use flexicon::adaptive::{FromName, StringOrStruct};
+///
+/// #[derive(Debug, Clone, Serialize, Deserialize)]
+/// struct Image {
+///     name: String,
+///     #[serde(default)]
+///     tag: Option<String>,
+/// }
+///
+/// impl FromName for Image {
+///     fn from_name(name: &str) -> Self {
+///         Image { name: name.to_string(), tag: None }
+///     }
+/// }
+///
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() {
+/// let short: StringOrStruct<Image> = serde_json::from_str(r#""nginx""#).unwrap();
+/// let full: StringOrStruct<Image> =
+///     serde_json::from_str(r#"{"name": "nginx", "tag": "1.27"}"#).unwrap();
+/// assert_eq!(short.name, "nginx");
+/// assert_eq!(full.tag.as_deref(), Some("1.27"));
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StringOrStruct<T>(T);
+
+impl<T> StringOrStruct<T> {
+    /// Wraps `value`.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: FromName> FromName for StringOrStruct<T> {
+    fn from_name(name: &str) -> Self {
+        Self(T::from_name(name))
+    }
+}
+
+impl<T> Deref for StringOrStruct<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for StringOrStruct<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for StringOrStruct<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use alloc::string::String;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::{
+        de::{self, value::MapAccessDeserializer, Deserializer, MapAccess, Visitor},
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+
+    /// Builds strings with `FromName` and defers maps to `T`.
+    struct StringOrStructVisitor<T> {
+        _phantom: PhantomData<T>,
+    }
+
+    impl<'de, T> Visitor<'de> for StringOrStructVisitor<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        type Value = StringOrStruct<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a string shorthand or a full object")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(StringOrStruct(T::from_name(v)))
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            self.visit_str(&v)
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            T::deserialize(MapAccessDeserializer::new(map)).map(StringOrStruct)
+        }
+    }
+
+    impl<T: Serialize> Serialize for StringOrStruct<T> {
+        /// Serializes as `T`, never as the shorthand.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for StringOrStruct<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        /// Deserializes from a string, via `FromName`, or from `T`'s form.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(StringOrStructVisitor {
+                    _phantom: PhantomData,
+                })
+            } else {
+                T::deserialize(deserializer).map(StringOrStruct)
+            }
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct Image {
+        name: String,
+        #[cfg_attr(feature = "serde", serde(default))]
+        tag: Option<String>,
+    }
+
+    impl FromName for Image {
+        fn from_name(name: &str) -> Self {
+            Image {
+                name: name.to_string(),
+                tag: None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrapper_api() {
+        let mut image = StringOrStruct::<Image>::from_name("nginx");
+        assert_eq!(image.name, "nginx");
+        image.tag = Some("1.27".to_string());
+        assert_eq!(image.clone().into_inner().tag.as_deref(), Some("1.27"));
+        assert_eq!(
+            StringOrStruct::from(Image::from_name("redis")).name,
+            "redis"
+        );
+        assert_eq!(StringOrStruct::new(1).into_inner(), 1);
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod serde_tests {
+        use super::*;
+        use crate::adaptive::NamedMap;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Service {
+            image: StringOrStruct<Image>,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct Compose {
+            services: NamedMap<StringOrStruct<Image>>,
+        }
+
+        #[test]
+        fn test_json_shapes() {
+            let short: Service = serde_json::from_str(r#"{"image": "nginx"}"#).unwrap();
+            assert_eq!(*short.image, Image::from_name("nginx"));
+
+            let full: Service =
+                serde_json::from_str(r#"{"image": {"name": "nginx", "tag": "1.27"}}"#).unwrap();
+            assert_eq!(full.image.tag.as_deref(), Some("1.27"));
+
+            assert_eq!(
+                serde_json::to_value(&short).unwrap(),
+                serde_json::json!({"image": {"name": "nginx", "tag": null}})
+            );
+            let json = serde_json::to_string(&full).unwrap();
+            assert_eq!(serde_json::from_str::<Service>(&json).unwrap(), full);
+            let bytes = bincode::serialize(&full).unwrap();
+            assert_eq!(bincode::deserialize::<Service>(&bytes).unwrap(), full);
+
+            let err = serde_json::from_str::<Service>(r#"{"image": 7}"#).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("a string shorthand or a full object"),
+                "{}",
+                err
+            );
+        }
+
+        #[test]
+        fn test_toml_shapes() {
+            let short: Service = toml::from_str("image = \"nginx\"\n").unwrap();
+            assert_eq!(short.image.name, "nginx");
+
+            let full: Service =
+                toml::from_str("[image]\nname = \"nginx\"\ntag = \"1.27\"\n").unwrap();
+            assert_eq!(full.image.tag.as_deref(), Some("1.27"));
+            let text = toml::to_string(&full).unwrap();
+            assert_eq!(toml::from_str::<Service>(&text).unwrap(), full);
+        }
+
+        #[test]
+        fn test_inside_named_map() {
+            let compose: Compose = serde_json::from_str(
+                r#"{"services": {"web": "nginx", "cache": {"name": "redis", "tag": "7"}}}"#,
+            )
+            .unwrap();
+            assert_eq!(compose.services["web"].name, "nginx");
+            assert_eq!(compose.services["cache"].tag.as_deref(), Some("7"));
+
+            let compose: Compose = toml::from_str(
+                "[services]\nweb = \"nginx\"\ncache = { name = \"redis\", tag = \"7\" }\n",
+            )
+            .unwrap();
+            assert_eq!(compose.services["web"].name, "nginx");
+            assert_eq!(compose.services["cache"].name, "redis");
+
+            let compose: Compose = toml::from_str("services = [\"proxy\"]\n").unwrap();
+            assert_eq!(compose.services["proxy"].name, "proxy");
+        }
+    }
+}
@@ -0,0 +1,325 @@
+// flexicon/src/adaptive/toggle.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Feature switches written as a boolean or as their configuration.
+
+use super::FromName;
+
+/// A feature that is switched on or off with a boolean, or switched on
+/// with explicit settings: `metrics = true`, `metrics = false` or
+/// `metrics = { port = 9000 }`.
+///
+/// With the `serde` feature `true` becomes `Enabled(T::default())`,
+/// `false` becomes `Disabled` and an object becomes `Configured`.
+/// `Disabled` serializes as `false` and the others as their `T`, so a
+/// written toggle reads back as `Configured`. Formats that are not
+/// human-readable (bincode, postcard, ...) store it as an `Option<T>`.
+///
+/// In the array form of a [`NamedMap`](super::NamedMap), a bare name builds
+/// `Enabled(T::from_name(name))`.
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// # use serde::Deserialize;
+/// use flexicon::adaptive::Toggle;
+///
+/// #[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+/// struct Metrics {
+///     #[serde(default)]
+///     port: u16,
+/// }
+///
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() {
+/// let on: Toggle<Metrics> = serde_json::from_str("true").unwrap();
+/// let off: Toggle<Metrics> = serde_json::from_str("false").unwrap();
+/// let custom: Toggle<Metrics> = serde_json::from_str(r#"{"port": 9000}"#).unwrap();
+/// assert!(on.is_enabled() && !off.is_enabled());
+/// assert_eq!(custom.as_config().map(|metrics| metrics.port), Some(9000));
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Toggle<T> {
+    /// Switched on without settings (`true`); holds the default
+    /// configuration.
+    Enabled(T),
+    /// Switched off (`false`).
+    #[default]
+    Disabled,
+    /// Switched on with explicit settings.
+    Configured(T),
+}
+
+impl<T> Toggle<T> {
+    /// Returns `true` unless the toggle is `Disabled`.
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, Toggle::Disabled)
+    }
+
+    /// Returns the configuration in effect, or `None` if disabled.
+    pub fn as_config(&self) -> Option<&T> {
+        match self {
+            Toggle::Enabled(config) | Toggle::Configured(config) => Some(config),
+            Toggle::Disabled => None,
+        }
+    }
+
+    /// Returns a mutable reference to the configuration, or `None` if
+    /// disabled.
+    pub fn as_config_mut(&mut self) -> Option<&mut T> {
+        match self {
+            Toggle::Enabled(config) | Toggle::Configured(config) => Some(config),
+            Toggle::Disabled => None,
+        }
+    }
+
+    /// Returns the configuration in effect, or `None` if disabled.
+    pub fn into_config(self) -> Option<T> {
+        match self {
+            Toggle::Enabled(config) | Toggle::Configured(config) => Some(config),
+            Toggle::Disabled => None,
+        }
+    }
+}
+
+impl<T: FromName> FromName for Toggle<T> {
+    /// A bare name switches the feature on with its named defaults.
+    fn from_name(name: &str) -> Self {
+        Toggle::Enabled(T::from_name(name))
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::{
+        de::{self, value::MapAccessDeserializer, Deserializer, MapAccess, Visitor},
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+
+    struct ToggleVisitor<T> {
+        _phantom: PhantomData<T>,
+    }
+
+    impl<'de, T> Visitor<'de> for ToggleVisitor<T>
+    where
+        T: Deserialize<'de> + Default,
+    {
+        type Value = Toggle<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a boolean or an object")
+        }
+
+        fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(if v {
+                Toggle::Enabled(T::default())
+            } else {
+                Toggle::Disabled
+            })
+        }
+
+        /// RON may present the value wrapped in an option; the wrapper
+        /// carries no meaning here.
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            T::deserialize(MapAccessDeserializer::new(map)).map(Toggle::Configured)
+        }
+    }
+
+    impl<T: Serialize> Serialize for Toggle<T> {
+        /// Serializes `Disabled` as `false` and the others as their `T`.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if !serializer.is_human_readable() {
+                return self.as_config().serialize(serializer);
+            }
+            match self.as_config() {
+                Some(config) => config.serialize(serializer),
+                None => serializer.serialize_bool(false),
+            }
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for Toggle<T>
+    where
+        T: Deserialize<'de> + Default,
+    {
+        /// Deserializes from `true`, `false` or `T`'s object form.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(ToggleVisitor {
+                    _phantom: PhantomData,
+                })
+            } else {
+                Ok(match Option::<T>::deserialize(deserializer)? {
+                    Some(config) => Toggle::Configured(config),
+                    None => Toggle::Disabled,
+                })
+            }
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    struct Metrics {
+        port: u16,
+        path: Option<alloc::string::String>,
+    }
+
+    impl FromName for Metrics {
+        fn from_name(_name: &str) -> Self {
+            Metrics {
+                port: 9100,
+                path: None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_helpers() {
+        let mut toggle = Toggle::Enabled(Metrics::default());
+        assert!(toggle.is_enabled());
+        toggle.as_config_mut().unwrap().port = 1;
+        assert_eq!(toggle.as_config().unwrap().port, 1);
+        assert_eq!(Toggle::Configured(7).into_config(), Some(7));
+
+        let off = Toggle::<Metrics>::default();
+        assert_eq!(off, Toggle::Disabled);
+        assert!(!off.is_enabled());
+        assert_eq!(off.as_config(), None);
+
+        assert_eq!(
+            Toggle::<Metrics>::from_name("metrics")
+                .into_config()
+                .unwrap()
+                .port,
+            9100
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod serde_tests {
+        use super::*;
+        use crate::adaptive::NamedMap;
+        use serde::{Deserialize, Serialize};
+        use serde_json::json;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Config {
+            metrics: Toggle<Metrics>,
+        }
+
+        #[test]
+        fn test_json_inputs() {
+            let on: Config = serde_json::from_str(r#"{"metrics": true}"#).unwrap();
+            assert_eq!(on.metrics, Toggle::Enabled(Metrics::default()));
+            let off: Config = serde_json::from_str(r#"{"metrics": false}"#).unwrap();
+            assert_eq!(off.metrics, Toggle::Disabled);
+            let custom: Config = serde_json::from_str(r#"{"metrics": {"port": 9000}}"#).unwrap();
+            assert_eq!(custom.metrics.as_config().unwrap().port, 9000);
+            assert!(matches!(custom.metrics, Toggle::Configured(_)));
+
+            let err = serde_json::from_str::<Config>(r#"{"metrics": "yes"}"#).unwrap_err();
+            assert!(
+                err.to_string().contains("a boolean or an object"),
+                "{}",
+                err
+            );
+        }
+
+        #[test]
+        fn test_toml_inputs() {
+            let on: Config = toml::from_str("metrics = true\n").unwrap();
+            assert!(matches!(on.metrics, Toggle::Enabled(_)));
+            let off: Config = toml::from_str("metrics = false\n").unwrap();
+            assert_eq!(off.metrics, Toggle::Disabled);
+            let custom: Config = toml::from_str("[metrics]\nport = 9000\n").unwrap();
+            assert_eq!(custom.metrics.as_config().unwrap().port, 9000);
+        }
+
+        #[test]
+        fn test_round_trip_is_stable() {
+            for input in [
+                json!(true),
+                json!(false),
+                json!({"port": 9000, "path": "/metrics"}),
+            ] {
+                let toggle: Toggle<Metrics> = serde_json::from_value(input).unwrap();
+                let written = serde_json::to_value(&toggle).unwrap();
+                let reread: Toggle<Metrics> = serde_json::from_value(written.clone()).unwrap();
+                assert_eq!(reread.as_config(), toggle.as_config());
+                assert_eq!(serde_json::to_value(&reread).unwrap(), written);
+
+                let bytes = bincode::serialize(&toggle).unwrap();
+                let decoded: Toggle<Metrics> = bincode::deserialize(&bytes).unwrap();
+                assert_eq!(decoded.as_config(), toggle.as_config());
+            }
+            assert_eq!(
+                serde_json::to_value(Toggle::<Metrics>::Disabled).unwrap(),
+                json!(false)
+            );
+        }
+
+        #[test]
+        fn test_inside_named_map() {
+            let map: NamedMap<Toggle<Metrics>> = serde_json::from_str(
+                r#"{"metrics": true, "tracing": false, "profiling": {"port": 6060}}"#,
+            )
+            .unwrap();
+            assert!(map["metrics"].is_enabled());
+            assert!(!map["tracing"].is_enabled());
+            assert_eq!(map["profiling"].as_config().unwrap().port, 6060);
+
+            let map: NamedMap<Toggle<Metrics>> =
+                serde_json::from_str(r#"["metrics", {"tracing": false}]"#).unwrap();
+            assert_eq!(map["metrics"].as_config().unwrap().port, 9100);
+            assert_eq!(map["tracing"], Toggle::Disabled);
+
+            let map: NamedMap<Toggle<Metrics>> =
+                toml::from_str("metrics = true\ntracing = false\n[profiling]\nport = 6060\n")
+                    .unwrap();
+            assert_eq!(map.len(), 3);
+            assert_eq!(map["profiling"].as_config().unwrap().port, 6060);
+        }
+    }
+}
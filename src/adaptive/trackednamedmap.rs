@@ -0,0 +1,378 @@
+// flexicon/src/adaptive/trackednamedmap.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use core::ops::Deref;
+
+use super::map::HashMap;
+use super::NamedMap;
+
+/// How an entry of a [`TrackedNamedMap`] came to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// Written as a bare name (`"a"` or `["a"]`) and built with `FromName`.
+    Simple,
+    /// Written with a value, in the object form or as an inline
+    /// single-key map (`[{ "a": {...} }]`).
+    Detailed,
+    /// Inserted through the API rather than deserialized.
+    Programmatic,
+}
+
+/// A [`NamedMap`] that remembers, per entry, whether it was authored as a
+/// bare name or with a full value — e.g. for a linter suggesting the
+/// shorter form.
+///
+/// The origins are metadata only: serialization emits the plain object
+/// form, exactly like [`NamedMap`]. The map dereferences to a read-only
+/// [`NamedMap`].
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # #[derive(Debug, Clone, Serialize, Deserialize)]
+/// # struct Plugin { enabled: bool }
+/// # impl flexicon::adaptive::FromName for Plugin {
+/// #     fn from_name(_name: &str) -> Self { Self { enabled: true } }
+/// # }
+/// use flexicon::adaptive::{Origin, TrackedNamedMap};
+///
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() {
+/// let map: TrackedNamedMap<Plugin> =
+///     serde_json::from_str(r#"["logger", {"http": {"enabled": false}}]"#).unwrap();
+/// assert_eq!(map.origin("logger"), Some(Origin::Simple));
+/// assert_eq!(map.origin("http"), Some(Origin::Detailed));
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackedNamedMap<T> {
+    map: NamedMap<T>,
+    origins: HashMap<String, Origin>,
+}
+
+impl<T> TrackedNamedMap<T> {
+    /// Creates an empty `TrackedNamedMap`.
+    pub fn new() -> Self {
+        Self {
+            map: NamedMap::new(),
+            origins: HashMap::new(),
+        }
+    }
+
+    /// Inserts a key-value pair as [`Origin::Programmatic`], returning the
+    /// value it replaced, if any.
+    pub fn insert(&mut self, key: String, value: T) -> Option<T> {
+        self.origins.insert(key.clone(), Origin::Programmatic);
+        self.map.insert(key, value)
+    }
+
+    /// Removes the entry for `name` along with its origin.
+    pub fn remove(&mut self, name: &str) -> Option<T> {
+        self.origins.remove(name);
+        self.map.as_inner_mut().remove(name)
+    }
+
+    /// Returns a mutable reference to the value of `name`; its origin is
+    /// left as it was.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut T> {
+        self.map.as_inner_mut().get_mut(name)
+    }
+
+    /// Returns how the entry for `name` was created.
+    pub fn origin(&self, name: &str) -> Option<Origin> {
+        self.origins.get(name).copied()
+    }
+
+    /// Iterates over every name with its origin, in arbitrary order.
+    pub fn origins(&self) -> impl Iterator<Item = (&str, Origin)> {
+        self.origins
+            .iter()
+            .map(|(name, origin)| (name.as_str(), *origin))
+    }
+
+    /// Returns the map, dropping the origins.
+    pub fn into_inner(self) -> NamedMap<T> {
+        self.map
+    }
+}
+
+impl<T> Default for TrackedNamedMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Read-only: mutable access could insert entries without an origin.
+impl<T> Deref for TrackedNamedMap<T> {
+    type Target = NamedMap<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<T> From<TrackedNamedMap<T>> for NamedMap<T> {
+    fn from(map: TrackedNamedMap<T>) -> Self {
+        map.map
+    }
+}
+
+impl<T> From<NamedMap<T>> for TrackedNamedMap<T> {
+    /// Wraps an existing map, recording every entry as
+    /// [`Origin::Programmatic`].
+    fn from(map: NamedMap<T>) -> Self {
+        let origins = map
+            .keys()
+            .map(|name| (name.clone(), Origin::Programmatic))
+            .collect();
+        Self { map, origins }
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::adaptive::namedmap::{NamedMapVisitor, Place};
+    use crate::adaptive::seq::{deserialize_adaptive, next_named_value, SeqItem};
+    use crate::adaptive::{DuplicateNameError, DuplicatePolicy, FromName, NamePolicy};
+    use serde::{
+        de::{self, Deserializer, MapAccess, SeqAccess},
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+
+    // Records each entry's origin as `NamedMapVisitor` reads it.
+    impl<T: FromName> Place for TrackedNamedMap<T> {
+        type Value = T;
+        type Meta = Origin;
+
+        fn name_meta() -> Origin {
+            Origin::Simple
+        }
+
+        fn from_name<E: de::Error>(name: &str) -> Result<T, E> {
+            Ok(T::from_name(name))
+        }
+
+        fn next_item<'de, A>(
+            seq: &mut A,
+            _nullable: bool,
+        ) -> Result<Option<(SeqItem<T>, Origin)>, A::Error>
+        where
+            A: SeqAccess<'de>,
+            T: Deserialize<'de>,
+        {
+            Ok(seq.next_element::<SeqItem<T>>()?.map(|item| {
+                let origin = match item {
+                    SeqItem::Name(_) => Origin::Simple,
+                    SeqItem::Entry(..) => Origin::Detailed,
+                };
+                (item, origin)
+            }))
+        }
+
+        fn next_value<'de, A>(
+            access: &mut A,
+            name: &str,
+            _nullable: bool,
+        ) -> Result<(T, Origin), A::Error>
+        where
+            A: MapAccess<'de>,
+            T: Deserialize<'de>,
+        {
+            Ok((next_named_value(access, name)?, Origin::Detailed))
+        }
+
+        fn clear(&mut self) {
+            self.map.clear();
+            self.origins.clear();
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            self.map.reserve(additional);
+            self.origins.reserve(additional);
+        }
+
+        fn contains_key(&self, name: &str) -> bool {
+            self.map.contains_key(name)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn len(&self) -> usize {
+            self.map.len()
+        }
+
+        fn insert(
+            &mut self,
+            policy: DuplicatePolicy,
+            name: String,
+            value: T,
+            origin: Origin,
+            index: usize,
+        ) -> Result<(), DuplicateNameError> {
+            // Both maps hold the same names, so `policy` decides alike for each.
+            policy.insert(&mut self.origins, name.clone(), origin, index)?;
+            policy.insert(self.map.as_inner_mut(), name, value, index)
+        }
+    }
+
+    impl<T: Serialize> Serialize for TrackedNamedMap<T> {
+        /// Serializes the entries only, as [`NamedMap`] does; the origins
+        /// are not written.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.map.serialize(serializer)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for TrackedNamedMap<T>
+    where
+        T: Deserialize<'de> + FromName,
+    {
+        /// Deserializes from the same forms as [`NamedMap`], recording
+        /// whether each entry was a bare name or came with a value.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let self_describing = deserializer.is_human_readable();
+            let mut map = TrackedNamedMap::new();
+            deserialize_adaptive(
+                deserializer,
+                NamedMapVisitor {
+                    place: &mut map,
+                    policy: DuplicatePolicy::Error,
+                    names: NamePolicy::RejectEmpty,
+                    nullable: false,
+                    self_describing,
+                },
+            )?;
+            Ok(map)
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct Plugin {
+        enabled: bool,
+    }
+
+    #[cfg(feature = "serde")]
+    impl crate::adaptive::FromName for Plugin {
+        fn from_name(_name: &str) -> Self {
+            Self { enabled: true }
+        }
+    }
+
+    #[test]
+    fn test_programmatic_origin() {
+        let mut map = TrackedNamedMap::new();
+        assert_eq!(map.insert("a".to_string(), Plugin { enabled: true }), None);
+        assert_eq!(map.origin("a"), Some(Origin::Programmatic));
+        assert_eq!(map.origin("b"), None);
+        map.get_mut("a").unwrap().enabled = false;
+        assert_eq!(map.origin("a"), Some(Origin::Programmatic));
+        assert_eq!(map.remove("a"), Some(Plugin { enabled: false }));
+        assert_eq!(map.origins().count(), 0);
+
+        let mut plain = NamedMap::new();
+        plain.insert("b".to_string(), Plugin { enabled: true });
+        let map = TrackedNamedMap::from(plain);
+        assert_eq!(map.origin("b"), Some(Origin::Programmatic));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_origins_from_each_form() {
+        let mut map: TrackedNamedMap<Plugin> =
+            serde_json::from_str(r#"["logger", {"http": {"enabled": false}}]"#).unwrap();
+        assert_eq!(map.origin("logger"), Some(Origin::Simple));
+        assert_eq!(map.origin("http"), Some(Origin::Detailed));
+        assert!(!map["http"].enabled);
+        map.insert("metrics".to_string(), Plugin { enabled: true });
+        let mut origins: Vec<_> = map.origins().collect();
+        origins.sort_by_key(|(name, _)| *name);
+        assert_eq!(
+            origins,
+            [
+                ("http", Origin::Detailed),
+                ("logger", Origin::Simple),
+                ("metrics", Origin::Programmatic),
+            ]
+        );
+
+        let map: TrackedNamedMap<Plugin> =
+            serde_json::from_str(r#"{"logger": {"enabled": true}}"#).unwrap();
+        assert_eq!(map.origin("logger"), Some(Origin::Detailed));
+
+        let map: TrackedNamedMap<Plugin> = serde_json::from_str(r#""logger""#).unwrap();
+        assert_eq!(map.origin("logger"), Some(Origin::Simple));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_serialize_omits_origins() {
+        let map: TrackedNamedMap<Plugin> = serde_json::from_str(r#"["logger"]"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&map).unwrap(),
+            r#"{"logger":{"enabled":true}}"#
+        );
+        let bytes = bincode::serialize(&map).unwrap();
+        let back: TrackedNamedMap<Plugin> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back.origin("logger"), Some(Origin::Detailed));
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn test_ron_option_wrapped() {
+        let map: TrackedNamedMap<Plugin> =
+            ron::from_str(r#"Some(["logger", {"http": (enabled: false)}])"#).unwrap();
+        assert_eq!(map.origin("logger"), Some(Origin::Simple));
+        assert_eq!(map.origin("http"), Some(Origin::Detailed));
+        let map: TrackedNamedMap<Plugin> = ron::from_str(r#"Some("logger")"#).unwrap();
+        assert_eq!(map.origin("logger"), Some(Origin::Simple));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_rejects_like_named_map() {
+        for (input, message) in [
+            (r#"["a", "a"]"#, "duplicate name \"a\" at index 1"),
+            (
+                r#"["a", {"a": {"enabled": true}}]"#,
+                "duplicate name \"a\" at index 1",
+            ),
+            (r#"["a", " "]"#, "empty name at index 1"),
+            (r#"{" ": {"enabled": true}}"#, r#"empty key " ""#),
+            (
+                r#"{"a": {"enabled": true}, "a": {"enabled": true}}"#,
+                "duplicate key \"a\"",
+            ),
+        ] {
+            let err = serde_json::from_str::<TrackedNamedMap<Plugin>>(input).unwrap_err();
+            assert!(err.to_string().contains(message), "{}: {}", input, err);
+        }
+    }
+}
@@ -0,0 +1,312 @@
+// flexicon/src/adaptive/trynamedmap.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::ops::{Deref, DerefMut};
+
+use super::NamedMap;
+
+/// A [`NamedMap`] whose simple form is parsed through [`TryFromName`](super::TryFromName).
+///
+/// Behaves exactly like `NamedMap` once parsed (it derefs to one), but a name
+/// that `T::try_from_name` rejects becomes a deserialization error that
+/// includes the offending name instead of a silent placeholder.
+/// The object form is unaffected.
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// use flexicon::adaptive::{TryFromName, TryNamedMap};
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// struct Port(u16);
+///
+/// impl TryFromName for Port {
+///     type Error = std::num::ParseIntError;
+///
+///     fn try_from_name(name: &str) -> Result<Self, Self::Error> {
+///         name.parse().map(Port)
+///     }
+/// }
+///
+/// # #[cfg(feature = "serde_json")]
+/// # {
+/// let ok: TryNamedMap<Port> = serde_json::from_str(r#"["80", "443"]"#).unwrap();
+/// assert_eq!(ok["443"].0, 443);
+///
+/// let err = serde_json::from_str::<TryNamedMap<Port>>(r#"["80", "http"]"#).unwrap_err();
+/// assert!(err.to_string().contains("invalid name \"http\""));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryNamedMap<T>(NamedMap<T>);
+
+impl<T> TryNamedMap<T> {
+    /// Creates an empty `TryNamedMap`.
+    pub fn new() -> Self {
+        Self(NamedMap::new())
+    }
+
+    /// Consumes the wrapper and returns the underlying `NamedMap`.
+    pub fn into_inner(self) -> NamedMap<T> {
+        self.0
+    }
+}
+
+impl<T> Default for TryNamedMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<NamedMap<T>> for TryNamedMap<T> {
+    fn from(map: NamedMap<T>) -> Self {
+        TryNamedMap(map)
+    }
+}
+
+impl<T> From<TryNamedMap<T>> for NamedMap<T> {
+    fn from(map: TryNamedMap<T>) -> Self {
+        map.0
+    }
+}
+
+impl<T> Deref for TryNamedMap<T> {
+    type Target = NamedMap<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for TryNamedMap<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::adaptive::namedmap::{NamedMapVisitor, Place};
+    use crate::adaptive::seq::{deserialize_adaptive, next_named_value, try_from_name, SeqItem};
+    use crate::adaptive::{DuplicateNameError, DuplicatePolicy, NamePolicy, TryFromName};
+    use serde::{
+        de::{self, DeserializeOwned, Deserializer, MapAccess, SeqAccess},
+        ser::Serializer,
+        Deserialize, Serialize,
+    };
+    use alloc::string::String;
+
+    // Routes bare names through `TryFromName` as `NamedMapVisitor` reads them.
+    impl<T: TryFromName> Place for TryNamedMap<T> {
+        type Value = T;
+        type Meta = ();
+
+        fn name_meta() {}
+
+        fn from_name<E: de::Error>(name: &str) -> Result<T, E> {
+            try_from_name(name)
+        }
+
+        fn next_item<'de, A>(
+            seq: &mut A,
+            _nullable: bool,
+        ) -> Result<Option<(SeqItem<T>, ())>, A::Error>
+        where
+            A: SeqAccess<'de>,
+            T: Deserialize<'de>,
+        {
+            Ok(seq.next_element::<SeqItem<T>>()?.map(|item| (item, ())))
+        }
+
+        fn next_value<'de, A>(
+            access: &mut A,
+            name: &str,
+            _nullable: bool,
+        ) -> Result<(T, ()), A::Error>
+        where
+            A: MapAccess<'de>,
+            T: Deserialize<'de>,
+        {
+            Ok((next_named_value(access, name)?, ()))
+        }
+
+        fn clear(&mut self) {
+            self.0.clear();
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            self.0.reserve(additional);
+        }
+
+        fn contains_key(&self, name: &str) -> bool {
+            self.0.contains_key(name)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn insert(
+            &mut self,
+            policy: DuplicatePolicy,
+            name: String,
+            value: T,
+            _meta: (),
+            index: usize,
+        ) -> Result<(), DuplicateNameError> {
+            policy.insert(&mut *self.0, name, value, index)
+        }
+    }
+
+    impl<T> Serialize for TryNamedMap<T>
+    where
+        T: Serialize,
+    {
+        /// Serializes exactly like the wrapped `NamedMap`.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for TryNamedMap<T>
+    where
+        T: DeserializeOwned + TryFromName,
+    {
+        /// Deserializes from the same formats as `NamedMap`, failing on
+        /// names rejected by `TryFromName`.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let self_describing = deserializer.is_human_readable();
+            let mut map = TryNamedMap::new();
+            deserialize_adaptive(
+                deserializer,
+                NamedMapVisitor {
+                    place: &mut map,
+                    policy: DuplicatePolicy::Error,
+                    names: NamePolicy::RejectEmpty,
+                    nullable: false,
+                    self_describing,
+                },
+            )?;
+            Ok(map)
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::adaptive::{FromName, TryFromName};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct Version {
+        major: u32,
+    }
+
+    impl TryFromName for Version {
+        type Error = String;
+
+        fn try_from_name(name: &str) -> Result<Self, Self::Error> {
+            name.strip_prefix('v')
+                .and_then(|n| n.parse().ok())
+                .map(|major| Version { major })
+                .ok_or_else(|| "expected `v<number>`".to_string())
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct Plain {
+        value: String,
+    }
+
+    impl FromName for Plain {
+        fn from_name(name: &str) -> Self {
+            Self {
+                value: name.to_string(),
+            }
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(bound = "T: serde::de::DeserializeOwned + TryFromName")]
+    struct Manifest<T> {
+        items: TryNamedMap<T>,
+    }
+
+    #[test]
+    fn test_valid_names() {
+        let m: Manifest<Version> = toml::from_str(r#"items = ["v1", "v2"]"#).unwrap();
+        assert_eq!(m.items["v2"], Version { major: 2 });
+    }
+
+    #[test]
+    fn test_bad_name_is_reported() {
+        let err = toml::from_str::<Manifest<Version>>(r#"items = ["v1", "latest"]"#).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("invalid name \"latest\""), "{}", msg);
+        assert_eq!(err.message(), "invalid name \"latest\": expected `v<number>`");
+    }
+
+    #[test]
+    fn test_single_name() {
+        let m: Manifest<Version> = toml::from_str(r#"items = "v3""#).unwrap();
+        assert_eq!(m.items["v3"], Version { major: 3 });
+
+        let err = toml::from_str::<Manifest<Version>>(r#"items = "latest""#).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "invalid name \"latest\": expected `v<number>`"
+        );
+    }
+
+    #[test]
+    fn test_checked_like_named_map() {
+        let err = toml::from_str::<Manifest<Version>>(r#"items = ["v1", "v2", "v1"]"#).unwrap_err();
+        assert_eq!(err.message(), "duplicate name \"v1\" at index 2");
+
+        let err = toml::from_str::<Manifest<Version>>(r#"items = ["v1", " "]"#).unwrap_err();
+        assert_eq!(err.message(), "empty name at index 1");
+
+        let err =
+            toml::from_str::<Manifest<Version>>(r#"items = { "" = { major = 1 } }"#).unwrap_err();
+        assert_eq!(err.message(), "empty key \"\"");
+    }
+
+    #[test]
+    fn test_object_form_unaffected() {
+        let m: Manifest<Version> = toml::from_str(
+            r#"
+            [items]
+            latest = { major = 7 }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(m.items["latest"], Version { major: 7 });
+    }
+
+    #[test]
+    fn test_from_name_types_still_work() {
+        let m: Manifest<Plain> = toml::from_str(r#"items = ["a", "b"]"#).unwrap();
+        let map: NamedMap<Plain> = m.items.into_inner();
+        assert_eq!(map["a"], Plain::from_name("a"));
+    }
+}
@@ -0,0 +1,373 @@
+// flexicon/src/adaptive/validatednamedmap.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use super::{FromName, NamedMap};
+
+/// Why a validator rejected a name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyError {
+    /// A short description of the problem, e.g. "must not be empty".
+    pub reason: Cow<'static, str>,
+}
+
+impl KeyError {
+    /// Creates an error with the given reason.
+    pub fn new(reason: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.reason)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyError {}
+
+/// A name rejected by the validator of a [`ValidatedNamedMap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidKeyError {
+    /// The rejected name.
+    pub key: String,
+    /// Why it was rejected.
+    pub error: KeyError,
+}
+
+impl fmt::Display for InvalidKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid key {:?}: {}", self.key, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidKeyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Decides which names a [`ValidatedNamedMap`] accepts.
+pub trait KeyValidator {
+    /// Returns an error describing why `name` is not acceptable.
+    fn validate(name: &str) -> Result<(), KeyError>;
+}
+
+/// Accepts every name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AnyKey;
+
+impl KeyValidator for AnyKey {
+    fn validate(_name: &str) -> Result<(), KeyError> {
+        Ok(())
+    }
+}
+
+/// Accepts non-empty names made of ASCII letters, digits, `-` and `_`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IdentifierKeys;
+
+impl KeyValidator for IdentifierKeys {
+    fn validate(name: &str) -> Result<(), KeyError> {
+        if name.is_empty() {
+            return Err(KeyError::new("must not be empty"));
+        }
+        match name
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || *c == '-' || *c == '_'))
+        {
+            Some(c) => Err(KeyError::new(alloc::format!(
+                "contains {:?}; only ASCII letters, digits, `-` and `_` are allowed",
+                c
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A [`NamedMap`] whose names are checked by a [`KeyValidator`].
+///
+/// Every way in — [`insert`](Self::insert), `TryFrom` and deserialization —
+/// rejects names the validator refuses, reporting the name and the reason.
+/// The map dereferences to a read-only [`NamedMap`].
+///
+/// # Example (with serde)
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # #[derive(Debug, Clone, Serialize, Deserialize)]
+/// # struct Plugin { enabled: bool }
+/// # impl flexicon::adaptive::FromName for Plugin {
+/// #     fn from_name(_name: &str) -> Self { Self { enabled: true } }
+/// # }
+/// use flexicon::adaptive::{IdentifierKeys, ValidatedNamedMap};
+///
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() {
+/// let err = serde_json::from_str::<ValidatedNamedMap<Plugin, IdentifierKeys>>(r#"["my plugin"]"#)
+///     .unwrap_err();
+/// assert!(err.to_string().starts_with("invalid key \"my plugin\": contains ' '"));
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedNamedMap<T, V: KeyValidator> {
+    map: NamedMap<T>,
+    _validator: PhantomData<fn() -> V>,
+}
+
+impl<T, V: KeyValidator> ValidatedNamedMap<T, V> {
+    /// Creates an empty `ValidatedNamedMap`.
+    pub fn new() -> Self {
+        Self {
+            map: NamedMap::new(),
+            _validator: PhantomData,
+        }
+    }
+
+    /// Inserts a key-value pair if the validator accepts `key`, returning
+    /// the value it replaced, if any.
+    pub fn insert(&mut self, key: String, value: T) -> Result<Option<T>, InvalidKeyError> {
+        match V::validate(&key) {
+            Ok(()) => Ok(self.map.insert(key, value)),
+            Err(error) => Err(InvalidKeyError { key, error }),
+        }
+    }
+
+    /// Removes the entry for `name`, returning its value.
+    pub fn remove(&mut self, name: &str) -> Option<T> {
+        self.map.as_inner_mut().remove(name)
+    }
+
+    /// Returns a mutable reference to the value of `name`.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut T> {
+        self.map.as_inner_mut().get_mut(name)
+    }
+
+    /// Returns the validated map.
+    pub fn into_inner(self) -> NamedMap<T> {
+        self.map
+    }
+}
+
+impl<T, V: KeyValidator> Default for ValidatedNamedMap<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Read-only: mutable access could insert names the validator never saw.
+impl<T, V: KeyValidator> Deref for ValidatedNamedMap<T, V> {
+    type Target = NamedMap<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<T, V: KeyValidator> From<ValidatedNamedMap<T, V>> for NamedMap<T> {
+    fn from(map: ValidatedNamedMap<T, V>) -> Self {
+        map.map
+    }
+}
+
+impl<T, V: KeyValidator> TryFrom<NamedMap<T>> for ValidatedNamedMap<T, V> {
+    type Error = InvalidKeyError;
+
+    /// Validates every key of `map`.
+    fn try_from(map: NamedMap<T>) -> Result<Self, Self::Error> {
+        if let Some((key, error)) = map
+            .keys()
+            .find_map(|key| V::validate(key).err().map(|error| (key, error)))
+        {
+            return Err(InvalidKeyError {
+                key: key.clone(),
+                error,
+            });
+        }
+        Ok(Self {
+            map,
+            _validator: PhantomData,
+        })
+    }
+}
+
+impl<T: FromName, V: KeyValidator> TryFrom<Vec<String>> for ValidatedNamedMap<T, V> {
+    type Error = InvalidKeyError;
+
+    /// Builds a map from a list of names, failing at the first name the
+    /// validator rejects.
+    fn try_from(names: Vec<String>) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        for name in names {
+            let value = T::from_name(&name);
+            map.insert(name, value)?;
+        }
+        Ok(map)
+    }
+}
+
+// === SERDE INTEGRATION (format-agnostic) ===
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    impl<T: Serialize, V: KeyValidator> Serialize for ValidatedNamedMap<T, V> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.map.serialize(serializer)
+        }
+    }
+
+    impl<'de, T, V> Deserialize<'de> for ValidatedNamedMap<T, V>
+    where
+        T: Deserialize<'de> + FromName,
+        V: KeyValidator,
+    {
+        /// Deserializes from the same forms as [`NamedMap`], then rejects
+        /// the first name the validator refuses.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let map = NamedMap::<T>::deserialize(deserializer)?;
+            Self::try_from(map).map_err(de::Error::custom)
+        }
+    }
+}
+
+// === TESTS ===
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct Plugin {
+        enabled: bool,
+    }
+
+    impl FromName for Plugin {
+        fn from_name(_name: &str) -> Self {
+            Self { enabled: true }
+        }
+    }
+
+    #[test]
+    fn test_identifier_keys() {
+        assert!(IdentifierKeys::validate("http-server_2").is_ok());
+        assert_eq!(
+            IdentifierKeys::validate("").unwrap_err().reason,
+            "must not be empty"
+        );
+        assert_eq!(
+            IdentifierKeys::validate("a.b").unwrap_err().reason,
+            "contains '.'; only ASCII letters, digits, `-` and `_` are allowed"
+        );
+        assert!(IdentifierKeys::validate("café").is_err());
+        assert!(AnyKey::validate("").is_ok());
+        assert!(AnyKey::validate("a.b c").is_ok());
+    }
+
+    #[test]
+    fn test_insert_and_try_from() {
+        let mut map = ValidatedNamedMap::<Plugin, IdentifierKeys>::new();
+        assert_eq!(
+            map.insert("logger".to_string(), Plugin { enabled: false }),
+            Ok(None)
+        );
+        let err = map
+            .insert("my logger".to_string(), Plugin { enabled: true })
+            .unwrap_err();
+        assert_eq!(err.key, "my logger");
+        assert_eq!(map.len(), 1);
+
+        let map =
+            ValidatedNamedMap::<Plugin, IdentifierKeys>::try_from(vec!["a".to_string()]).unwrap();
+        assert!(map.contains_key("a"));
+        let err = ValidatedNamedMap::<Plugin, IdentifierKeys>::try_from(vec![
+            "a".to_string(),
+            "b/c".to_string(),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid key \"b/c\": contains '/'; only ASCII letters, digits, `-` and `_` are allowed"
+        );
+
+        let map = ValidatedNamedMap::<Plugin, AnyKey>::try_from(vec!["b/c".to_string()]).unwrap();
+        assert!(map.contains_key("b/c"));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_deserialize_array_form() {
+        let map: ValidatedNamedMap<Plugin, IdentifierKeys> =
+            serde_json::from_str(r#"["logger", {"http": {"enabled": false}}]"#).unwrap();
+        assert_eq!(map.len(), 2);
+
+        let err = serde_json::from_str::<ValidatedNamedMap<Plugin, IdentifierKeys>>(
+            r#"["logger", "net/http"]"#,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("invalid key \"net/http\": contains '/'"),
+            "{}",
+            err
+        );
+
+        let map: ValidatedNamedMap<Plugin, AnyKey> =
+            serde_json::from_str(r#"["logger", "net/http"]"#).unwrap();
+        assert!(map.contains_key("net/http"));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_deserialize_object_form() {
+        let map: ValidatedNamedMap<Plugin, IdentifierKeys> =
+            serde_json::from_str(r#"{"http-2": {"enabled": true}}"#).unwrap();
+        assert!(map["http-2"].enabled);
+        assert_eq!(
+            serde_json::to_string(&map).unwrap(),
+            r#"{"http-2":{"enabled":true}}"#
+        );
+
+        let err = serde_json::from_str::<ValidatedNamedMap<Plugin, IdentifierKeys>>(
+            r#"{"http:2": {"enabled": true}}"#,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("invalid key \"http:2\": contains ':'"),
+            "{}",
+            err
+        );
+
+        let map: ValidatedNamedMap<Plugin, AnyKey> =
+            serde_json::from_str(r#"{"a b": {"enabled": true}}"#).unwrap();
+        assert!(map.contains_key("a b"));
+    }
+}
@@ -0,0 +1,73 @@
+// flexicon/src/de.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Deserialization helpers reporting *where* in a document an error occurred.
+
+use serde::{Deserialize, Deserializer};
+use std::error::Error;
+use std::fmt;
+
+/// A deserialization error together with the path of the failing value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathError {
+    /// Dotted path to the failing value, e.g. `plugins.http.version`
+    /// (`.` for the document root).
+    pub path: String,
+    /// The underlying deserializer's message.
+    pub message: String,
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path == "." {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl Error for PathError {}
+
+/// Deserializes a `T` from `deserializer`, tracking the path to any error
+/// with `serde_path_to_error`.
+///
+/// # Example
+///
+/// ```rust
+/// use flexicon::adaptive::NamedMap;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     plugins: NamedMap<Plugin>,
+/// }
+///
+/// #[derive(Clone, Deserialize)]
+/// struct Plugin {
+///     enabled: bool,
+/// }
+/// # impl flexicon::adaptive::FromName for Plugin {
+/// #     fn from_name(_name: &str) -> Self { Plugin { enabled: true } }
+/// # }
+///
+/// let mut de = serde_json::Deserializer::from_str(r#"{"plugins": {"http": {"enabled": 1}}}"#);
+/// let err = flexicon::de::with_path::<Config, _>(&mut de).err().unwrap();
+/// assert_eq!(err.path, "plugins.http.enabled");
+/// ```
+pub fn with_path<'de, T, D>(deserializer: D) -> Result<T, PathError>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    serde_path_to_error::deserialize(deserializer).map_err(|err| PathError {
+        path: err.path().to_string(),
+        message: err.inner().to_string(),
+    })
+}
@@ -7,4 +7,14 @@
 // This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("flexicon requires either the `std` or the `alloc` feature");
+
+extern crate alloc;
+
 pub mod adaptive;
+
+#[cfg(feature = "path-to-error")]
+pub mod de;
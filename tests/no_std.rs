@@ -0,0 +1,65 @@
+// flexicon/tests/no_std.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Uses the core API from a `#![no_std]` crate, with serde's in-memory
+//! deserializers only.
+//!
+//! To link against the `hashbrown`-backed build as well, run
+//! `cargo test --no-default-features --features alloc,serde --test no_std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+use alloc::vec;
+use flexicon::adaptive::{FromName, NamedMap};
+use serde::de::value::{Error, MapDeserializer, SeqDeserializer, StrDeserializer};
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(transparent)]
+struct Port(u16);
+
+impl FromName for Port {
+    fn from_name(name: &str) -> Self {
+        Port(if name == "https" { 443 } else { 80 })
+    }
+}
+
+#[test]
+fn test_insert_and_from_names() {
+    let mut map = NamedMap::<Port>::from_names(["http", "https"]);
+    assert_eq!(map.insert("metrics".to_string(), Port(9090)), None);
+    assert_eq!(map.len(), 3);
+    assert_eq!(map["https"], Port(443));
+
+    let collected: NamedMap<Port> = vec!["http".to_string()].into_iter().collect();
+    assert_eq!(collected["http"], Port(80));
+}
+
+#[test]
+fn test_deserialize_every_form() {
+    let names = SeqDeserializer::<_, Error>::new(vec!["http", "https"].into_iter());
+    let map = NamedMap::<Port>::deserialize(names).unwrap();
+    assert_eq!(map["https"], Port(443));
+
+    let name: StrDeserializer<'_, Error> = "http".into_deserializer();
+    let map = NamedMap::<Port>::deserialize(name).unwrap();
+    assert_eq!(map.len(), 1);
+
+    let entries = MapDeserializer::<_, Error>::new(vec![("http", 8080u16)].into_iter());
+    let map = NamedMap::<Port>::deserialize(entries).unwrap();
+    assert_eq!(map["http"], Port(8080));
+
+    let repeated = SeqDeserializer::<_, Error>::new(vec!["a", "a"].into_iter());
+    let err = NamedMap::<Port>::deserialize(repeated).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate name \"a\" at index 1");
+}
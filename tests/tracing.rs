@@ -0,0 +1,178 @@
+// flexicon/tests/tracing.rs
+//
+// Copyright (c) 2025 Arcella Team
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE>
+// or the MIT license <LICENSE-MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Checks the spans and events emitted with the `tracing` feature, using a
+//! subscriber that records them as text lines.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use flexicon::adaptive::{FromName, MergeStrategy, NamedMap};
+use serde::Deserialize;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Plugin {
+    enabled: bool,
+}
+
+impl FromName for Plugin {
+    fn from_name(_name: &str) -> Self {
+        Plugin { enabled: true }
+    }
+}
+
+/// Renders fields as ` name=value`, strings unquoted.
+struct Fields<'a>(&'a mut String);
+
+impl Visit for Fields<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push_str(&format!(" {}={}", field.name(), value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push_str(&format!(" {}={:?}", field.name(), value));
+    }
+}
+
+/// Records spans as `span <name><fields>` and events as
+/// `<current span>:<fields>`. Single-threaded use only.
+#[derive(Clone, Default)]
+struct Capture {
+    lines: Arc<Mutex<Vec<String>>>,
+    spans: Arc<Mutex<Vec<&'static str>>>,
+    stack: Arc<Mutex<Vec<u64>>>,
+}
+
+impl Capture {
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.lines.lock().unwrap())
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut line = format!("span {}", span.metadata().name());
+        span.record(&mut Fields(&mut line));
+        self.lines.lock().unwrap().push(line);
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(span.metadata().name());
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let current = match self.stack.lock().unwrap().last() {
+            Some(id) => self.spans.lock().unwrap()[*id as usize - 1],
+            None => "",
+        };
+        let mut line = format!("{}:", current);
+        event.record(&mut Fields(&mut line));
+        self.lines.lock().unwrap().push(line);
+    }
+
+    fn enter(&self, span: &Id) {
+        self.stack.lock().unwrap().push(span.into_u64());
+    }
+
+    fn exit(&self, _span: &Id) {
+        self.stack.lock().unwrap().pop();
+    }
+}
+
+fn capture<R>(f: impl FnOnce() -> R) -> (R, Vec<String>) {
+    let subscriber = Capture::default();
+    let result = tracing::subscriber::with_default(subscriber.clone(), f);
+    (result, subscriber.take())
+}
+
+#[test]
+fn test_deserialize_seq_form() {
+    let (map, lines) = capture(|| {
+        serde_json::from_str::<NamedMap<Plugin>>(r#"["logger", {"http": {"enabled": false}}]"#)
+            .unwrap()
+    });
+    assert_eq!(map.len(), 2);
+    assert_eq!(
+        lines,
+        [
+            "span named_map.deserialize form=seq",
+            "named_map.deserialize: message=deserialized entries=2",
+        ]
+    );
+}
+
+#[test]
+fn test_deserialize_map_form() {
+    let (map, lines) = capture(|| {
+        serde_json::from_str::<NamedMap<Plugin>>(r#"{"logger": {"enabled": true}}"#).unwrap()
+    });
+    assert_eq!(map.len(), 1);
+    assert_eq!(
+        lines,
+        [
+            "span named_map.deserialize form=map",
+            "named_map.deserialize: message=deserialized entries=1",
+        ]
+    );
+}
+
+#[test]
+fn test_merge_reports_overwritten_keys() {
+    let mut system: NamedMap<i32> = [("logger".to_string(), 1)].into_iter().collect();
+    let user: NamedMap<i32> = [("logger".to_string(), 2), ("http".to_string(), 3)]
+        .into_iter()
+        .collect();
+    let (_, lines) = capture(|| system.merge(user, MergeStrategy::Overwrite));
+    assert_eq!(
+        lines,
+        [
+            "span named_map.merge strategy=Overwrite",
+            "named_map.merge: message=overwritten key=logger",
+            "named_map.merge: message=merged added=1 replaced=1 kept=0",
+        ]
+    );
+
+    let empty = NamedMap::new();
+    let (_, lines) = capture(|| system.diff(&empty).is_empty());
+    assert_eq!(
+        lines,
+        [": message=named_map.diff added=0 removed=2 changed=0"]
+    );
+}
+
+#[test]
+fn test_load_reports_path_format_and_duration() {
+    let dir = std::env::temp_dir().join(format!("flexicon-tracing-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("plugins.json");
+    std::fs::write(&path, r#"["logger"]"#).unwrap();
+
+    let (map, lines) = capture(|| NamedMap::<Plugin>::from_path(&path).unwrap());
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(map.contains_key("logger"));
+
+    let load = lines.last().unwrap();
+    let prefix = format!(
+        ": message=named_map.load path={} format=JSON duration=",
+        path.display()
+    );
+    assert!(load.starts_with(&prefix), "{}", load);
+    assert!(load.ends_with(" ok=true"), "{}", load);
+    assert!(lines.contains(&"span named_map.deserialize form=seq".to_string()));
+}